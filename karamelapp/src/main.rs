@@ -6,11 +6,13 @@ extern crate karamellib;
 use clap::{App, Arg};
 
 use karamellib::{
+    compiler::capabilities::Capabilities,
     constants::{KARAMEL_CONTACT_EMAIL, KARAMEL_HELP_ABOUT, KARAMEL_TITLE, KARAMEL_VERSION},
     vm::executer::{ExecutionParameters, ExecutionSource},
 };
 
 fn main() {
+    karamellib::logger::init_logger();
     let matches = App::new(KARAMEL_TITLE)
         .version(KARAMEL_VERSION)
         .author(KARAMEL_CONTACT_EMAIL)
@@ -23,15 +25,27 @@ fn main() {
                 .help("Çalıştırılacak karamel dosyası")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("seed")
+                .short("t")
+                .long("tohum")
+                .value_name("SEED")
+                .help("Rastgele sayı üretimi için kullanılacak tohum")
+                .takes_value(true),
+        )
         .get_matches();
 
+    let random_seed = matches
+        .value_of("seed")
+        .and_then(|seed| seed.parse::<u64>().ok());
+
     let parameters = match matches.value_of("file") {
         Some(file) => ExecutionParameters {
             source: ExecutionSource::File(file.to_string()),
             return_opcode: true,
-            return_output: true,
-            dump_opcode: false,
-            dump_memory: false,
+            random_seed,
+            capabilities: Capabilities::all(),
+            ..Default::default()
         },
         None => ExecutionParameters {
             source: ExecutionSource::Code(
@@ -46,9 +60,9 @@ döngü i = 0, i < 10, i++:
                 .to_string(),
             ),
             return_opcode: true,
-            return_output: true,
-            dump_opcode: false,
-            dump_memory: false,
+            random_seed,
+            capabilities: Capabilities::all(),
+            ..Default::default()
         },
     };
 
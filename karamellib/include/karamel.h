@@ -0,0 +1,111 @@
+/*
+ * C interface of the Karamel Programlama Dili.
+ *
+ * Ownership rules:
+ *  - KaramelEngine is created with karamel_engine_new or karamel_engine_with_input and
+ *    destroyed with karamel_engine_free.
+ *  - KaramelValue objects that returned by the karamel_value_* functions and karamel_engine_call
+ *    are owned by the caller and destroyed with karamel_value_free. Values that returned from
+ *    a KaramelFunction are owned by Karamel.
+ *  - Texts that returned by karamel_engine_* functions are owned by the engine and valid until the
+ *    same function is called again or the engine is destroyed.
+ *  - Texts that returned by karamel_value_to_string are destroyed with karamel_string_free.
+ *  - Texts that given to the functions are copied, they should be null terminated UTF-8.
+ *
+ * An engine should be used only from the thread that created it.
+ */
+
+#ifndef KARAMEL_H
+#define KARAMEL_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Returned instead of an error code when a required pointer is null. */
+#define KARAMEL_INVALID_ARGUMENT (-1)
+
+/* Returned when the library fails internally, the engine should not be used after it. */
+#define KARAMEL_INTERNAL_ERROR (-2)
+
+typedef struct KaramelEngine KaramelEngine;
+typedef struct KaramelValue KaramelValue;
+
+typedef enum KaramelValueType {
+    KARAMEL_VALUE_EMPTY = 0,
+    KARAMEL_VALUE_BOOL = 1,
+    /* Integers, big integers, decimals, fractions and floating numbers */
+    KARAMEL_VALUE_NUMBER = 2,
+    KARAMEL_VALUE_TEXT = 3,
+    KARAMEL_VALUE_ERROR = 4,
+    KARAMEL_VALUE_OTHER = 5
+} KaramelValueType;
+
+/*
+ * Function that called by the script. Arguments are owned by Karamel and valid only during the call,
+ * they should not be freed or returned.
+ * Returned value must be newly created with a karamel_value_* function, it is owned by Karamel after
+ * the call. NULL means 'boş'. Returning karamel_value_error stops the script.
+ */
+typedef KaramelValue *(*KaramelFunction)(const KaramelValue *const *arguments, size_t count, void *user_data);
+
+KaramelEngine *karamel_engine_new(void);
+
+/* Every line of the input is read by one 'gç::satıroku' call. */
+KaramelEngine *karamel_engine_with_input(const char *input);
+void karamel_engine_free(KaramelEngine *engine);
+
+/* Adds the function as 'module::name', should be called before karamel_engine_execute. Returns 0 or the error code. */
+int32_t karamel_engine_register_function(KaramelEngine *engine, const char *module, const char *name, KaramelFunction function, void *user_data);
+
+/* Executes the script once. Returns 0 or the error code that documented at 'hata_kodlari.md'. */
+int32_t karamel_engine_execute(KaramelEngine *engine, const char *source);
+
+/* Calls the function of the executed script. Result is written to 'result' if it is not NULL. Returns 0 or the error code. */
+int32_t karamel_engine_call(KaramelEngine *engine, const char *name, const KaramelValue *const *arguments, size_t count, KaramelValue **result);
+
+/*
+ * Runs the code with the variables and functions of the previous executions. Value of the last expression
+ * is written to 'result' if it is not NULL, 'boş' if the code ends with a statement. Returns 0 or the error code.
+ */
+int32_t karamel_engine_eval(KaramelEngine *engine, const char *source, KaramelValue **result);
+
+const char *karamel_engine_stdout(KaramelEngine *engine);
+const char *karamel_engine_stderr(KaramelEngine *engine);
+
+/* Code and message of the last execution or call, 0 and an empty text if it was successful. */
+int32_t karamel_engine_error_code(const KaramelEngine *engine);
+const char *karamel_engine_error_message(KaramelEngine *engine);
+
+KaramelValue *karamel_value_empty(void);
+KaramelValue *karamel_value_bool(bool value);
+KaramelValue *karamel_value_int(int64_t value);
+KaramelValue *karamel_value_double(double value);
+KaramelValue *karamel_value_string(const char *value);
+KaramelValue *karamel_value_error(const char *message);
+
+KaramelValueType karamel_value_type(const KaramelValue *value);
+
+/*
+ * Writes the value to 'output' and returns true if the value has the requested type. Every number could be read
+ * as double, integers that fit into int64_t could be read as int.
+ */
+bool karamel_value_as_int(const KaramelValue *value, int64_t *output);
+bool karamel_value_as_double(const KaramelValue *value, double *output);
+bool karamel_value_as_bool(const KaramelValue *value, bool *output);
+
+/* Text of the value, other values are formatted like 'gç::yaz'. */
+char *karamel_value_to_string(const KaramelValue *value);
+
+void karamel_value_free(KaramelValue *value);
+void karamel_string_free(char *text);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* KARAMEL_H */
@@ -3,7 +3,7 @@ use crate::compiler::GetType;
 use crate::types::VmObject;
 use crate::{
     compiler::{
-        function::{FunctionParameter, FunctionReference, NativeCall, NativeCallResult},
+        function::{FunctionArgument, FunctionParameter, FunctionReference, NativeCall, NativeCallResult},
         EMPTY_OBJECT,
     },
     n_parameter_expected,
@@ -60,10 +60,10 @@ impl BaseFunctionsModule {
         };
 
         let rc_module = Rc::new(module);
-        rc_module
-            .methods
-            .borrow_mut()
-            .insert("tür_bilgisi".to_string(), FunctionReference::native_function(Self::type_info as NativeCall, "tür_bilgisi".to_string(), rc_module.clone()));
+        rc_module.methods.borrow_mut().insert(
+            "tür_bilgisi".to_string(),
+            FunctionReference::native_function_with_arguments(Self::type_info as NativeCall, "tür_bilgisi".to_string(), vec![FunctionArgument::new("değer")], rc_module.clone()),
+        );
         rc_module
     }
 
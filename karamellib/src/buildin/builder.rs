@@ -0,0 +1,146 @@
+use crate::buildin::{Class, Module};
+use crate::compiler::convert::{native_closure, IntoNativeFunction};
+use crate::compiler::function::{FunctionArgument, FunctionParameter, FunctionReference, NativeCall, NativeCallResult, NativeClosure};
+use crate::compiler::value::KaramelPrimative;
+use std::rc::Rc;
+use std::{cell::RefCell, collections::HashMap};
+
+/// Module created by the 'ModuleBuilder'.
+pub struct NativeModule {
+    name: String,
+    path: Vec<String>,
+    methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
+    constants: HashMap<String, Rc<KaramelPrimative>>,
+}
+
+impl Module for NativeModule {
+    fn get_module_name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn get_path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    fn get_method(&self, name: &str) -> Option<Rc<FunctionReference>> {
+        self.methods.borrow().get(name).cloned()
+    }
+
+    fn get_module(&self, _: &str) -> Option<Rc<dyn Module>> {
+        None
+    }
+
+    fn get_methods(&self) -> Vec<Rc<FunctionReference>> {
+        let mut response = Vec::new();
+        self.methods
+            .borrow()
+            .iter()
+            .for_each(|(_, reference)| response.push(reference.clone()));
+        response
+    }
+
+    fn get_modules(&self) -> HashMap<String, Rc<dyn Module>> {
+        HashMap::new()
+    }
+
+    fn get_classes(&self) -> Vec<Rc<dyn Class>> {
+        Vec::new()
+    }
+
+    fn get_constant(&self, name: &str) -> Option<Rc<KaramelPrimative>> {
+        self.constants.get(name).cloned()
+    }
+}
+
+/// Creates native modules for the applications that embed Karamel.
+///
+/// ```
+/// use karamellib::buildin::builder::ModuleBuilder;
+/// use karamellib::compiler::function::{FunctionParameter, NativeCallResult};
+/// use karamellib::compiler::value::KaramelPrimative;
+/// use karamellib::types::VmObject;
+///
+/// fn score(_: FunctionParameter) -> NativeCallResult {
+///     Ok(VmObject::from(100_i64))
+/// }
+///
+/// let module = ModuleBuilder::new("oyun")
+///     .function("skor", score)
+///     .typed_function("topla", |left: i64, right: i64| left + right)
+///     .constant("SÜRÜM", KaramelPrimative::Integer(2))
+///     .build();
+/// ```
+pub struct ModuleBuilder {
+    name: String,
+    functions: Vec<(String, NativeCall, Option<Vec<FunctionArgument>>)>,
+    closures: Vec<(String, Rc<NativeClosure>)>,
+    constants: HashMap<String, Rc<KaramelPrimative>>,
+}
+
+impl ModuleBuilder {
+    pub fn new<T: Into<String>>(name: T) -> Self {
+        ModuleBuilder {
+            name: name.into(),
+            functions: Vec::new(),
+            closures: Vec::new(),
+            constants: HashMap::new(),
+        }
+    }
+
+    /// Function that accepts any number of arguments, arguments should be checked by the function.
+    pub fn function<T: Into<String>>(mut self, name: T, function: NativeCall) -> Self {
+        self.functions.push((name.into(), function, None));
+        self
+    }
+
+    /// Function with named arguments, argument count checked and default values filled before the call.
+    pub fn function_with_arguments<T: Into<String>>(mut self, name: T, function: NativeCall, arguments: Vec<FunctionArgument>) -> Self {
+        self.functions
+            .push((name.into(), function, Some(arguments)));
+        self
+    }
+
+    /// Rust closure with typed parameters, parameters converted with 'FromKaramel' and the result with 'IntoKaramel'.
+    pub fn typed_function<T: Into<String>, Args, F: IntoNativeFunction<Args>>(mut self, name: T, function: F) -> Self {
+        let name = name.into();
+        let closure = native_closure(&name, function);
+        self.closures.push((name, closure));
+        self
+    }
+
+    /// Function that keeps its own state, arguments should be checked by the function.
+    pub fn closure<T: Into<String>, F: Fn(FunctionParameter) -> NativeCallResult + 'static>(mut self, name: T, function: F) -> Self {
+        self.closures.push((name.into(), Rc::new(function)));
+        self
+    }
+
+    /// Value that could be used with 'modül::isim' in the script.
+    pub fn constant<T: Into<String>>(mut self, name: T, value: KaramelPrimative) -> Self {
+        self.constants.insert(name.into(), Rc::new(value));
+        self
+    }
+
+    pub fn build(self) -> Rc<NativeModule> {
+        let module = Rc::new(NativeModule {
+            path: vec![self.name.to_string()],
+            name: self.name,
+            methods: RefCell::new(HashMap::new()),
+            constants: self.constants,
+        });
+
+        for (name, function, arguments) in self.functions {
+            let reference = match arguments {
+                Some(arguments) => FunctionReference::native_function_with_arguments(function, name.to_string(), arguments, module.clone()),
+                None => FunctionReference::native_function(function, name.to_string(), module.clone()),
+            };
+            module.methods.borrow_mut().insert(name, reference);
+        }
+
+        for (name, closure) in self.closures {
+            let reference = FunctionReference::native_closure(closure, name.to_string(), module.clone());
+            module.methods.borrow_mut().insert(name, reference);
+        }
+
+        module
+    }
+}
@@ -0,0 +1,286 @@
+use std::rc::Rc;
+
+use crate::buildin::class::baseclass::BasicInnerClass;
+use crate::buildin::date::{format_duration, is_leap_year, DateModule, DateParts, DAY_NAMES, DEFAULT_DATE_FORMAT, MILLISECONDS_IN_DAY, MILLISECONDS_IN_HOUR, MILLISECONDS_IN_MINUTE, MILLISECONDS_IN_SECOND, MONTH_NAMES};
+use crate::compiler::number::KaramelNumber;
+use crate::compiler::value::KaramelPrimative;
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::error::KaramelErrorType;
+use crate::types::VmObject;
+use crate::{
+    buildin::Class,
+    compiler::function::{FunctionParameter, NativeCallResult},
+};
+use crate::{expected_parameter_type, n_parameter_expected};
+
+pub fn get_primative_class() -> Rc<dyn Class> {
+    let mut opcode = BasicInnerClass::default();
+    opcode.set_name("tarih");
+
+    opcode.add_class_method("yıl", year);
+    opcode.add_class_method("yil", year);
+    opcode.add_class_method("ay", month);
+    opcode.add_class_method("gün", day);
+    opcode.add_class_method("gun", day);
+    opcode.add_class_method("saat", hour);
+    opcode.add_class_method("dakika", minute);
+    opcode.add_class_method("saniye", second);
+    opcode.add_class_method("milisaniye", millisecond);
+    opcode.add_class_method("haftanın_günü", weekday);
+    opcode.add_class_method("haftanin_gunu", weekday);
+    opcode.add_class_method("yılın_günü", day_of_year);
+    opcode.add_class_method("yilin_gunu", day_of_year);
+    opcode.add_class_method("gün_adı", day_name);
+    opcode.add_class_method("gun_adi", day_name);
+    opcode.add_class_method("ay_adı", month_name);
+    opcode.add_class_method("ay_adi", month_name);
+    opcode.add_class_method("artık_yıl_mı", leap_year);
+    opcode.add_class_method("artik_yil_mi", leap_year);
+    opcode.add_class_method("biçimlendir", format);
+    opcode.add_class_method("bicimlendir", format);
+    opcode.add_class_method("yazı", format);
+    opcode.add_class_method("yazi", format);
+    opcode.add_class_method("ekle", add);
+    opcode.add_class_method("gün_ekle", add_days);
+    opcode.add_class_method("gun_ekle", add_days);
+    opcode.add_class_method("ay_ekle", add_months);
+    opcode.add_class_method("yıl_ekle", add_years);
+    opcode.add_class_method("yil_ekle", add_years);
+    opcode.add_class_method("fark", difference);
+    opcode.add_class_method("zaman_damgası", timestamp);
+    opcode.add_class_method("zaman_damgasi", timestamp);
+
+    Rc::new(opcode)
+}
+
+pub fn get_duration_class() -> Rc<dyn Class> {
+    let mut opcode = BasicInnerClass::default();
+    opcode.set_name("süre");
+
+    opcode.add_class_method("gün", total_days);
+    opcode.add_class_method("gun", total_days);
+    opcode.add_class_method("saat", total_hours);
+    opcode.add_class_method("dakika", total_minutes);
+    opcode.add_class_method("saniye", total_seconds);
+    opcode.add_class_method("milisaniye", total_milliseconds);
+    opcode.add_class_method("yazı", duration_text);
+    opcode.add_class_method("yazi", duration_text);
+
+    Rc::new(opcode)
+}
+
+fn date_parts(parameter: &FunctionParameter) -> Option<DateParts> {
+    match &*parameter.source().unwrap().deref() {
+        KaramelPrimative::Date(millis) => Some(DateParts::from_millis(*millis)),
+        _ => None,
+    }
+}
+
+fn date_field(parameter: FunctionParameter, field: fn(&DateParts) -> i64) -> NativeCallResult {
+    match date_parts(&parameter) {
+        Some(parts) => Ok(VmObject::from(field(&parts))),
+        None => Ok(EMPTY_OBJECT),
+    }
+}
+
+fn year(parameter: FunctionParameter) -> NativeCallResult {
+    date_field(parameter, |parts| parts.year)
+}
+
+fn month(parameter: FunctionParameter) -> NativeCallResult {
+    date_field(parameter, |parts| parts.month)
+}
+
+fn day(parameter: FunctionParameter) -> NativeCallResult {
+    date_field(parameter, |parts| parts.day)
+}
+
+fn hour(parameter: FunctionParameter) -> NativeCallResult {
+    date_field(parameter, |parts| parts.hour)
+}
+
+fn minute(parameter: FunctionParameter) -> NativeCallResult {
+    date_field(parameter, |parts| parts.minute)
+}
+
+fn second(parameter: FunctionParameter) -> NativeCallResult {
+    date_field(parameter, |parts| parts.second)
+}
+
+fn millisecond(parameter: FunctionParameter) -> NativeCallResult {
+    date_field(parameter, |parts| parts.millisecond)
+}
+
+fn weekday(parameter: FunctionParameter) -> NativeCallResult {
+    date_field(parameter, DateParts::weekday)
+}
+
+fn day_of_year(parameter: FunctionParameter) -> NativeCallResult {
+    date_field(parameter, DateParts::day_of_year)
+}
+
+fn day_name(parameter: FunctionParameter) -> NativeCallResult {
+    match date_parts(&parameter) {
+        Some(parts) => Ok(VmObject::from(Rc::new(DAY_NAMES[parts.weekday() as usize - 1].to_string()))),
+        None => Ok(EMPTY_OBJECT),
+    }
+}
+
+fn month_name(parameter: FunctionParameter) -> NativeCallResult {
+    match date_parts(&parameter) {
+        Some(parts) => Ok(VmObject::from(Rc::new(MONTH_NAMES[parts.month as usize - 1].to_string()))),
+        None => Ok(EMPTY_OBJECT),
+    }
+}
+
+fn leap_year(parameter: FunctionParameter) -> NativeCallResult {
+    match date_parts(&parameter) {
+        Some(parts) => Ok(VmObject::from(is_leap_year(parts.year))),
+        None => Ok(EMPTY_OBJECT),
+    }
+}
+
+fn format(parameter: FunctionParameter) -> NativeCallResult {
+    if let Some(parts) = date_parts(&parameter) {
+        return match parameter.length() {
+            0 => Ok(VmObject::from(Rc::new(parts.format(DEFAULT_DATE_FORMAT)))),
+            1 => match &*parameter.iter().next().unwrap().deref() {
+                KaramelPrimative::Text(format) => Ok(VmObject::from(Rc::new(parts.format(format)))),
+                _ => expected_parameter_type!("biçimlendir".to_string(), "Yazı".to_string()),
+            },
+            _ => n_parameter_expected!("biçimlendir".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn new_date(millis: Option<i64>) -> NativeCallResult {
+    match millis {
+        Some(millis) => Ok(VmObject::native_convert(KaramelPrimative::Date(millis))),
+        None => Err(KaramelErrorType::DateNotValid),
+    }
+}
+
+fn add(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Date(millis) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            1 => match &*parameter.iter().next().unwrap().deref() {
+                KaramelPrimative::Duration(duration) => new_date(millis.checked_add(*duration)),
+                _ => expected_parameter_type!("ekle".to_string(), "Süre".to_string()),
+            },
+            _ => n_parameter_expected!("ekle".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn single_integer(parameter: &FunctionParameter, function: &str) -> Result<i64, KaramelErrorType> {
+    match parameter.length() {
+        1 => DateModule::integer(function, parameter.iter().next().unwrap()),
+        _ => n_parameter_expected!(function.to_string(), 1, parameter.length()),
+    }
+}
+
+fn add_days(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Date(millis) = &*parameter.source().unwrap().deref() {
+        let days = single_integer(&parameter, "gün_ekle")?;
+        return new_date(
+            days.checked_mul(MILLISECONDS_IN_DAY)
+                .and_then(|duration| millis.checked_add(duration)),
+        );
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn add_months(parameter: FunctionParameter) -> NativeCallResult {
+    if let Some(parts) = date_parts(&parameter) {
+        let months = single_integer(&parameter, "ay_ekle")?;
+        return new_date(parts.add_months(months).and_then(|parts| parts.to_millis()));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn add_years(parameter: FunctionParameter) -> NativeCallResult {
+    if let Some(parts) = date_parts(&parameter) {
+        let years = single_integer(&parameter, "yıl_ekle")?;
+        return new_date(
+            years
+                .checked_mul(12)
+                .and_then(|months| parts.add_months(months))
+                .and_then(|parts| parts.to_millis()),
+        );
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn difference(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Date(millis) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            1 => match &*parameter.iter().next().unwrap().deref() {
+                KaramelPrimative::Date(other) => Ok(VmObject::native_convert(KaramelPrimative::Duration(millis - other))),
+                _ => expected_parameter_type!("fark".to_string(), "Tarih".to_string()),
+            },
+            _ => n_parameter_expected!("fark".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn timestamp(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Date(millis) = &*parameter.source().unwrap().deref() {
+        return Ok(VmObject::from(millis.div_euclid(MILLISECONDS_IN_SECOND)));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+/// Total duration with the unit, fractional part kept.
+fn duration_total(parameter: FunctionParameter, unit: i64) -> NativeCallResult {
+    if let KaramelPrimative::Duration(millis) = &*parameter.source().unwrap().deref() {
+        return match millis % unit {
+            0 => Ok(VmObject::from(millis / unit)),
+            _ => Ok(VmObject::from(KaramelNumber::Integer(*millis).div(&KaramelNumber::Integer(unit))?)),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn total_days(parameter: FunctionParameter) -> NativeCallResult {
+    duration_total(parameter, MILLISECONDS_IN_DAY)
+}
+
+fn total_hours(parameter: FunctionParameter) -> NativeCallResult {
+    duration_total(parameter, MILLISECONDS_IN_HOUR)
+}
+
+fn total_minutes(parameter: FunctionParameter) -> NativeCallResult {
+    duration_total(parameter, MILLISECONDS_IN_MINUTE)
+}
+
+fn total_seconds(parameter: FunctionParameter) -> NativeCallResult {
+    duration_total(parameter, MILLISECONDS_IN_SECOND)
+}
+
+fn total_milliseconds(parameter: FunctionParameter) -> NativeCallResult {
+    duration_total(parameter, 1)
+}
+
+fn duration_text(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Duration(millis) = &*parameter.source().unwrap().deref() {
+        return Ok(VmObject::from(Rc::new(format_duration(*millis))));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nativecall_test;
+    use crate::primative_text;
+
+    nativecall_test! {test_year_1, year, KaramelPrimative::Date(DateParts::new(2023, 10, 29, 0, 0, 0).unwrap().to_millis().unwrap()), KaramelPrimative::Integer(2023)}
+    nativecall_test! {test_day_name_1, day_name, KaramelPrimative::Date(DateParts::new(2023, 10, 29, 0, 0, 0).unwrap().to_millis().unwrap()), primative_text!("Pazar")}
+    nativecall_test! {test_month_name_1, month_name, KaramelPrimative::Date(DateParts::new(2024, 2, 1, 0, 0, 0).unwrap().to_millis().unwrap()), primative_text!("Şubat")}
+    nativecall_test! {test_format_1, format, KaramelPrimative::Date(DateParts::new(2024, 2, 1, 8, 30, 0).unwrap().to_millis().unwrap()), primative_text!("2024-02-01 08:30:00")}
+    nativecall_test! {test_total_hours_1, total_hours, KaramelPrimative::Duration(90 * MILLISECONDS_IN_MINUTE), KaramelPrimative::Number(1.5)}
+    nativecall_test! {test_duration_text_1, duration_text, KaramelPrimative::Duration(MILLISECONDS_IN_DAY + MILLISECONDS_IN_MINUTE), primative_text!("1 gün 00:01:00")}
+}
@@ -1,9 +1,8 @@
-use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::buildin::class::baseclass::BasicInnerClass;
-use crate::compiler::value::KaramelPrimative;
 use crate::compiler::value::EMPTY_OBJECT;
+use crate::compiler::value::{KaramelDictKey, KaramelPrimative};
 use crate::types::VmObject;
 use crate::{arc_bool, expected_parameter_type, n_parameter_expected, primative_list};
 use crate::{
@@ -14,8 +13,6 @@ use crate::{
     },
 };
 
-use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
-
 #[derive(Default)]
 pub struct DictClass {
     base: BasicInnerClass,
@@ -41,11 +38,6 @@ impl DictClass {
         dict.add_class_method("sil", remove);
         dict.add_class_method("anahtarlar", keys);
 
-        PRIMATIVE_CLASS_NAMES
-            .lock()
-            .unwrap()
-            .insert(dict.get_type());
-
         dict
     }
 }
@@ -74,7 +66,7 @@ impl Class for DictClass {
                 Some(object) => match &*object.deref() {
                     KaramelPrimative::Dict(dict) => dict
                         .borrow()
-                        .get(&*field.clone())
+                        .get(&KaramelDictKey::Text(field.clone()))
                         .map(|data| ClassProperty::Field(data.deref())),
                     _ => None,
                 },
@@ -121,12 +113,12 @@ fn get(parameter: FunctionParameter) -> NativeCallResult {
         return match parameter.length() {
             0 => n_parameter_expected!("getir".to_string(), 1),
             1 => {
-                let key = match &*parameter.iter().next().unwrap().deref() {
-                    KaramelPrimative::Text(yazi) => yazi.clone(),
-                    _ => return expected_parameter_type!("anahtar".to_string(), "Yazı".to_string()),
+                let key = match KaramelDictKey::new(&parameter.iter().next().unwrap().deref()) {
+                    Some(key) => key,
+                    _ => return expected_parameter_type!("anahtar".to_string(), "Yazı ya da Demet".to_string()),
                 };
 
-                return match dict.borrow().get(&*key) {
+                return match dict.borrow().get(&key) {
                     Some(item) => Ok(*item),
                     _ => Ok(EMPTY_OBJECT),
                 };
@@ -153,14 +145,11 @@ fn insert_or_update(parameter: FunctionParameter, function_name: &str) -> Native
                 let mut iter = parameter.iter();
                 let (position_object, item) = (&*iter.next().unwrap().deref(), iter.next().unwrap());
 
-                let position = match position_object {
-                    KaramelPrimative::Text(text) => text.clone(),
-                    _ => return expected_parameter_type!("anahtar".to_string(), "Yazı".to_string()),
+                let position = match KaramelDictKey::new(position_object) {
+                    Some(key) => key,
+                    _ => return expected_parameter_type!("anahtar".to_string(), "Yazı ya da Demet".to_string()),
                 };
-                *dict
-                    .borrow_mut()
-                    .entry(position.to_string())
-                    .or_insert(*item) = *item;
+                *dict.borrow_mut().entry(position).or_insert(*item) = *item;
                 Ok(EMPTY_OBJECT)
             }
             _ => n_parameter_expected!(function_name.to_string(), 2, parameter.length()),
@@ -171,7 +160,7 @@ fn insert_or_update(parameter: FunctionParameter, function_name: &str) -> Native
 
 fn length(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Dict(dict) = &*parameter.source().unwrap().deref() {
-        let length = dict.borrow().len() as f64;
+        let length = dict.borrow().len();
         return Ok(VmObject::from(length));
     }
     Ok(EMPTY_OBJECT)
@@ -189,12 +178,12 @@ fn remove(parameter: FunctionParameter) -> NativeCallResult {
         return match parameter.length() {
             0 => n_parameter_expected!("sil".to_string(), 1),
             1 => {
-                let key = match &*parameter.iter().next().unwrap().deref() {
-                    KaramelPrimative::Text(text) => text.clone(),
-                    _ => return expected_parameter_type!("anahtar".to_string(), "Yazı".to_string()),
+                let key = match KaramelDictKey::new(&parameter.iter().next().unwrap().deref()) {
+                    Some(key) => key,
+                    _ => return expected_parameter_type!("anahtar".to_string(), "Yazı ya da Demet".to_string()),
                 };
 
-                Ok(match dict.borrow_mut().remove(&key.to_string()) {
+                Ok(match dict.borrow_mut().remove(&key) {
                     Some(_) => arc_bool!(true),
                     None => arc_bool!(false),
                 })
@@ -209,7 +198,7 @@ fn keys(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Dict(dict) = &*parameter.source().unwrap().deref() {
         let mut keys = Vec::new();
         for key in dict.borrow().keys() {
-            keys.push(VmObject::native_convert(KaramelPrimative::Text(Rc::new(key.to_string()))));
+            keys.push(VmObject::native_convert(key.to_primative()));
         }
 
         return Ok(VmObject::native_convert(primative_list!(keys)));
@@ -222,9 +211,9 @@ fn contains(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Dict(dict) = &*parameter.source().unwrap().deref() {
         return match parameter.length() {
             0 => n_parameter_expected!("içeriyormu".to_string(), 1),
-            1 => match &*parameter.iter().next().unwrap().deref() {
-                KaramelPrimative::Text(search) => Ok(VmObject::from(dict.borrow().contains_key(&**search))),
-                _ => expected_parameter_type!("içeriyormu".to_string(), "Yazı".to_string()),
+            1 => match KaramelDictKey::new(&parameter.iter().next().unwrap().deref()) {
+                Some(key) => Ok(VmObject::from(dict.borrow().contains_key(&key))),
+                _ => expected_parameter_type!("içeriyormu".to_string(), "Yazı ya da Demet".to_string()),
             },
             _ => n_parameter_expected!("içeriyormu".to_string(), 1, parameter.length()),
         };
@@ -1,7 +1,6 @@
 use std::rc::Rc;
 
 use crate::buildin::class::baseclass::BasicInnerClass;
-use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
 use crate::compiler::value::KaramelPrimative;
 use crate::compiler::value::EMPTY_OBJECT;
 use crate::types::VmObject;
@@ -24,13 +23,12 @@ pub fn get_primative_class() -> Rc<dyn Class> {
     opcode.add_class_method("arayaekle", insert);
     opcode.add_class_method("pop", pop);
     opcode.add_class_method("sil", remove);
+    opcode.add_class_method("eşle", map);
+    opcode.add_class_method("esle", map);
+    opcode.add_class_method("filtrele", filter);
     opcode.set_getter(getter);
     opcode.set_setter(setter);
 
-    PRIMATIVE_CLASS_NAMES
-        .lock()
-        .unwrap()
-        .insert(opcode.get_class_name());
     Rc::new(opcode)
 }
 
@@ -39,8 +37,8 @@ fn get(parameter: FunctionParameter) -> NativeCallResult {
         return match parameter.length() {
             0 => n_parameter_expected!("getir".to_string(), 1),
             1 => {
-                let position = match &*parameter.iter().next().unwrap().deref() {
-                    KaramelPrimative::Number(number) => *number as usize,
+                let position = match parameter.iter().next().unwrap().deref().as_f64() {
+                    Some(number) => number as usize,
                     _ => return expected_parameter_type!("sıra".to_string(), "Sayı".to_string()),
                 };
 
@@ -63,8 +61,8 @@ fn set(parameter: FunctionParameter) -> NativeCallResult {
                 let mut iter = parameter.iter();
                 let (position_object, item) = (&*iter.next().unwrap().deref(), iter.next().unwrap());
 
-                let position = match position_object {
-                    KaramelPrimative::Number(number) => *number,
+                let position = match position_object.as_f64() {
+                    Some(number) => number,
                     _ => return expected_parameter_type!("güncelle".to_string(), "Sayı".to_string()),
                 };
 
@@ -83,6 +81,45 @@ fn set(parameter: FunctionParameter) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
+/// New list with the results of the function that called for every item.
+fn map(mut parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            1 => {
+                let function = *parameter.iter().next().unwrap();
+                let items = list.borrow().clone();
+                let items = items
+                    .iter()
+                    .map(|item| parameter.call(function, &[*item]))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(VmObject::from(items))
+            }
+            _ => n_parameter_expected!("eşle".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+/// New list with the items that the function returns true.
+fn filter(mut parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            1 => {
+                let function = *parameter.iter().next().unwrap();
+                let mut items = Vec::new();
+                for item in list.borrow().clone() {
+                    if parameter.call(function, &[item])?.deref().is_true() {
+                        items.push(item);
+                    }
+                }
+                Ok(VmObject::from(items))
+            }
+            _ => n_parameter_expected!("filtrele".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
 fn getter(source: VmObject, index: f64) -> NativeCallResult {
     let index = match index >= 0.0 {
         true => index as usize,
@@ -123,7 +160,7 @@ fn setter(source: VmObject, index: f64, item: VmObject) -> NativeCallResult {
 
 fn length(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
-        let length = list.borrow().len() as f64;
+        let length = list.borrow().len();
         return Ok(VmObject::from(length));
     }
     Ok(EMPTY_OBJECT)
@@ -141,7 +178,7 @@ pub fn add(parameter: FunctionParameter) -> NativeCallResult {
         return match parameter.length() {
             0 => n_parameter_expected!("ekle".to_string(), 1),
             1 => {
-                let length = list.borrow().len() as f64;
+                let length = list.borrow().len();
                 list.borrow_mut().push(*parameter.iter().next().unwrap());
                 return Ok(VmObject::from(length));
             }
@@ -159,8 +196,8 @@ pub fn insert(parameter: FunctionParameter) -> NativeCallResult {
                 let mut iter = parameter.iter();
                 let (position_object, item) = (&*iter.next().unwrap().deref(), iter.next().unwrap());
 
-                let position = match position_object {
-                    KaramelPrimative::Number(number) => *number,
+                let position = match position_object.as_f64() {
+                    Some(number) => number,
                     _ => return expected_parameter_type!("arayaekle".to_string(), "Sayı".to_string()),
                 };
 
@@ -184,8 +221,8 @@ fn remove(parameter: FunctionParameter) -> NativeCallResult {
         match parameter.length() {
             0 => return n_parameter_expected!("sil".to_string(), 1),
             1 => {
-                let position = match &*parameter.iter().next().unwrap().deref() {
-                    KaramelPrimative::Number(number) => *number as usize,
+                let position = match parameter.iter().next().unwrap().deref().as_f64() {
+                    Some(number) => number as usize,
                     _ => return expected_parameter_type!("sıra".to_string(), "Sayı".to_string()),
                 };
 
@@ -238,17 +275,15 @@ mod tests {
     fn test_add_3() {
         use std::cell::RefCell;
         let stack: Vec<VmObject> = [arc_text!("merhaba")].to_vec();
-        let stdout = Some(RefCell::new(String::new()));
-        let stderr = Some(RefCell::new(String::new()));
         let list = KaramelPrimative::List(RefCell::new(Vec::new()));
         let obj = VmObject::native_convert(list);
 
-        let parameter = FunctionParameter::new(&stack, Some(obj), stack.len(), stack.len() as u8, &stdout, &stderr);
+        let parameter = FunctionParameter::new(&stack, Some(obj), stack.len(), stack.len() as u8);
         let result = add(parameter);
         assert!(result.is_ok());
 
         match &*result.unwrap().deref() {
-            KaramelPrimative::Number(p) => assert_eq!(*p, 0.0),
+            KaramelPrimative::Integer(p) => assert_eq!(*p, 0),
             _ => assert_eq!(true, false),
         };
     }
@@ -256,12 +291,10 @@ mod tests {
     #[test]
     fn test_insert_1() {
         use std::cell::RefCell;
-        let stdout = Some(RefCell::new(String::new()));
-        let stderr = Some(RefCell::new(String::new()));
         let list = Rc::new(KaramelPrimative::List(RefCell::new(Vec::new())));
         let obj = VmObject::native_convert_by_ref(list.clone());
 
-        let result = add(FunctionParameter::new([arc_text!("dünya")].as_ref(), Some(obj), 1_usize, 1_u8, &stdout, &stderr));
+        let result = add(FunctionParameter::new([arc_text!("dünya")].as_ref(), Some(obj), 1_usize, 1_u8));
         assert!(result.is_ok());
 
         match &*list {
@@ -269,7 +302,7 @@ mod tests {
             _ => assert_eq!(true, false),
         };
 
-        let result = insert(FunctionParameter::new([arc_number!(0), arc_text!("merhaba")].as_ref(), Some(obj), 2_usize, 2_u8, &stdout, &stderr));
+        let result = insert(FunctionParameter::new([arc_number!(0), arc_text!("merhaba")].as_ref(), Some(obj), 2_usize, 2_u8));
         assert!(result.is_ok());
 
         match &*list {
@@ -286,15 +319,13 @@ mod tests {
     fn test_clear_1() {
         use std::cell::RefCell;
         let stack: Vec<VmObject> = Vec::new();
-        let stdout = Some(RefCell::new(String::new()));
-        let stderr = Some(RefCell::new(String::new()));
         let list = Rc::new(KaramelPrimative::List(RefCell::new([arc_bool!(true), arc_empty!(), arc_number!(1)].to_vec())));
         let obj = VmObject::native_convert_by_ref(list.clone());
 
-        let result = add(FunctionParameter::new([arc_text!("dünya")].as_ref(), Some(obj), 1_usize, 1_u8, &stdout, &stderr));
+        let result = add(FunctionParameter::new([arc_text!("dünya")].as_ref(), Some(obj), 1_usize, 1_u8));
         assert!(result.is_ok());
 
-        let parameter = FunctionParameter::new(&stack, Some(obj), stack.len(), stack.len() as u8, &stdout, &stderr);
+        let parameter = FunctionParameter::new(&stack, Some(obj), stack.len(), stack.len() as u8);
         let result = clear(parameter);
         assert!(result.is_ok());
 
@@ -1,20 +1,17 @@
 pub mod baseclass;
+pub mod date;
 pub mod dict;
 pub mod list;
 pub mod number;
+pub mod pattern;
 pub mod proxy;
+pub mod set;
 pub mod text;
+pub mod tuple;
 
 use super::Class;
 use crate::buildin::class::baseclass::BasicInnerClass;
-use lazy_static::*;
-use std::{collections::HashSet, rc::Rc};
-
-use std::sync::Mutex;
-
-lazy_static! {
-    pub static ref PRIMATIVE_CLASS_NAMES: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
-}
+use std::rc::Rc;
 
 pub fn get_empty_class() -> Rc<dyn Class> {
     let mut opcode = BasicInnerClass::default();
@@ -27,12 +24,9 @@ macro_rules! nativecall_test {
     ($name:ident, $function_name:ident, $query:expr, $result:expr) => {
         #[test]
         fn $name() {
-            use std::cell::RefCell;
             let stack: Vec<VmObject> = Vec::new();
-            let stdout = Some(RefCell::new(String::new()));
-            let stderr = Some(RefCell::new(String::new()));
 
-            let parameter = FunctionParameter::new(&stack, Some(VmObject::native_convert($query)), 0, 0, &stdout, &stderr);
+            let parameter = FunctionParameter::new(&stack, Some(VmObject::native_convert($query)), 0, 0);
             let result = $function_name(parameter);
             assert!(result.is_ok());
             let object = result.unwrap().deref();
@@ -58,7 +52,7 @@ macro_rules! primative_number {
 #[macro_export]
 macro_rules! primative_list {
     ($list:expr) => {
-        KaramelPrimative::List(RefCell::new($list))
+        KaramelPrimative::List(std::cell::RefCell::new($list))
     };
 }
 
@@ -95,12 +89,9 @@ macro_rules! nativecall_test_with_params {
     ($name:ident, $function_name:ident, $query:expr, $params:expr, $result:expr) => {
         #[test]
         fn $name() {
-            use std::cell::RefCell;
             let stack: Vec<VmObject> = $params.to_vec();
-            let stdout = Some(RefCell::new(String::new()));
-            let stderr = Some(RefCell::new(String::new()));
 
-            let parameter = FunctionParameter::new(&stack, Some(VmObject::native_convert($query)), stack.len() as usize, stack.len() as u8, &stdout, &stderr);
+            let parameter = FunctionParameter::new(&stack, Some(VmObject::native_convert($query)), stack.len() as usize, stack.len() as u8);
             let result = $function_name(parameter);
             assert!(result.is_ok());
             let object = result.unwrap().deref();
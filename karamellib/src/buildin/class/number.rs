@@ -1,5 +1,5 @@
 use crate::buildin::class::BasicInnerClass;
-use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
+use crate::compiler::number::KaramelNumber;
 use crate::compiler::value::KaramelPrimative;
 use crate::compiler::value::EMPTY_OBJECT;
 use crate::types::VmObject;
@@ -8,6 +8,7 @@ use crate::{
     compiler::function::{FunctionParameter, NativeCallResult},
     expected_parameter_type, n_parameter_expected,
 };
+use num_rational::BigRational;
 
 use std::rc::Rc;
 
@@ -25,74 +26,95 @@ pub fn get_primative_class() -> Rc<dyn Class> {
     opcode.add_class_method("kesir", fract);
     opcode.add_class_method("üst", power);
 
-    PRIMATIVE_CLASS_NAMES
-        .lock()
-        .unwrap()
-        .insert(opcode.get_class_name());
     Rc::new(opcode)
 }
 
 fn hex(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        if number.fract() != 0.0 {
-            let as_int: u64 = (*number).to_bits();
-            return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(format!("0x{:x}", as_int)))));
-        }
+    if let Some(number) = parameter.source().unwrap().as_numeric() {
+        let text = match number {
+            KaramelNumber::Integer(number) => format!("0x{:x}", number),
+            KaramelNumber::BigInteger(number) => format!("0x{:x}", number),
+            number if number.as_f64().fract() != 0.0 => format!("0x{:x}", number.as_f64().to_bits()),
+            number => format!("0x{:x}", number.as_f64().trunc() as i64),
+        };
 
-        return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(format!("0x{:x}", number.trunc() as i64)))));
+        return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(text))));
     }
     Ok(EMPTY_OBJECT)
 }
 
 fn string(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(format!("{}", number)))));
+    if let Some(number) = parameter.source().unwrap().as_numeric() {
+        let text = match number {
+            KaramelNumber::Integer(number) => number.to_string(),
+            KaramelNumber::BigInteger(number) => number.to_string(),
+            KaramelNumber::Decimal(number) => number.to_plain_string(),
+            KaramelNumber::Fraction(number) => number.to_string(),
+            KaramelNumber::Float(number) => number.to_string(),
+        };
+
+        return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(text))));
     }
     Ok(EMPTY_OBJECT)
 }
 
-fn round(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::from(number.round()));
+fn round_with(parameter: FunctionParameter, float: fn(f64) -> f64, exact: fn(&BigRational) -> BigRational) -> NativeCallResult {
+    if let Some(number) = parameter.source().unwrap().as_numeric() {
+        return Ok(VmObject::from(number.round_with(float, exact)));
     }
     Ok(EMPTY_OBJECT)
 }
 
-fn ceil(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::from(number.ceil()));
+fn round(parameter: FunctionParameter) -> NativeCallResult {
+    match parameter.length() {
+        0 => round_with(parameter, f64::round, BigRational::round),
+        1 => {
+            let digits = match parameter
+                .iter()
+                .next()
+                .unwrap()
+                .as_numeric()
+                .and_then(|number| number.to_integer())
+            {
+                Some(KaramelNumber::Integer(digits)) => digits,
+                _ => return expected_parameter_type!("basamak".to_string(), "Sayı".to_string()),
+            };
+
+            match parameter.source().unwrap().as_numeric() {
+                Some(number) => Ok(VmObject::from(number.round_digits(digits))),
+                None => Ok(EMPTY_OBJECT),
+            }
+        }
+        _ => n_parameter_expected!("yuvarla".to_string(), 1, parameter.length()),
     }
-    Ok(EMPTY_OBJECT)
+}
+
+fn ceil(parameter: FunctionParameter) -> NativeCallResult {
+    round_with(parameter, f64::ceil, BigRational::ceil)
 }
 
 fn floor(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::from(number.floor()));
-    }
-    Ok(EMPTY_OBJECT)
+    round_with(parameter, f64::floor, BigRational::floor)
 }
 
 fn trunc(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::from(number.trunc()));
-    }
-    Ok(EMPTY_OBJECT)
+    round_with(parameter, f64::trunc, BigRational::trunc)
 }
 
 fn fract(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::from(number.fract()));
+    match parameter.source().unwrap().as_numeric() {
+        Some(number) => Ok(VmObject::from(number.sub(&number.round_with(f64::trunc, BigRational::trunc)))),
+        None => Ok(EMPTY_OBJECT),
     }
-    Ok(EMPTY_OBJECT)
 }
 
 fn power(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(sayi) = &*parameter.source().unwrap().deref() {
+    if let Some(sayi) = parameter.source().unwrap().as_numeric() {
         return match parameter.length() {
             0 => n_parameter_expected!("üst".to_string(), 1),
-            1 => match &*parameter.iter().next().unwrap().deref() {
-                KaramelPrimative::Number(pow) => Ok(VmObject::native_convert(KaramelPrimative::Number(sayi.powf(*pow)))),
-                _ => expected_parameter_type!("üst".to_string(), "Sayı".to_string()),
+            1 => match parameter.iter().next().unwrap().as_numeric() {
+                Some(pow) => Ok(VmObject::from(sayi.pow(&pow)?)),
+                None => expected_parameter_type!("üst".to_string(), "Sayı".to_string()),
             },
             _ => n_parameter_expected!("üst".to_string(), 1, parameter.length()),
         };
@@ -136,4 +158,9 @@ mod tests {
     nativecall_test! {test_tamsayi_2, trunc, KaramelPrimative::Number(122.51), KaramelPrimative::Number(122.0)}
 
     nativecall_test! {test_kesir_1, fract, KaramelPrimative::Number(-1.5), KaramelPrimative::Number(-0.5)}
+    nativecall_test! {test_kesir_2, fract, KaramelPrimative::Integer(10), KaramelPrimative::Integer(0)}
+
+    nativecall_test! {test_yazi_1, string, KaramelPrimative::Integer(-42), KaramelPrimative::Text(Rc::new("-42".to_string()))}
+    nativecall_test! {test_yazi_2, string, KaramelPrimative::BigInteger(num_bigint::BigInt::from(u64::MAX) * 10), KaramelPrimative::Text(Rc::new("184467440737095516150".to_string()))}
+    nativecall_test! {test_hex_4, hex, KaramelPrimative::Integer(255), KaramelPrimative::Text(Rc::new("0xff".to_string()))}
 }
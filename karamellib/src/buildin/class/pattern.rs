@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use regex::{Captures, Regex};
+
+use crate::buildin::class::baseclass::BasicInnerClass;
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::compiler::value::{KaramelDictKey, KaramelPrimative};
+use crate::error::KaramelErrorType;
+use crate::types::VmObject;
+use crate::{
+    buildin::Class,
+    compiler::function::{FunctionParameter, NativeCallResult},
+};
+use crate::{expected_parameter_type, n_parameter_expected};
+
+pub fn get_primative_class() -> Rc<dyn Class> {
+    let mut opcode = BasicInnerClass::default();
+    opcode.set_name("desen");
+
+    opcode.add_class_method("eşleşiyormu", is_match);
+    opcode.add_class_method("eslesiyormu", is_match);
+    opcode.add_class_method("bul", find);
+    opcode.add_class_method("tümünü_bul", find_all);
+    opcode.add_class_method("tumunu_bul", find_all);
+    opcode.add_class_method("değiştir", replace);
+    opcode.add_class_method("degistir", replace);
+    opcode.add_class_method("parçala", split);
+    opcode.add_class_method("parcala", split);
+    opcode.add_class_method("kalıp", source);
+    opcode.add_class_method("kalip", source);
+
+    Rc::new(opcode)
+}
+
+pub fn compile(pattern: &str) -> Result<Rc<Regex>, KaramelErrorType> {
+    match Regex::new(pattern) {
+        Ok(regex) => Ok(Rc::new(regex)),
+        Err(error) => Err(KaramelErrorType::PatternNotValid { pattern: pattern.to_string(), error: error.to_string() }),
+    }
+}
+
+/// Compiled pattern of the desen or text value.
+pub fn pattern_parameter(function: &str, parameter: &VmObject) -> Result<Rc<Regex>, KaramelErrorType> {
+    match &*parameter.deref() {
+        KaramelPrimative::Pattern(pattern) => Ok(pattern.clone()),
+        KaramelPrimative::Text(pattern) => compile(pattern),
+        _ => expected_parameter_type!(function.to_string(), "Desen".to_string()),
+    }
+}
+
+fn text_parameter(function: &str, parameter: &VmObject) -> Result<Rc<String>, KaramelErrorType> {
+    match &*parameter.deref() {
+        KaramelPrimative::Text(text) => Ok(text.clone()),
+        _ => expected_parameter_type!(function.to_string(), "Yazı".to_string()),
+    }
+}
+
+fn text_object(text: &str) -> VmObject {
+    VmObject::from(Rc::new(text.to_string()))
+}
+
+/// Matched text if the pattern has no group, dictionary for the named groups and list for the other groups.
+fn match_object(pattern: &Regex, captures: &Captures) -> VmObject {
+    let group = |index: usize| match captures.get(index) {
+        Some(item) => text_object(item.as_str()),
+        None => EMPTY_OBJECT,
+    };
+
+    if pattern.captures_len() == 1 {
+        return group(0);
+    }
+
+    match pattern.capture_names().any(|name| name.is_some()) {
+        true => {
+            let groups = pattern
+                .capture_names()
+                .enumerate()
+                .filter_map(|(index, name)| name.map(|name| (KaramelDictKey::from(name), group(index))))
+                .collect::<HashMap<_, _>>();
+            VmObject::from(groups)
+        }
+        false => VmObject::from((1..pattern.captures_len()).map(group).collect::<Vec<_>>()),
+    }
+}
+
+pub fn pattern_is_match(pattern: &Regex, text: &str) -> VmObject {
+    VmObject::from(pattern.is_match(text))
+}
+
+pub fn pattern_find(pattern: &Regex, text: &str) -> VmObject {
+    match pattern.captures(text) {
+        Some(captures) => match_object(pattern, &captures),
+        None => EMPTY_OBJECT,
+    }
+}
+
+pub fn pattern_find_all(pattern: &Regex, text: &str) -> VmObject {
+    VmObject::from(
+        pattern
+            .captures_iter(text)
+            .map(|captures| match_object(pattern, &captures))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Groups could be used in the new text with '$1' or '${isim}'.
+pub fn pattern_replace(pattern: &Regex, text: &str, replacement: &str) -> VmObject {
+    text_object(&pattern.replace_all(text, replacement))
+}
+
+pub fn pattern_split(pattern: &Regex, text: &str) -> VmObject {
+    VmObject::from(pattern.split(text).map(text_object).collect::<Vec<_>>())
+}
+
+/// Calls the operation with the pattern and the first text parameter.
+fn pattern_call(parameter: FunctionParameter, function: &str, size: u8, operation: fn(&Regex, &str, &FunctionParameter) -> NativeCallResult) -> NativeCallResult {
+    if let KaramelPrimative::Pattern(pattern) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => n_parameter_expected!(function.to_string(), size),
+            length if length == size => {
+                let text = text_parameter(function, parameter.iter().next().unwrap())?;
+                operation(pattern, &text, &parameter)
+            }
+            _ => n_parameter_expected!(function.to_string(), size, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn is_match(parameter: FunctionParameter) -> NativeCallResult {
+    pattern_call(parameter, "eşleşiyormu", 1, |pattern, text, _| Ok(pattern_is_match(pattern, text)))
+}
+
+fn find(parameter: FunctionParameter) -> NativeCallResult {
+    pattern_call(parameter, "bul", 1, |pattern, text, _| Ok(pattern_find(pattern, text)))
+}
+
+fn find_all(parameter: FunctionParameter) -> NativeCallResult {
+    pattern_call(parameter, "tümünü_bul", 1, |pattern, text, _| Ok(pattern_find_all(pattern, text)))
+}
+
+fn replace(parameter: FunctionParameter) -> NativeCallResult {
+    pattern_call(parameter, "değiştir", 2, |pattern, text, parameter| {
+        let replacement = text_parameter("değiştir", parameter.iter().nth(1).unwrap())?;
+        Ok(pattern_replace(pattern, text, &replacement))
+    })
+}
+
+fn split(parameter: FunctionParameter) -> NativeCallResult {
+    pattern_call(parameter, "parçala", 1, |pattern, text, _| Ok(pattern_split(pattern, text)))
+}
+
+fn source(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Pattern(pattern) = &*parameter.source().unwrap().deref() {
+        return Ok(text_object(pattern.as_str()));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nativecall_test_with_params;
+    use crate::{arc_text, primative_text};
+    use std::cell::RefCell;
+
+    fn primative_pattern(pattern: &str) -> KaramelPrimative {
+        KaramelPrimative::Pattern(compile(pattern).unwrap())
+    }
+
+    fn primative_list(items: &[&str]) -> KaramelPrimative {
+        KaramelPrimative::List(RefCell::new(items.iter().map(|item| arc_text!(*item)).collect()))
+    }
+
+    nativecall_test_with_params! {test_is_match_1, is_match, primative_pattern(r"^\w+$"), [arc_text!("çağrışım")], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params! {test_is_match_2, is_match, primative_pattern(r"^\d+$"), [arc_text!("12a")], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params! {test_find_1, find, primative_pattern(r"\d+"), [arc_text!("a12b34")], primative_text!("12")}
+    nativecall_test_with_params! {test_find_2, find, primative_pattern(r"\d+"), [arc_text!("karamel")], KaramelPrimative::Empty}
+    nativecall_test_with_params! {test_find_all_1, find_all, primative_pattern(r"\w+"), [arc_text!("Iğdır, Şırnak ve Çorum")], primative_list(&["Iğdır", "Şırnak", "ve", "Çorum"])}
+    nativecall_test_with_params! {test_replace_1, replace, primative_pattern(r"(\w+)@(\w+)"), [arc_text!("ali@karamel"), arc_text!("$2:$1")], primative_text!("karamel:ali")}
+    nativecall_test_with_params! {test_split_1, split, primative_pattern(r"\s*,\s*"), [arc_text!("elma , armut,kiraz")], primative_list(&["elma", "armut", "kiraz"])}
+
+    #[test]
+    fn test_groups() {
+        let pattern = compile(r"(\d+)-(\d+)?").unwrap();
+        assert_eq!(*pattern_find(&pattern, "10-").deref(), KaramelPrimative::List(RefCell::new(vec![arc_text!("10"), EMPTY_OBJECT])));
+
+        let pattern = compile(r"(?P<yıl>\d{4})-(?P<ay>\d{2})").unwrap();
+        match &*pattern_find(&pattern, "2024-05").deref() {
+            KaramelPrimative::Dict(items) => assert_eq!(*items.borrow()[&KaramelDictKey::from("yıl")].deref(), primative_text!("2024")),
+            _ => panic!("Sözlük bekleniyordu"),
+        };
+
+        assert!(compile("(").is_err());
+    }
+}
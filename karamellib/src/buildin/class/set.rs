@@ -0,0 +1,218 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use indexmap::IndexSet;
+
+use crate::buildin::class::baseclass::BasicInnerClass;
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::compiler::value::{KaramelDictKey, KaramelPrimative};
+use crate::error::KaramelErrorType;
+use crate::types::VmObject;
+use crate::{arc_bool, expected_parameter_type, n_parameter_expected};
+use crate::{
+    buildin::Class,
+    compiler::function::{FunctionParameter, NativeCallResult},
+};
+
+pub fn get_primative_class() -> Rc<dyn Class> {
+    let mut opcode = BasicInnerClass::default();
+    opcode.set_name("küme");
+
+    opcode.add_class_method("ekle", add);
+    opcode.add_class_method("sil", remove);
+    opcode.add_class_method("içeriyormu", contains);
+    opcode.add_class_method("iceriyormu", contains);
+    opcode.add_class_method("uzunluk", length);
+    opcode.add_class_method("temizle", clear);
+    opcode.add_class_method("liste", to_list);
+    opcode.add_class_method("birleşim", union);
+    opcode.add_class_method("birlesim", union);
+    opcode.add_class_method("kesişim", intersection);
+    opcode.add_class_method("kesisim", intersection);
+    opcode.add_class_method("fark", difference);
+    opcode.add_class_method("altkümemi", is_subset);
+    opcode.add_class_method("altkumemi", is_subset);
+    opcode.add_class_method("üstkümemi", is_superset);
+    opcode.add_class_method("ustkumemi", is_superset);
+    opcode.set_getter(getter);
+
+    Rc::new(opcode)
+}
+
+/// Set key of the value, mutable values could not be added to a set.
+pub fn set_item(item: &VmObject) -> Result<KaramelDictKey, KaramelErrorType> {
+    let item = item.deref();
+    match KaramelDictKey::hashable(&item) {
+        Some(key) => Ok(key),
+        None => Err(KaramelErrorType::SetItemNotValid(item.clone())),
+    }
+}
+
+/// Items of the set, list or tuple value.
+pub fn set_items(function: &str, source: &VmObject) -> Result<IndexSet<KaramelDictKey>, KaramelErrorType> {
+    match &*source.deref() {
+        KaramelPrimative::Set(items) => Ok(items.borrow().clone()),
+        KaramelPrimative::List(items) => items.borrow().iter().map(set_item).collect(),
+        KaramelPrimative::Tuple(items) => items.iter().map(set_item).collect(),
+        _ => expected_parameter_type!(function.to_string(), "Küme".to_string()),
+    }
+}
+
+fn add(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => n_parameter_expected!("ekle".to_string(), 1),
+            1 => {
+                let item = set_item(parameter.iter().next().unwrap())?;
+                Ok(arc_bool!(items.borrow_mut().insert(item)))
+            }
+            _ => n_parameter_expected!("ekle".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn remove(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => n_parameter_expected!("sil".to_string(), 1),
+            1 => match KaramelDictKey::hashable(&parameter.iter().next().unwrap().deref()) {
+                Some(item) => Ok(arc_bool!(items.borrow_mut().shift_remove(&item))),
+                None => Ok(arc_bool!(false)),
+            },
+            _ => n_parameter_expected!("sil".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn contains(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => n_parameter_expected!("içeriyormu".to_string(), 1),
+            1 => match KaramelDictKey::hashable(&parameter.iter().next().unwrap().deref()) {
+                Some(item) => Ok(arc_bool!(items.borrow().contains(&item))),
+                None => Ok(arc_bool!(false)),
+            },
+            _ => n_parameter_expected!("içeriyormu".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn length(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        return Ok(VmObject::from(items.borrow().len()));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn clear(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        items.borrow_mut().clear();
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn to_list(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        let list = items
+            .borrow()
+            .iter()
+            .map(|item| VmObject::native_convert(item.to_primative()))
+            .collect();
+        return Ok(VmObject::native_convert(KaramelPrimative::List(RefCell::new(list))));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+/// Create new set from the source set and the other set with given operation.
+fn set_operation(parameter: FunctionParameter, function: &str, operation: fn(&IndexSet<KaramelDictKey>, &IndexSet<KaramelDictKey>) -> IndexSet<KaramelDictKey>) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => n_parameter_expected!(function.to_string(), 1),
+            1 => {
+                let other = set_items(function, parameter.iter().next().unwrap())?;
+                let result = operation(&items.borrow(), &other);
+                Ok(VmObject::native_convert(KaramelPrimative::Set(RefCell::new(result))))
+            }
+            _ => n_parameter_expected!(function.to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn union(parameter: FunctionParameter) -> NativeCallResult {
+    set_operation(parameter, "birleşim", |left, right| left.union(right).cloned().collect())
+}
+
+fn intersection(parameter: FunctionParameter) -> NativeCallResult {
+    set_operation(parameter, "kesişim", |left, right| left.intersection(right).cloned().collect())
+}
+
+fn difference(parameter: FunctionParameter) -> NativeCallResult {
+    set_operation(parameter, "fark", |left, right| left.difference(right).cloned().collect())
+}
+
+fn set_check(parameter: FunctionParameter, function: &str, check: fn(&IndexSet<KaramelDictKey>, &IndexSet<KaramelDictKey>) -> bool) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => n_parameter_expected!(function.to_string(), 1),
+            1 => {
+                let other = set_items(function, parameter.iter().next().unwrap())?;
+                Ok(arc_bool!(check(&items.borrow(), &other)))
+            }
+            _ => n_parameter_expected!(function.to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn is_subset(parameter: FunctionParameter) -> NativeCallResult {
+    set_check(parameter, "altkümemi", |left, right| left.is_subset(right))
+}
+
+fn is_superset(parameter: FunctionParameter) -> NativeCallResult {
+    set_check(parameter, "üstkümemi", |left, right| left.is_superset(right))
+}
+
+fn getter(source: VmObject, index: f64) -> NativeCallResult {
+    let index = match index >= 0.0 {
+        true => index as usize,
+        false => return Ok(EMPTY_OBJECT),
+    };
+
+    if let KaramelPrimative::Set(items) = &*source.deref() {
+        return match items.borrow().get_index(index) {
+            Some(item) => Ok(VmObject::native_convert(item.to_primative())),
+            _ => Ok(EMPTY_OBJECT),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::value::KaramelPrimative;
+    use crate::nativecall_test;
+    use crate::nativecall_test_with_params;
+    use crate::{arc_number, arc_text, primative_text};
+
+    fn primative_set(items: &[KaramelDictKey]) -> KaramelPrimative {
+        KaramelPrimative::Set(RefCell::new(items.iter().cloned().collect()))
+    }
+
+    nativecall_test! {test_length_1, length, primative_set(&[KaramelDictKey::Integer(1), KaramelDictKey::from("karamel")]), KaramelPrimative::Integer(2)}
+    nativecall_test! {test_length_2, length, primative_set(&[]), KaramelPrimative::Integer(0)}
+    nativecall_test_with_params! {test_add_1, add, primative_set(&[KaramelDictKey::Integer(1)]), [arc_number!(2)], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params! {test_add_2, add, primative_set(&[KaramelDictKey::Integer(1)]), [arc_number!(1)], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params! {test_contains_1, contains, primative_set(&[KaramelDictKey::from("karamel")]), [arc_text!("karamel")], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params! {test_contains_2, contains, primative_set(&[KaramelDictKey::from("karamel")]), [arc_number!(1)], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params! {test_remove_1, remove, primative_set(&[KaramelDictKey::Integer(1)]), [arc_number!(1)], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params! {test_union_1, union, primative_set(&[KaramelDictKey::Integer(1)]), [VmObject::native_convert(primative_set(&[KaramelDictKey::Integer(2)]))], primative_set(&[KaramelDictKey::Integer(2), KaramelDictKey::Integer(1)])}
+    nativecall_test_with_params! {test_intersection_1, intersection, primative_set(&[KaramelDictKey::Integer(1), KaramelDictKey::Integer(2)]), [VmObject::native_convert(primative_set(&[KaramelDictKey::Integer(2)]))], primative_set(&[KaramelDictKey::Integer(2)])}
+    nativecall_test_with_params! {test_difference_1, difference, primative_set(&[KaramelDictKey::Integer(1), KaramelDictKey::Integer(2)]), [VmObject::native_convert(primative_set(&[KaramelDictKey::Integer(2)]))], primative_set(&[KaramelDictKey::Integer(1)])}
+    nativecall_test_with_params! {test_subset_1, is_subset, primative_set(&[KaramelDictKey::Integer(1)]), [VmObject::native_convert(primative_set(&[KaramelDictKey::Integer(1), KaramelDictKey::Integer(2)]))], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params! {test_superset_1, is_superset, primative_set(&[KaramelDictKey::Integer(1)]), [VmObject::native_convert(primative_set(&[KaramelDictKey::Integer(1), KaramelDictKey::Integer(2)]))], KaramelPrimative::Bool(false)}
+}
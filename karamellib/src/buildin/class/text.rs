@@ -1,5 +1,6 @@
 use crate::buildin::class::baseclass::BasicInnerClass;
-use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
+use crate::buildin::class::pattern::{pattern_find_all, pattern_is_match, pattern_parameter, pattern_replace, pattern_split};
+use crate::compiler::number::KaramelNumber;
 use crate::compiler::value::KaramelPrimative;
 use crate::compiler::value::EMPTY_OBJECT;
 use crate::primative_text;
@@ -42,13 +43,17 @@ pub fn get_primative_class() -> Rc<dyn Class> {
     opcode.add_class_method("sayı", number);
     opcode.add_class_method("sayi", number);
     opcode.add_class_method("levenshtein", levenshtein);
+    opcode.add_class_method("eşleşiyormu", is_match);
+    opcode.add_class_method("eslesiyormu", is_match);
+    opcode.add_class_method("tümünü_bul", find_all);
+    opcode.add_class_method("tumunu_bul", find_all);
+    opcode.add_class_method("regex_değiştir", regex_replace);
+    opcode.add_class_method("regex_degistir", regex_replace);
+    opcode.add_class_method("regex_parçala", regex_split);
+    opcode.add_class_method("regex_parcala", regex_split);
     opcode.set_getter(getter);
     opcode.set_setter(setter);
 
-    PRIMATIVE_CLASS_NAMES
-        .lock()
-        .unwrap()
-        .insert(opcode.get_class_name());
     Rc::new(opcode)
 }
 
@@ -118,7 +123,7 @@ fn setter(source: VmObject, index: f64, item: VmObject) -> NativeCallResult {
 
 fn length(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::native_convert(KaramelPrimative::Number(text.chars().count() as f64)));
+        return Ok(VmObject::from(text.chars().count()));
     }
     Ok(EMPTY_OBJECT)
 }
@@ -128,7 +133,7 @@ fn levenshtein(parameter: FunctionParameter) -> NativeCallResult {
         return match parameter.length() {
             0 => n_parameter_expected!("levenshtein".to_string(), 1),
             1 => match &*parameter.iter().next().unwrap().deref() {
-                KaramelPrimative::Text(search) => Ok(VmObject::native_convert(KaramelPrimative::Number(levenshtein::levenshtein(text, search) as f64))),
+                KaramelPrimative::Text(search) => Ok(VmObject::from(levenshtein::levenshtein(text, search))),
                 _ => expected_parameter_type!("levenshtein".to_string(), "Yazı".to_string()),
             },
             _ => n_parameter_expected!("levenshtein".to_string(), 1, parameter.length()),
@@ -139,8 +144,8 @@ fn levenshtein(parameter: FunctionParameter) -> NativeCallResult {
 
 fn number(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
-        return match text.parse::<f64>() {
-            Ok(num) => Ok(VmObject::native_convert(KaramelPrimative::Number(num))),
+        return match KaramelNumber::parse(text) {
+            Some(num) => Ok(VmObject::from(num)),
             _ => Ok(EMPTY_OBJECT),
         };
     }
@@ -242,7 +247,7 @@ fn find(parameter: FunctionParameter) -> NativeCallResult {
             0 => n_parameter_expected!("parçala".to_string(), 1),
             1 => match &*parameter.iter().next().unwrap().deref() {
                 KaramelPrimative::Text(search) => match text.find(&**search) {
-                    Some(location) => Ok(VmObject::native_convert(KaramelPrimative::Number(UnicodeWidthStr::width(&text[..location]) as f64))),
+                    Some(location) => Ok(VmObject::from(UnicodeWidthStr::width(&text[..location]))),
                     _ => Ok(EMPTY_OBJECT),
                 },
                 _ => expected_parameter_type!("parçala".to_string(), "Yazı".to_string()),
@@ -271,6 +276,57 @@ fn replace(parameter: FunctionParameter) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
+fn is_match(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => n_parameter_expected!("eşleşiyormu".to_string(), 1),
+            1 => Ok(pattern_is_match(&*pattern_parameter("eşleşiyormu", parameter.iter().next().unwrap())?, text)),
+            _ => n_parameter_expected!("eşleşiyormu".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn find_all(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => n_parameter_expected!("tümünü_bul".to_string(), 1),
+            1 => Ok(pattern_find_all(&*pattern_parameter("tümünü_bul", parameter.iter().next().unwrap())?, text)),
+            _ => n_parameter_expected!("tümünü_bul".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn regex_replace(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => n_parameter_expected!("regex_değiştir".to_string(), 2),
+            2 => {
+                let mut iter = parameter.iter();
+                let pattern = pattern_parameter("regex_değiştir", iter.next().unwrap())?;
+                match &*iter.next().unwrap().deref() {
+                    KaramelPrimative::Text(to) => Ok(pattern_replace(&pattern, text, to)),
+                    _ => expected_parameter_type!("regex_değiştir".to_string(), "Yazı".to_string()),
+                }
+            }
+            _ => n_parameter_expected!("regex_değiştir".to_string(), 2, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn regex_split(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => n_parameter_expected!("regex_parçala".to_string(), 1),
+            1 => Ok(pattern_split(&*pattern_parameter("regex_parçala", parameter.iter().next().unwrap())?, text)),
+            _ => n_parameter_expected!("regex_parçala".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
 fn trim(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
         return Ok(VmObject::native_convert(primative_text!(text.trim())));
@@ -299,16 +355,16 @@ fn substring(parameter: FunctionParameter) -> NativeCallResult {
             2 => {
                 let mut iter = parameter.iter();
                 let (from, to) = (&*iter.next().unwrap().deref(), &*iter.next().unwrap().deref());
-                match (from, to) {
-                    (KaramelPrimative::Number(start), KaramelPrimative::Number(end)) => {
-                        let start_size = if (*start as i64) < 0 {
+                match (from.as_f64(), to.as_f64()) {
+                    (Some(start), Some(end)) => {
+                        let start_size = if (start as i64) < 0 {
                             0_usize
                         } else {
-                            *start as usize
+                            start as usize
                         };
 
-                        let end_size = if (*end as usize) < text.len() {
-                            *end as usize
+                        let end_size = if (end as usize) < text.len() {
+                            end as usize
                         } else {
                             text.len()
                         };
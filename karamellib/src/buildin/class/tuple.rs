@@ -0,0 +1,79 @@
+use std::rc::Rc;
+
+use crate::buildin::class::baseclass::BasicInnerClass;
+use crate::compiler::value::KaramelPrimative;
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::types::VmObject;
+use crate::{
+    buildin::Class,
+    compiler::function::{FunctionParameter, NativeCallResult},
+};
+use crate::{expected_parameter_type, n_parameter_expected};
+
+pub fn get_primative_class() -> Rc<dyn Class> {
+    let mut opcode = BasicInnerClass::default();
+    opcode.set_name("demet");
+
+    opcode.add_class_method("getir", get);
+    opcode.add_class_method("uzunluk", length);
+    opcode.set_getter(getter);
+
+    Rc::new(opcode)
+}
+
+fn get(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Tuple(items) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => n_parameter_expected!("getir".to_string(), 1),
+            1 => {
+                let position = match parameter.iter().next().unwrap().deref().as_f64() {
+                    Some(number) => number as usize,
+                    _ => return expected_parameter_type!("sıra".to_string(), "Sayı".to_string()),
+                };
+
+                return match items.get(position) {
+                    Some(item) => Ok(*item),
+                    _ => Ok(EMPTY_OBJECT),
+                };
+            }
+            _ => n_parameter_expected!("getir".to_string(), 1, parameter.length()),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn getter(source: VmObject, index: f64) -> NativeCallResult {
+    let index = match index >= 0.0 {
+        true => index as usize,
+        false => return Ok(EMPTY_OBJECT),
+    };
+
+    if let KaramelPrimative::Tuple(items) = &*source.deref() {
+        return match items.get(index) {
+            Some(item) => Ok(*item),
+            _ => Ok(EMPTY_OBJECT),
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn length(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Tuple(items) = &*parameter.source().unwrap().deref() {
+        return Ok(VmObject::from(items.len()));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::value::KaramelPrimative;
+    use crate::nativecall_test;
+    use crate::nativecall_test_with_params;
+    use crate::{arc_bool, arc_number, arc_text, primative_text};
+
+    nativecall_test! {test_length_1, length, KaramelPrimative::Tuple([arc_text!(""), arc_bool!(true)].to_vec()), KaramelPrimative::Number(2.0)}
+    nativecall_test! {test_length_2, length, KaramelPrimative::Tuple(Vec::new()), KaramelPrimative::Number(0.0)}
+    nativecall_test_with_params! {test_get_1, get, KaramelPrimative::Tuple([arc_number!(1), arc_text!("karamel")].to_vec()), [arc_number!(1)], primative_text!("karamel")}
+    nativecall_test_with_params! {test_get_2, get, KaramelPrimative::Tuple([arc_number!(1)].to_vec()), [arc_number!(5)], KaramelPrimative::Empty}
+}
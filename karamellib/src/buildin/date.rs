@@ -0,0 +1,559 @@
+use crate::buildin::{Class, Module};
+use crate::compiler::function::{FunctionArgument, FunctionParameter, FunctionReference, NativeCall, NativeCallResult};
+use crate::compiler::value::KaramelPrimative;
+use crate::error::KaramelErrorType;
+use crate::expected_parameter_type;
+use crate::types::VmObject;
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{cell::RefCell, collections::HashMap};
+
+pub const MILLISECONDS_IN_SECOND: i64 = 1000;
+pub const MILLISECONDS_IN_MINUTE: i64 = 60 * MILLISECONDS_IN_SECOND;
+pub const MILLISECONDS_IN_HOUR: i64 = 60 * MILLISECONDS_IN_MINUTE;
+pub const MILLISECONDS_IN_DAY: i64 = 24 * MILLISECONDS_IN_HOUR;
+
+/// Years that could be kept as milliseconds in i64.
+pub const MAX_YEAR: i64 = 292_000_000;
+
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+pub const MONTH_NAMES: [&str; 12] = ["Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül", "Ekim", "Kasım", "Aralık"];
+pub const DAY_NAMES: [&str; 7] = ["Pazartesi", "Salı", "Çarşamba", "Perşembe", "Cuma", "Cumartesi", "Pazar"];
+
+/// Time source of the 'tarih' module. Hosts could inject their own clock to get reproducible results.
+pub trait Clock {
+    /// Milliseconds since 1970-01-01 00:00:00 UTC.
+    fn now(&self) -> i64;
+
+    /// Difference between the local time and UTC in seconds.
+    fn utc_offset(&self) -> i64 {
+        0
+    }
+}
+
+/// Clock of the operating system, works with UTC.
+#[derive(Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> i64 {
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_millis() as i64,
+            Err(error) => -(error.duration().as_millis() as i64),
+        }
+    }
+}
+
+/// Clock that always returns the same time.
+pub struct FixedClock(pub i64);
+
+impl Clock for FixedClock {
+    fn now(&self) -> i64 {
+        self.0
+    }
+}
+
+pub fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Calendar fields of the date. Dates are kept as milliseconds since 1970-01-01 without time zone.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DateParts {
+    pub year: i64,
+    pub month: i64,
+    pub day: i64,
+    pub hour: i64,
+    pub minute: i64,
+    pub second: i64,
+    pub millisecond: i64,
+}
+
+impl DateParts {
+    pub fn new(year: i64, month: i64, day: i64, hour: i64, minute: i64, second: i64) -> Result<DateParts, KaramelErrorType> {
+        let parts = DateParts { year, month, day, hour, minute, second, millisecond: 0 };
+        match parts.is_valid() {
+            true => Ok(parts),
+            false => Err(KaramelErrorType::DateNotValid),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        (-MAX_YEAR..=MAX_YEAR).contains(&self.year) && (1..=12).contains(&self.month) && (1..=days_in_month(self.year, self.month)).contains(&self.day) && (0..24).contains(&self.hour) && (0..60).contains(&self.minute) && (0..60).contains(&self.second) && (0..1000).contains(&self.millisecond)
+    }
+
+    /* Civil calendar conversions are based on Howard Hinnant's 'days_from_civil' and 'civil_from_days' algorithms */
+    fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+        let year = if month <= 2 {
+            year - 1
+        } else {
+            year
+        };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year =
+            (153 * (if month > 2 {
+                month - 3
+            } else {
+                month + 9
+            }) + 2)
+                / 5
+                + day
+                - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    fn civil_from_days(days: i64) -> (i64, i64, i64) {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400;
+        (
+            if month <= 2 {
+                year + 1
+            } else {
+                year
+            },
+            month,
+            day,
+        )
+    }
+
+    pub fn from_millis(millis: i64) -> DateParts {
+        let days = millis.div_euclid(MILLISECONDS_IN_DAY);
+        let time = millis.rem_euclid(MILLISECONDS_IN_DAY);
+        let (year, month, day) = Self::civil_from_days(days);
+
+        DateParts {
+            year,
+            month,
+            day,
+            hour: time / MILLISECONDS_IN_HOUR,
+            minute: (time % MILLISECONDS_IN_HOUR) / MILLISECONDS_IN_MINUTE,
+            second: (time % MILLISECONDS_IN_MINUTE) / MILLISECONDS_IN_SECOND,
+            millisecond: time % MILLISECONDS_IN_SECOND,
+        }
+    }
+
+    /// Milliseconds of the date, dates near the year limits could not be represented.
+    pub fn to_millis(&self) -> Option<i64> {
+        let time = self.hour * MILLISECONDS_IN_HOUR + self.minute * MILLISECONDS_IN_MINUTE + self.second * MILLISECONDS_IN_SECOND + self.millisecond;
+        Self::days_from_civil(self.year, self.month, self.day)
+            .checked_mul(MILLISECONDS_IN_DAY)?
+            .checked_add(time)
+    }
+
+    /// Day of the week, Monday is 1 and Sunday is 7.
+    pub fn weekday(&self) -> i64 {
+        (Self::days_from_civil(self.year, self.month, self.day) + 3).rem_euclid(7) + 1
+    }
+
+    pub fn day_of_year(&self) -> i64 {
+        Self::days_from_civil(self.year, self.month, self.day) - Self::days_from_civil(self.year, 1, 1) + 1
+    }
+
+    /// Months added, day of the month limited with the last day of the new month. Dates out of the year limits are not created.
+    pub fn add_months(&self, months: i64) -> Option<DateParts> {
+        let index = self
+            .year
+            .checked_mul(12)?
+            .checked_add(self.month - 1)?
+            .checked_add(months)?;
+        let (year, month) = (index.div_euclid(12), index.rem_euclid(12) + 1);
+        let parts = DateParts {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+            ..*self
+        };
+        parts.is_valid().then_some(parts)
+    }
+
+    /// Date written with the format. '%Y', '%m', '%d', '%H', '%M', '%S', '%B' (month name) and '%A' (day name) are supported.
+    pub fn format(&self, format: &str) -> String {
+        let mut result = String::new();
+        let mut chars = format.chars();
+
+        while let Some(ch) = chars.next() {
+            if ch != '%' {
+                result.push(ch);
+                continue;
+            }
+
+            match chars.next() {
+                Some('Y') => result.push_str(&format!("{:04}", self.year)),
+                Some('m') => result.push_str(&format!("{:02}", self.month)),
+                Some('d') => result.push_str(&format!("{:02}", self.day)),
+                Some('H') => result.push_str(&format!("{:02}", self.hour)),
+                Some('M') => result.push_str(&format!("{:02}", self.minute)),
+                Some('S') => result.push_str(&format!("{:02}", self.second)),
+                Some('B') => result.push_str(MONTH_NAMES[self.month as usize - 1]),
+                Some('A') => result.push_str(DAY_NAMES[self.weekday() as usize - 1]),
+                Some(other) => {
+                    result.push('%');
+                    result.push(other);
+                }
+                None => result.push('%'),
+            }
+        }
+        result
+    }
+
+    /// Date read with the format, same fields of the 'format' function are supported. Missing fields are 1 for date and 0 for time.
+    pub fn parse(text: &str, format: &str) -> Result<DateParts, KaramelErrorType> {
+        let error = || KaramelErrorType::DateParseFailed(text.to_string());
+        let mut parts = DateParts {
+            year: 1970,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+        };
+        let mut rest = text.trim();
+        let mut format_chars = format.chars();
+
+        while let Some(ch) = format_chars.next() {
+            if ch != '%' {
+                rest = rest.strip_prefix(ch).ok_or_else(error)?;
+                continue;
+            }
+
+            let field = format_chars.next().ok_or_else(error)?;
+            match field {
+                'B' | 'A' => {
+                    let names: &[&str] = if field == 'B' {
+                        &MONTH_NAMES
+                    } else {
+                        &DAY_NAMES
+                    };
+                    let lower_rest = rest.to_lowercase();
+                    let (index, name) = names
+                        .iter()
+                        .enumerate()
+                        .find(|(_, name)| lower_rest.starts_with(&name.to_lowercase()))
+                        .ok_or_else(error)?;
+
+                    if field == 'B' {
+                        parts.month = index as i64 + 1;
+                    }
+                    rest = &rest[rest
+                        .char_indices()
+                        .nth(name.chars().count())
+                        .map_or(rest.len(), |(position, _)| position)..];
+                }
+                '%' => rest = rest.strip_prefix('%').ok_or_else(error)?,
+                _ => {
+                    let max_digits = if field == 'Y' {
+                        4
+                    } else {
+                        2
+                    };
+                    let length = rest
+                        .chars()
+                        .take(max_digits)
+                        .take_while(|ch| ch.is_ascii_digit())
+                        .count();
+                    let number = rest[..length].parse::<i64>().map_err(|_| error())?;
+                    rest = &rest[length..];
+
+                    match field {
+                        'Y' => parts.year = number,
+                        'm' => parts.month = number,
+                        'd' => parts.day = number,
+                        'H' => parts.hour = number,
+                        'M' => parts.minute = number,
+                        'S' => parts.second = number,
+                        _ => return Err(error()),
+                    }
+                }
+            }
+        }
+
+        match rest.is_empty() && parts.is_valid() {
+            true => Ok(parts),
+            false => Err(error()),
+        }
+    }
+}
+
+/// Duration written as days and time, like '2 gün 03:04:05'.
+pub fn format_duration(millis: i64) -> String {
+    let sign = if millis < 0 {
+        "-"
+    } else {
+        ""
+    };
+    let millis = millis.unsigned_abs() as i64;
+    let days = millis / MILLISECONDS_IN_DAY;
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        (millis % MILLISECONDS_IN_DAY) / MILLISECONDS_IN_HOUR,
+        (millis % MILLISECONDS_IN_HOUR) / MILLISECONDS_IN_MINUTE,
+        (millis % MILLISECONDS_IN_MINUTE) / MILLISECONDS_IN_SECOND
+    );
+    let time = match millis % MILLISECONDS_IN_SECOND {
+        0 => time,
+        rest => format!("{}.{:03}", time, rest),
+    };
+
+    match days {
+        0 => format!("{}{}", sign, time),
+        _ => format!("{}{} gün {}", sign, days, time),
+    }
+}
+
+pub struct DateModule {
+    methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
+    path: Vec<String>,
+}
+
+impl Module for DateModule {
+    fn get_module_name(&self) -> String {
+        "tarih".to_string()
+    }
+
+    fn get_path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    fn get_method(&self, name: &str) -> Option<Rc<FunctionReference>> {
+        self.methods.borrow().get(name).cloned()
+    }
+
+    fn get_module(&self, _: &str) -> Option<Rc<dyn Module>> {
+        None
+    }
+
+    fn get_methods(&self) -> Vec<Rc<FunctionReference>> {
+        let mut response = Vec::new();
+        self.methods
+            .borrow()
+            .iter()
+            .for_each(|(_, reference)| response.push(reference.clone()));
+        response
+    }
+
+    fn get_modules(&self) -> HashMap<String, Rc<dyn Module>> {
+        HashMap::new()
+    }
+
+    fn get_classes(&self) -> Vec<Rc<dyn Class>> {
+        Vec::new()
+    }
+}
+
+impl DateModule {
+    pub fn new() -> Rc<DateModule> {
+        let module = DateModule {
+            methods: RefCell::new(HashMap::new()),
+            path: vec!["tarih".to_string()],
+        };
+
+        let zero = || Rc::new(KaramelPrimative::Integer(0));
+        let rc_module = Rc::new(module);
+        let functions: [(&[&str], NativeCall, Vec<FunctionArgument>); 7] = [
+            (&["şimdi", "simdi"], Self::now, Vec::new()),
+            (&["bugün", "bugun"], Self::today, Vec::new()),
+            (
+                &["oluştur", "olustur"],
+                Self::create,
+                vec![
+                    FunctionArgument::new("yıl"),
+                    FunctionArgument::new("ay"),
+                    FunctionArgument::new("gün"),
+                    FunctionArgument::with_default("saat", zero()),
+                    FunctionArgument::with_default("dakika", zero()),
+                    FunctionArgument::with_default("saniye", zero()),
+                ],
+            ),
+            (&["oku"], Self::parse, vec![FunctionArgument::new("yazı"), FunctionArgument::with_default("biçim", Rc::new(KaramelPrimative::Empty))]),
+            (
+                &["süre", "sure"],
+                Self::duration,
+                vec![
+                    FunctionArgument::with_default("gün", zero()),
+                    FunctionArgument::with_default("saat", zero()),
+                    FunctionArgument::with_default("dakika", zero()),
+                    FunctionArgument::with_default("saniye", zero()),
+                    FunctionArgument::with_default("milisaniye", zero()),
+                ],
+            ),
+            (&["artık_yıl_mı", "artik_yil_mi"], Self::leap_year, vec![FunctionArgument::new("yıl")]),
+            (&["ayın_gün_sayısı", "ayin_gun_sayisi"], Self::month_days, vec![FunctionArgument::new("yıl"), FunctionArgument::new("ay")]),
+        ];
+
+        for (names, function, arguments) in functions {
+            for name in names {
+                rc_module
+                    .methods
+                    .borrow_mut()
+                    .insert(name.to_string(), FunctionReference::native_function_with_arguments(function, name.to_string(), arguments.clone(), rc_module.clone()));
+            }
+        }
+
+        rc_module.clone()
+    }
+
+    pub fn integer(function: &str, parameter: &VmObject) -> Result<i64, KaramelErrorType> {
+        match parameter
+            .as_numeric()
+            .and_then(|number| number.to_integer())
+        {
+            Some(number) => match number.to_primative() {
+                KaramelPrimative::Integer(number) => Ok(number),
+                _ => expected_parameter_type!(function.to_string(), "Tam sayı".to_string()),
+            },
+            None => expected_parameter_type!(function.to_string(), "Tam sayı".to_string()),
+        }
+    }
+
+    /// Current local time of the clock. Functions called without context, like unit tests, use the system clock.
+    fn current_time(parameter: &FunctionParameter) -> i64 {
+        match parameter.context() {
+            Some(context) => context.clock.now() + context.clock.utc_offset() * MILLISECONDS_IN_SECOND,
+            None => SystemClock.now(),
+        }
+    }
+
+    pub fn now(parameter: FunctionParameter) -> NativeCallResult {
+        Ok(VmObject::native_convert(KaramelPrimative::Date(Self::current_time(&parameter))))
+    }
+
+    pub fn today(parameter: FunctionParameter) -> NativeCallResult {
+        let now = Self::current_time(&parameter);
+        Ok(VmObject::native_convert(KaramelPrimative::Date(now - now.rem_euclid(MILLISECONDS_IN_DAY))))
+    }
+
+    pub fn create(parameter: FunctionParameter) -> NativeCallResult {
+        let fields = parameter
+            .iter()
+            .map(|item| Self::integer("oluştur", item))
+            .collect::<Result<Vec<_>, _>>()?;
+        let millis = DateParts::new(fields[0], fields[1], fields[2], fields[3], fields[4], fields[5])?
+            .to_millis()
+            .ok_or(KaramelErrorType::DateNotValid)?;
+        Ok(VmObject::native_convert(KaramelPrimative::Date(millis)))
+    }
+
+    pub fn parse(parameter: FunctionParameter) -> NativeCallResult {
+        let mut iter = parameter.iter();
+        let text = match &*iter.next().unwrap().deref() {
+            KaramelPrimative::Text(text) => text.clone(),
+            _ => return expected_parameter_type!("oku".to_string(), "Yazı".to_string()),
+        };
+
+        let parts = match &*iter.next().unwrap().deref() {
+            KaramelPrimative::Text(format) => DateParts::parse(&text, format)?,
+            /* Without format, date with or without time could be read */
+            KaramelPrimative::Empty => DateParts::parse(&text, DEFAULT_DATE_FORMAT)
+                .or_else(|_| DateParts::parse(&text, "%Y-%m-%d %H:%M"))
+                .or_else(|_| DateParts::parse(&text, "%Y-%m-%d"))?,
+            _ => return expected_parameter_type!("oku".to_string(), "Yazı".to_string()),
+        };
+        let millis = parts.to_millis().ok_or(KaramelErrorType::DateNotValid)?;
+        Ok(VmObject::native_convert(KaramelPrimative::Date(millis)))
+    }
+
+    pub fn duration(parameter: FunctionParameter) -> NativeCallResult {
+        let units = [MILLISECONDS_IN_DAY, MILLISECONDS_IN_HOUR, MILLISECONDS_IN_MINUTE, MILLISECONDS_IN_SECOND, 1];
+        let mut total: i64 = 0;
+        for (item, unit) in parameter.iter().zip(units) {
+            total = Self::integer("süre", item)?
+                .checked_mul(unit)
+                .and_then(|value| value.checked_add(total))
+                .ok_or(KaramelErrorType::DateNotValid)?;
+        }
+        Ok(VmObject::native_convert(KaramelPrimative::Duration(total)))
+    }
+
+    pub fn leap_year(parameter: FunctionParameter) -> NativeCallResult {
+        Ok(VmObject::from(is_leap_year(Self::integer("artık_yıl_mı", parameter.iter().next().unwrap())?)))
+    }
+
+    pub fn month_days(parameter: FunctionParameter) -> NativeCallResult {
+        let mut iter = parameter.iter();
+        let year = Self::integer("ayın_gün_sayısı", iter.next().unwrap())?;
+        let month = Self::integer("ayın_gün_sayısı", iter.next().unwrap())?;
+        match (1..=12).contains(&month) {
+            true => Ok(VmObject::from(days_in_month(year, month))),
+            false => Err(KaramelErrorType::DateNotValid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_conversion() {
+        let parts = DateParts::new(2024, 2, 29, 13, 45, 10).unwrap();
+        assert_eq!(DateParts::from_millis(parts.to_millis().unwrap()), parts);
+        assert_eq!(DateParts::new(1970, 1, 1, 0, 0, 0).unwrap().to_millis(), Some(0));
+        assert_eq!(DateParts::from_millis(-1).year, 1969);
+        assert!(DateParts::new(2023, 2, 29, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(DateParts::new(1970, 1, 1, 0, 0, 0).unwrap().weekday(), 4);
+        assert_eq!(DateParts::new(2023, 10, 29, 0, 0, 0).unwrap().weekday(), 7);
+    }
+
+    #[test]
+    fn test_format_and_parse() {
+        let parts = DateParts::new(2023, 10, 29, 9, 5, 0).unwrap();
+        assert_eq!(parts.format("%d %B %Y %A %H:%M"), "29 Ekim 2023 Pazar 09:05");
+        assert_eq!(DateParts::parse("29 Ekim 2023 09:05", "%d %B %Y %H:%M").unwrap(), parts);
+        assert_eq!(DateParts::parse("1 şubat 2024", "%d %B %Y").unwrap().month, 2);
+        assert!(DateParts::parse("2023-13-01", "%Y-%m-%d").is_err());
+    }
+
+    #[test]
+    fn test_add_months() {
+        let parts = DateParts::new(2024, 1, 31, 0, 0, 0)
+            .unwrap()
+            .add_months(1)
+            .unwrap();
+        assert_eq!((parts.year, parts.month, parts.day), (2024, 2, 29));
+
+        let parts = DateParts::new(2024, 1, 15, 0, 0, 0)
+            .unwrap()
+            .add_months(-13)
+            .unwrap();
+        assert_eq!((parts.year, parts.month, parts.day), (2022, 12, 15));
+
+        let parts = DateParts::new(2024, 1, 15, 0, 0, 0).unwrap();
+        assert!(parts.add_months(i64::MAX).is_none());
+        assert!(parts.add_months(i64::MIN).is_none());
+        assert!(parts.add_months(MAX_YEAR * 12).is_none());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(MILLISECONDS_IN_HOUR + 5 * MILLISECONDS_IN_SECOND), "01:00:05");
+        assert_eq!(format_duration(-(2 * MILLISECONDS_IN_DAY + 250)), "-2 gün 00:00:00.250");
+    }
+}
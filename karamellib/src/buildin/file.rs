@@ -0,0 +1,213 @@
+use crate::buildin::{Class, Module};
+use crate::compiler::function::{FunctionArgument, FunctionParameter, FunctionReference, NativeCall, NativeCallResult};
+use crate::compiler::value::KaramelPrimative;
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::error::KaramelErrorType;
+use crate::expected_parameter_type;
+use crate::file::read_file;
+use crate::types::VmObject;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::rc::Rc;
+use std::{cell::RefCell, collections::HashMap};
+
+pub struct FileModule {
+    methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
+    path: Vec<String>,
+}
+
+impl Module for FileModule {
+    fn get_module_name(&self) -> String {
+        "dosya".to_string()
+    }
+
+    fn get_path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    fn get_method(&self, name: &str) -> Option<Rc<FunctionReference>> {
+        self.methods.borrow().get(name).cloned()
+    }
+
+    fn get_module(&self, _: &str) -> Option<Rc<dyn Module>> {
+        None
+    }
+
+    fn get_methods(&self) -> Vec<Rc<FunctionReference>> {
+        let mut response = Vec::new();
+        self.methods
+            .borrow()
+            .iter()
+            .for_each(|(_, reference)| response.push(reference.clone()));
+        response
+    }
+
+    fn get_modules(&self) -> HashMap<String, Rc<dyn Module>> {
+        HashMap::new()
+    }
+
+    fn get_classes(&self) -> Vec<Rc<dyn Class>> {
+        Vec::new()
+    }
+}
+
+impl FileModule {
+    pub fn new() -> Rc<FileModule> {
+        let module = FileModule {
+            methods: RefCell::new(HashMap::new()),
+            path: vec!["dosya".to_string()],
+        };
+
+        let rc_module = Rc::new(module);
+        let functions: [(&[&str], NativeCall, Vec<FunctionArgument>); 11] = [
+            (&["oku"], Self::read, vec![FunctionArgument::new("yol")]),
+            (&["yaz"], Self::write, vec![FunctionArgument::new("yol"), FunctionArgument::new("içerik")]),
+            (&["ekle"], Self::append, vec![FunctionArgument::new("yol"), FunctionArgument::new("içerik")]),
+            (&["satırlar", "satirlar"], Self::lines, vec![FunctionArgument::new("yol")]),
+            (&["var_mı", "var_mi"], Self::exists, vec![FunctionArgument::new("yol")]),
+            (&["dosya_mı", "dosya_mi"], Self::is_file, vec![FunctionArgument::new("yol")]),
+            (&["dizin_mi"], Self::is_directory, vec![FunctionArgument::new("yol")]),
+            (&["sil"], Self::remove, vec![FunctionArgument::new("yol")]),
+            (&["listele"], Self::list, vec![FunctionArgument::with_default("yol", Rc::new(KaramelPrimative::Text(Rc::new(".".to_string()))))]),
+            (&["dizin_oluştur", "dizin_olustur"], Self::create_directory, vec![FunctionArgument::new("yol")]),
+            (&["dizin_sil"], Self::remove_directory, vec![FunctionArgument::new("yol")]),
+        ];
+
+        for (names, function, arguments) in functions {
+            for name in names {
+                rc_module
+                    .methods
+                    .borrow_mut()
+                    .insert(name.to_string(), FunctionReference::native_function_with_arguments(function, name.to_string(), arguments.clone(), rc_module.clone()));
+            }
+        }
+
+        rc_module.clone()
+    }
+
+    /// Path parameter of the function. Scripts without file system permission could not reach any path.
+    fn path(function: &str, parameter: &FunctionParameter) -> Result<String, KaramelErrorType> {
+        match parameter.context() {
+            Some(context) if context.capabilities.has_file_access() => (),
+            _ => return Err(KaramelErrorType::FileAccessNotAllowed),
+        };
+
+        match &*parameter.iter().next().unwrap().deref() {
+            KaramelPrimative::Text(path) => Ok(path.to_string()),
+            _ => expected_parameter_type!(function.to_string(), "Yazı".to_string()),
+        }
+    }
+
+    fn content(function: &str, parameter: &FunctionParameter) -> Result<Rc<String>, KaramelErrorType> {
+        match &*parameter.iter().nth(1).unwrap().deref() {
+            KaramelPrimative::Text(content) => Ok(content.clone()),
+            _ => expected_parameter_type!(function.to_string(), "Yazı".to_string()),
+        }
+    }
+
+    fn read_error(path: &str, error: std::io::Error) -> KaramelErrorType {
+        match error.kind() {
+            ErrorKind::NotFound => KaramelErrorType::FileNotFound(path.to_string()),
+            _ => KaramelErrorType::FileReadError { filename: path.to_string(), error: error.to_string() },
+        }
+    }
+
+    fn write_error(path: &str, error: std::io::Error) -> KaramelErrorType {
+        KaramelErrorType::FileWriteError { filename: path.to_string(), error: error.to_string() }
+    }
+
+    fn read_text(path: &str) -> Result<String, KaramelErrorType> {
+        match Path::new(path).is_file() {
+            true => read_file(path),
+            false => Err(KaramelErrorType::FileNotFound(path.to_string())),
+        }
+    }
+
+    pub fn read(parameter: FunctionParameter) -> NativeCallResult {
+        let path = Self::path("oku", &parameter)?;
+        Ok(VmObject::from(Rc::new(Self::read_text(&path)?)))
+    }
+
+    pub fn write(parameter: FunctionParameter) -> NativeCallResult {
+        let path = Self::path("yaz", &parameter)?;
+        let content = Self::content("yaz", &parameter)?;
+        fs::write(&path, content.as_bytes()).map_err(|error| Self::write_error(&path, error))?;
+        Ok(EMPTY_OBJECT)
+    }
+
+    pub fn append(parameter: FunctionParameter) -> NativeCallResult {
+        let path = Self::path("ekle", &parameter)?;
+        let content = Self::content("ekle", &parameter)?;
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(content.as_bytes()))
+            .map_err(|error| Self::write_error(&path, error))?;
+        Ok(EMPTY_OBJECT)
+    }
+
+    pub fn lines(parameter: FunctionParameter) -> NativeCallResult {
+        let path = Self::path("satırlar", &parameter)?;
+        let lines = Self::read_text(&path)?
+            .lines()
+            .map(|line| VmObject::from(Rc::new(line.to_string())))
+            .collect();
+        Ok(VmObject::native_convert(KaramelPrimative::List(RefCell::new(lines))))
+    }
+
+    pub fn exists(parameter: FunctionParameter) -> NativeCallResult {
+        let path = Self::path("var_mı", &parameter)?;
+        Ok(VmObject::from(Path::new(&path).exists()))
+    }
+
+    pub fn is_file(parameter: FunctionParameter) -> NativeCallResult {
+        let path = Self::path("dosya_mı", &parameter)?;
+        Ok(VmObject::from(Path::new(&path).is_file()))
+    }
+
+    pub fn is_directory(parameter: FunctionParameter) -> NativeCallResult {
+        let path = Self::path("dizin_mi", &parameter)?;
+        Ok(VmObject::from(Path::new(&path).is_dir()))
+    }
+
+    pub fn remove(parameter: FunctionParameter) -> NativeCallResult {
+        let path = Self::path("sil", &parameter)?;
+        fs::remove_file(&path).map_err(|error| Self::read_error(&path, error))?;
+        Ok(EMPTY_OBJECT)
+    }
+
+    /// Names of the files and directories in alphabetical order.
+    pub fn list(parameter: FunctionParameter) -> NativeCallResult {
+        let path = Self::path("listele", &parameter)?;
+        let mut names = fs::read_dir(&path)
+            .and_then(|entries| {
+                entries
+                    .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().to_string()))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map_err(|error| Self::read_error(&path, error))?;
+
+        names.sort();
+        let names = names
+            .into_iter()
+            .map(|name| VmObject::from(Rc::new(name)))
+            .collect();
+        Ok(VmObject::native_convert(KaramelPrimative::List(RefCell::new(names))))
+    }
+
+    pub fn create_directory(parameter: FunctionParameter) -> NativeCallResult {
+        let path = Self::path("dizin_oluştur", &parameter)?;
+        fs::create_dir_all(&path).map_err(|error| Self::write_error(&path, error))?;
+        Ok(EMPTY_OBJECT)
+    }
+
+    /// Directory removed with everything in it.
+    pub fn remove_directory(parameter: FunctionParameter) -> NativeCallResult {
+        let path = Self::path("dizin_sil", &parameter)?;
+        fs::remove_dir_all(&path).map_err(|error| Self::read_error(&path, error))?;
+        Ok(EMPTY_OBJECT)
+    }
+}
@@ -2,12 +2,9 @@ use crate::buildin::{Class, Module};
 use crate::compiler::function::{FunctionParameter, FunctionReference, NativeCall, NativeCallResult};
 use crate::compiler::value::EMPTY_OBJECT;
 use crate::types::VmObject;
-use std::io;
 use std::rc::Rc;
 use std::{cell::RefCell, collections::HashMap};
 
-use log;
-
 #[derive(Clone)]
 pub struct IoModule {
     methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
@@ -88,11 +85,10 @@ impl IoModule {
         rc_module.clone()
     }
 
-    pub fn readline(_: FunctionParameter) -> NativeCallResult {
-        let mut line = String::new();
-        match io::stdin().read_line(&mut line) {
-            Ok(_) => Ok(VmObject::from(Rc::new(line.trim().to_string()))),
-            _ => Ok(EMPTY_OBJECT),
+    pub fn readline(parameter: FunctionParameter) -> NativeCallResult {
+        match parameter.io().read_line() {
+            Some(line) => Ok(VmObject::from(Rc::new(line.trim().to_string()))),
+            None => Ok(EMPTY_OBJECT),
         }
     }
 
@@ -101,8 +97,6 @@ impl IoModule {
         for arg in parameter.iter() {
             buffer.push_str(&format!("{}", arg.deref()));
         }
-        log::info!("{}", buffer);
-
         parameter.write_to_stdout(&buffer);
         Ok(EMPTY_OBJECT)
     }
@@ -115,7 +109,6 @@ impl IoModule {
         }
 
         buffer.push_str("\r\n");
-        log::info!("{}", buffer);
 
         parameter.write_to_stdout(&buffer);
         Ok(EMPTY_OBJECT)
@@ -0,0 +1,528 @@
+use crate::buildin::{Class, Module};
+use crate::compiler::function::{FunctionArgument, FunctionParameter, FunctionReference, NativeCall, NativeCallResult};
+use crate::compiler::value::{KaramelDictKey, KaramelPrimative};
+use crate::error::KaramelErrorType;
+use crate::expected_parameter_type;
+use crate::types::VmObject;
+use num_bigint::BigInt;
+use std::fmt::Write;
+use std::rc::Rc;
+use std::{cell::RefCell, collections::HashMap};
+
+/// Deeper values are rejected, self referencing lists and dictionaries also stopped with this limit.
+pub const MAX_JSON_DEPTH: usize = 256;
+
+/// JSON reader with line and column information for the errors.
+pub struct JsonParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    pub fn new(text: &'a str) -> Self {
+        JsonParser { chars: text.chars().peekable(), line: 1, column: 1 }
+    }
+
+    pub fn parse(mut self) -> Result<VmObject, KaramelErrorType> {
+        let value = self.value(0)?;
+        self.skip_whitespace();
+        match self.peek() {
+            Some(ch) => Err(self.error(format!("Beklenmeyen '{}' karakteri", ch))),
+            None => Ok(value),
+        }
+    }
+
+    fn error(&self, message: String) -> KaramelErrorType {
+        KaramelErrorType::JsonParseFailed { message, line: self.line, column: self.column }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.chars.next()?;
+        match ch {
+            '\n' => {
+                self.line += 1;
+                self.column = 1;
+            }
+            _ => self.column += 1,
+        };
+        Some(ch)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(' ' | '\t' | '\n' | '\r') = self.peek() {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), KaramelErrorType> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(ch) if ch == expected => {
+                self.next();
+                Ok(())
+            }
+            Some(ch) => Err(self.error(format!("'{}' bekleniyordu, '{}' bulundu", expected, ch))),
+            None => Err(self.error(format!("'{}' bekleniyordu", expected))),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: VmObject) -> Result<VmObject, KaramelErrorType> {
+        for expected in keyword.chars() {
+            match self.peek() {
+                Some(ch) if ch == expected => {
+                    self.next();
+                }
+                _ => return Err(self.error(format!("'{}' bekleniyordu", keyword))),
+            }
+        }
+        Ok(value)
+    }
+
+    fn value(&mut self, depth: usize) -> Result<VmObject, KaramelErrorType> {
+        if depth > MAX_JSON_DEPTH {
+            return Err(self.error("Çok fazla iç içe değer var".to_string()));
+        }
+
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.object(depth),
+            Some('[') => self.array(depth),
+            Some('"') => Ok(VmObject::from(self.text()?)),
+            Some('t') => self.keyword("true", VmObject::from(true)),
+            Some('f') => self.keyword("false", VmObject::from(false)),
+            Some('n') => self.keyword("null", VmObject::native_convert(KaramelPrimative::Empty)),
+            Some('-' | '0'..='9') => self.number(),
+            Some(ch) => Err(self.error(format!("Beklenmeyen '{}' karakteri", ch))),
+            None => Err(self.error("Değer bekleniyordu".to_string())),
+        }
+    }
+
+    fn object(&mut self, depth: usize) -> Result<VmObject, KaramelErrorType> {
+        self.next();
+        let mut items = HashMap::new();
+
+        self.skip_whitespace();
+        if let Some('}') = self.peek() {
+            self.next();
+            return Ok(VmObject::from(items));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error("Anahtar yazı olmalı".to_string()));
+            }
+
+            let key = self.text()?;
+            self.expect(':')?;
+            let value = self.value(depth + 1)?;
+            items.insert(KaramelDictKey::from(key), value);
+
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => (),
+                Some('}') => return Ok(VmObject::from(items)),
+                _ => return Err(self.error("',' ya da '}' bekleniyordu".to_string())),
+            }
+        }
+    }
+
+    fn array(&mut self, depth: usize) -> Result<VmObject, KaramelErrorType> {
+        self.next();
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if let Some(']') = self.peek() {
+            self.next();
+            return Ok(VmObject::from(items));
+        }
+
+        loop {
+            items.push(self.value(depth + 1)?);
+
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => (),
+                Some(']') => return Ok(VmObject::from(items)),
+                _ => return Err(self.error("',' ya da ']' bekleniyordu".to_string())),
+            }
+        }
+    }
+
+    fn hex(&mut self) -> Result<u32, KaramelErrorType> {
+        let mut code = 0;
+        for _ in 0..4 {
+            match self.next().and_then(|ch| ch.to_digit(16)) {
+                Some(digit) => code = code * 16 + digit,
+                None => return Err(self.error("Geçersiz unicode karakter kodu".to_string())),
+            }
+        }
+        Ok(code)
+    }
+
+    fn text(&mut self) -> Result<String, KaramelErrorType> {
+        self.next();
+        let mut text = String::new();
+
+        loop {
+            match self.next() {
+                Some('"') => return Ok(text),
+                Some('\\') => match self.next() {
+                    Some('"') => text.push('"'),
+                    Some('\\') => text.push('\\'),
+                    Some('/') => text.push('/'),
+                    Some('b') => text.push('\u{8}'),
+                    Some('f') => text.push('\u{c}'),
+                    Some('n') => text.push('\n'),
+                    Some('r') => text.push('\r'),
+                    Some('t') => text.push('\t'),
+                    Some('u') => {
+                        let mut code = self.hex()?;
+
+                        /* Characters outside of the basic plane written as two surrogate characters */
+                        if (0xD800..0xDC00).contains(&code) {
+                            if self.next() != Some('\\') || self.next() != Some('u') {
+                                return Err(self.error("Eksik unicode karakter kodu".to_string()));
+                            }
+
+                            let low = self.hex()?;
+                            if !(0xDC00..0xE000).contains(&low) {
+                                return Err(self.error("Geçersiz unicode karakter kodu".to_string()));
+                            }
+                            code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                        }
+
+                        match char::from_u32(code) {
+                            Some(ch) => text.push(ch),
+                            None => return Err(self.error("Geçersiz unicode karakter kodu".to_string())),
+                        }
+                    }
+                    _ => return Err(self.error("Geçersiz kaçış karakteri".to_string())),
+                },
+                Some(ch) if (ch as u32) < 0x20 => return Err(self.error("Yazı içerisinde kontrol karakteri kullanılamaz".to_string())),
+                Some(ch) => text.push(ch),
+                None => return Err(self.error("Yazı kapatılmamış".to_string())),
+            }
+        }
+    }
+
+    fn digits(&mut self, number: &mut String) -> usize {
+        let mut count = 0;
+        while let Some(ch @ '0'..='9') = self.peek() {
+            number.push(ch);
+            self.next();
+            count += 1;
+        }
+        count
+    }
+
+    fn number(&mut self) -> Result<VmObject, KaramelErrorType> {
+        let mut number = String::new();
+        let mut is_integer = true;
+
+        if let Some('-') = self.peek() {
+            number.push('-');
+            self.next();
+        }
+
+        let integer_start = number.len();
+        match self.digits(&mut number) {
+            0 => return Err(self.error("Sayı bekleniyordu".to_string())),
+            count if count > 1 && number[integer_start..].starts_with('0') => return Err(self.error("Sayı sıfır ile başlayamaz".to_string())),
+            _ => (),
+        };
+
+        if let Some('.') = self.peek() {
+            is_integer = false;
+            number.push('.');
+            self.next();
+            if self.digits(&mut number) == 0 {
+                return Err(self.error("Noktadan sonra sayı bekleniyordu".to_string()));
+            }
+        }
+
+        if let Some('e' | 'E') = self.peek() {
+            is_integer = false;
+            number.push('e');
+            self.next();
+            if let Some(sign @ ('+' | '-')) = self.peek() {
+                number.push(sign);
+                self.next();
+            }
+            if self.digits(&mut number) == 0 {
+                return Err(self.error("Üsten sonra sayı bekleniyordu".to_string()));
+            }
+        }
+
+        match is_integer {
+            true => match number.parse::<i64>() {
+                Ok(integer) => Ok(VmObject::from(integer)),
+                Err(_) => match number.parse::<BigInt>() {
+                    Ok(integer) => Ok(VmObject::native_convert(KaramelPrimative::BigInteger(integer))),
+                    Err(_) => Err(self.error("Geçersiz sayı".to_string())),
+                },
+            },
+            false => match number.parse::<f64>() {
+                Ok(float) if float.is_finite() => Ok(VmObject::from(float)),
+                _ => Err(self.error("Geçersiz sayı".to_string())),
+            },
+        }
+    }
+}
+
+/// JSON writer, dictionary keys are sorted to get the same text at every execution.
+pub struct JsonWriter {
+    indent: usize,
+    output: String,
+}
+
+impl JsonWriter {
+    pub fn new(indent: usize) -> Self {
+        JsonWriter { indent, output: String::new() }
+    }
+
+    pub fn write(mut self, value: &VmObject) -> Result<String, KaramelErrorType> {
+        self.value(value, 0)?;
+        Ok(self.output)
+    }
+
+    fn new_line(&mut self, depth: usize) {
+        if self.indent > 0 {
+            self.output.push('\n');
+            self.output.push_str(&" ".repeat(self.indent * depth));
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        self.output.push('"');
+        for ch in text.chars() {
+            match ch {
+                '"' => self.output.push_str("\\\""),
+                '\\' => self.output.push_str("\\\\"),
+                '\n' => self.output.push_str("\\n"),
+                '\r' => self.output.push_str("\\r"),
+                '\t' => self.output.push_str("\\t"),
+                '\u{8}' => self.output.push_str("\\b"),
+                '\u{c}' => self.output.push_str("\\f"),
+                ch if (ch as u32) < 0x20 => {
+                    let _ = write!(self.output, "\\u{:04x}", ch as u32);
+                }
+                ch => self.output.push(ch),
+            }
+        }
+        self.output.push('"');
+    }
+
+    fn array(&mut self, items: &[VmObject], depth: usize) -> Result<(), KaramelErrorType> {
+        self.output.push('[');
+        for (index, item) in items.iter().enumerate() {
+            if index > 0 {
+                self.output.push(',');
+            }
+            self.new_line(depth + 1);
+            self.value(item, depth + 1)?;
+        }
+
+        if !items.is_empty() {
+            self.new_line(depth);
+        }
+        self.output.push(']');
+        Ok(())
+    }
+
+    fn object(&mut self, items: &HashMap<KaramelDictKey, VmObject>, depth: usize) -> Result<(), KaramelErrorType> {
+        let mut keys = Vec::with_capacity(items.len());
+        for (key, value) in items.iter() {
+            match key {
+                KaramelDictKey::Text(text) => keys.push((text.clone(), *value)),
+                _ => return Err(KaramelErrorType::JsonEncodeFailed(format!("{:?}", key.to_primative()))),
+            }
+        }
+        keys.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+        self.output.push('{');
+        for (index, (key, value)) in keys.iter().enumerate() {
+            if index > 0 {
+                self.output.push(',');
+            }
+            self.new_line(depth + 1);
+            self.text(key);
+            self.output.push(':');
+            if self.indent > 0 {
+                self.output.push(' ');
+            }
+            self.value(value, depth + 1)?;
+        }
+
+        if !keys.is_empty() {
+            self.new_line(depth);
+        }
+        self.output.push('}');
+        Ok(())
+    }
+
+    fn value(&mut self, value: &VmObject, depth: usize) -> Result<(), KaramelErrorType> {
+        if depth > MAX_JSON_DEPTH {
+            return Err(KaramelErrorType::JsonEncodeFailed("Çok fazla iç içe değer".to_string()));
+        }
+
+        let primative = value.deref();
+        match &*primative {
+            KaramelPrimative::Empty => self.output.push_str("null"),
+            KaramelPrimative::Bool(true) => self.output.push_str("true"),
+            KaramelPrimative::Bool(false) => self.output.push_str("false"),
+            KaramelPrimative::Integer(number) => {
+                let _ = write!(self.output, "{}", number);
+            }
+            KaramelPrimative::BigInteger(number) => {
+                let _ = write!(self.output, "{}", number);
+            }
+            /* Debug format keeps the point, so floating numbers are read back as floating numbers */
+            KaramelPrimative::Number(number) if number.is_finite() => {
+                let _ = write!(self.output, "{:?}", number);
+            }
+            KaramelPrimative::Decimal(number) => {
+                let _ = write!(self.output, "{}", number);
+            }
+            KaramelPrimative::Text(text) => self.text(text),
+            KaramelPrimative::List(items) => self.array(&items.borrow(), depth)?,
+            KaramelPrimative::Tuple(items) => self.array(items, depth)?,
+            KaramelPrimative::Set(items) => {
+                let items = items
+                    .borrow()
+                    .iter()
+                    .map(|item| VmObject::native_convert(item.to_primative()))
+                    .collect::<Vec<_>>();
+                self.array(&items, depth)?
+            }
+            KaramelPrimative::Dict(items) => self.object(&items.borrow(), depth)?,
+            _ => return Err(KaramelErrorType::JsonEncodeFailed(format!("{:?}", primative))),
+        };
+        Ok(())
+    }
+}
+
+pub struct JsonModule {
+    methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
+    path: Vec<String>,
+}
+
+impl Module for JsonModule {
+    fn get_module_name(&self) -> String {
+        "json".to_string()
+    }
+
+    fn get_path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    fn get_method(&self, name: &str) -> Option<Rc<FunctionReference>> {
+        self.methods.borrow().get(name).cloned()
+    }
+
+    fn get_module(&self, _: &str) -> Option<Rc<dyn Module>> {
+        None
+    }
+
+    fn get_methods(&self) -> Vec<Rc<FunctionReference>> {
+        let mut response = Vec::new();
+        self.methods
+            .borrow()
+            .iter()
+            .for_each(|(_, reference)| response.push(reference.clone()));
+        response
+    }
+
+    fn get_modules(&self) -> HashMap<String, Rc<dyn Module>> {
+        HashMap::new()
+    }
+
+    fn get_classes(&self) -> Vec<Rc<dyn Class>> {
+        Vec::new()
+    }
+}
+
+impl JsonModule {
+    pub fn new() -> Rc<JsonModule> {
+        let module = JsonModule {
+            methods: RefCell::new(HashMap::new()),
+            path: vec!["json".to_string()],
+        };
+
+        let rc_module = Rc::new(module);
+        let functions: [(&[&str], NativeCall, Vec<FunctionArgument>); 2] = [
+            (&["çöz", "coz"], Self::decode, vec![FunctionArgument::new("yazı")]),
+            (&["kodla"], Self::encode, vec![FunctionArgument::new("değer"), FunctionArgument::with_default("girinti", Rc::new(KaramelPrimative::Integer(0)))]),
+        ];
+
+        for (names, function, arguments) in functions {
+            for name in names {
+                rc_module
+                    .methods
+                    .borrow_mut()
+                    .insert(name.to_string(), FunctionReference::native_function_with_arguments(function, name.to_string(), arguments.clone(), rc_module.clone()));
+            }
+        }
+
+        rc_module.clone()
+    }
+
+    pub fn decode(parameter: FunctionParameter) -> NativeCallResult {
+        match &*parameter.iter().next().unwrap().deref() {
+            KaramelPrimative::Text(text) => JsonParser::new(text).parse(),
+            _ => expected_parameter_type!("çöz".to_string(), "Yazı".to_string()),
+        }
+    }
+
+    pub fn encode(parameter: FunctionParameter) -> NativeCallResult {
+        let mut iter = parameter.iter();
+        let value = iter.next().unwrap();
+        let indent = match &*iter.next().unwrap().deref() {
+            KaramelPrimative::Empty => 0,
+            KaramelPrimative::Integer(indent) if (0..=16).contains(indent) => *indent as usize,
+            _ => return expected_parameter_type!("kodla".to_string(), "0 ile 16 arasında girinti".to_string()),
+        };
+
+        Ok(VmObject::from(JsonWriter::new(indent).write(value)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(text: &str, indent: usize) -> String {
+        JsonWriter::new(indent)
+            .write(&JsonParser::new(text).parse().unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(round_trip(r#"{"b": [1, 2.5, -3e2], "a": {"c": null, "d": true}}"#, 0), r#"{"a":{"c":null,"d":true},"b":[1,2.5,-300.0]}"#);
+        assert_eq!(round_trip("1.0", 0), "1.0");
+        assert_eq!(round_trip("12345678901234567890", 0), "12345678901234567890");
+        assert_eq!(round_trip(r#""ç😀\n""#, 0), "\"ç😀\\n\"");
+        assert_eq!(round_trip("[1, [2]]", 2), "[\n  1,\n  [\n    2\n  ]\n]");
+    }
+
+    #[test]
+    fn test_error_position() {
+        match JsonParser::new("{\n  \"a\": tru\n}").parse() {
+            Err(KaramelErrorType::JsonParseFailed { line, column, .. }) => assert_eq!((line, column), (2, 11)),
+            _ => panic!("JSON hata bilgisi beklenen şekilde değil"),
+        }
+
+        assert!(JsonParser::new("[1, 2").parse().is_err());
+        assert!(JsonParser::new("01").parse().is_err());
+        assert!(JsonParser::new("[1] 2").parse().is_err());
+        assert!(JsonParser::new(&"[".repeat(MAX_JSON_DEPTH + 2))
+            .parse()
+            .is_err());
+    }
+}
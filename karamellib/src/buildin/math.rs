@@ -0,0 +1,349 @@
+use crate::buildin::{Class, Module};
+use crate::compiler::function::{FunctionArgument, FunctionParameter, FunctionReference, NativeCall, NativeCallResult};
+use crate::compiler::number::KaramelNumber;
+use crate::compiler::value::KaramelPrimative;
+use crate::error::KaramelErrorType;
+use crate::expected_parameter_type;
+use crate::types::VmObject;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed};
+use std::cmp::Ordering;
+use std::rc::Rc;
+use std::{cell::RefCell, collections::HashMap};
+
+pub struct MathModule {
+    methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
+    path: Vec<String>,
+}
+
+impl Module for MathModule {
+    fn get_module_name(&self) -> String {
+        "matematik".to_string()
+    }
+
+    fn get_path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    fn get_method(&self, name: &str) -> Option<Rc<FunctionReference>> {
+        self.methods.borrow().get(name).cloned()
+    }
+
+    fn get_module(&self, _: &str) -> Option<Rc<dyn Module>> {
+        None
+    }
+
+    fn get_methods(&self) -> Vec<Rc<FunctionReference>> {
+        let mut response = Vec::new();
+        self.methods
+            .borrow()
+            .iter()
+            .for_each(|(_, reference)| response.push(reference.clone()));
+        response
+    }
+
+    fn get_modules(&self) -> HashMap<String, Rc<dyn Module>> {
+        HashMap::new()
+    }
+
+    fn get_classes(&self) -> Vec<Rc<dyn Class>> {
+        Vec::new()
+    }
+
+    fn get_constant(&self, name: &str) -> Option<Rc<KaramelPrimative>> {
+        match name {
+            "pi" => Some(Rc::new(KaramelPrimative::Number(std::f64::consts::PI))),
+            "e" => Some(Rc::new(KaramelPrimative::Number(std::f64::consts::E))),
+            _ => None,
+        }
+    }
+}
+
+impl MathModule {
+    pub fn new() -> Rc<MathModule> {
+        let module = MathModule {
+            methods: RefCell::new(HashMap::new()),
+            path: vec!["matematik".to_string()],
+        };
+
+        let rc_module = Rc::new(module);
+        let functions: [(&[&str], NativeCall, Vec<FunctionArgument>); 19] = [
+            (&["kök", "kok"], Self::sqrt, vec![FunctionArgument::new("sayı")]),
+            (&["sin"], Self::sin, vec![FunctionArgument::new("açı")]),
+            (&["cos"], Self::cos, vec![FunctionArgument::new("açı")]),
+            (&["tan"], Self::tan, vec![FunctionArgument::new("açı")]),
+            (&["asin"], Self::asin, vec![FunctionArgument::new("sayı")]),
+            (&["acos"], Self::acos, vec![FunctionArgument::new("sayı")]),
+            (&["atan"], Self::atan, vec![FunctionArgument::new("sayı")]),
+            (&["log"], Self::log, vec![FunctionArgument::new("sayı"), FunctionArgument::with_default("taban", Rc::new(KaramelPrimative::Integer(10)))]),
+            (&["ln"], Self::ln, vec![FunctionArgument::new("sayı")]),
+            (&["mutlak"], Self::abs, vec![FunctionArgument::new("sayı")]),
+            (&["ebob"], Self::gcd, vec![FunctionArgument::new("sayı1"), FunctionArgument::new("sayı2")]),
+            (&["ekok"], Self::lcm, vec![FunctionArgument::new("sayı1"), FunctionArgument::new("sayı2")]),
+            (&["faktöriyel", "faktoriyel"], Self::factorial, vec![FunctionArgument::new("sayı")]),
+            (&["kombinasyon"], Self::combination, vec![FunctionArgument::new("n"), FunctionArgument::new("k")]),
+            (&["permütasyon", "permutasyon"], Self::permutation, vec![FunctionArgument::new("n"), FunctionArgument::new("k")]),
+            (&["toplam"], Self::sum, vec![FunctionArgument::new("liste")]),
+            (&["ortalama"], Self::mean, vec![FunctionArgument::new("liste")]),
+            (&["medyan"], Self::median, vec![FunctionArgument::new("liste")]),
+            (&["standart_sapma"], Self::standard_deviation, vec![FunctionArgument::new("liste")]),
+        ];
+
+        for (names, function, arguments) in functions {
+            for name in names {
+                rc_module
+                    .methods
+                    .borrow_mut()
+                    .insert(name.to_string(), FunctionReference::native_function_with_arguments(function, name.to_string(), arguments.clone(), rc_module.clone()));
+            }
+        }
+
+        /* 'en_büyük' and 'en_küçük' accept a list or any number of arguments */
+        for (name, function) in [("en_büyük", Self::max as NativeCall), ("en_buyuk", Self::max), ("en_küçük", Self::min), ("en_kucuk", Self::min)] {
+            rc_module
+                .methods
+                .borrow_mut()
+                .insert(name.to_string(), FunctionReference::native_function(function, name.to_string(), rc_module.clone()));
+        }
+
+        rc_module.clone()
+    }
+
+    fn number(function: &str, parameter: &VmObject) -> Result<KaramelNumber, KaramelErrorType> {
+        match parameter.as_numeric() {
+            Some(number) => Ok(number),
+            None => expected_parameter_type!(function.to_string(), "Sayı".to_string()),
+        }
+    }
+
+    fn integer(function: &str, parameter: &VmObject) -> Result<BigInt, KaramelErrorType> {
+        match Self::number(function, parameter)?.to_integer() {
+            Some(number) => Ok(number.to_big()),
+            None => expected_parameter_type!(function.to_string(), "Tam sayı".to_string()),
+        }
+    }
+
+    fn natural(function: &str, parameter: &VmObject) -> Result<BigInt, KaramelErrorType> {
+        let number = Self::integer(function, parameter)?;
+        match number.is_negative() {
+            true => expected_parameter_type!(function.to_string(), "Negatif olmayan tam sayı".to_string()),
+            false => Ok(number),
+        }
+    }
+
+    /// Numbers inside of the list, tuple or set.
+    fn numbers(function: &str, parameter: &VmObject) -> Result<Vec<KaramelNumber>, KaramelErrorType> {
+        let numbers = match &*parameter.deref() {
+            KaramelPrimative::List(items) => items
+                .borrow()
+                .iter()
+                .map(|item| item.as_numeric())
+                .collect::<Option<Vec<_>>>(),
+            KaramelPrimative::Tuple(items) => items
+                .iter()
+                .map(|item| item.as_numeric())
+                .collect::<Option<Vec<_>>>(),
+            KaramelPrimative::Set(items) => items
+                .borrow()
+                .iter()
+                .map(|item| KaramelNumber::from_primative(&item.to_primative()))
+                .collect::<Option<Vec<_>>>(),
+            _ => None,
+        };
+
+        match numbers {
+            Some(numbers) if !numbers.is_empty() => Ok(numbers),
+            _ => expected_parameter_type!(function.to_string(), "Sayı listesi".to_string()),
+        }
+    }
+
+    fn float_function(parameter: FunctionParameter, function: &str, operation: fn(f64) -> f64) -> NativeCallResult {
+        let number = Self::number(function, parameter.iter().next().unwrap())?;
+        Ok(VmObject::from(operation(number.as_f64())))
+    }
+
+    pub fn sqrt(parameter: FunctionParameter) -> NativeCallResult {
+        Self::float_function(parameter, "kök", f64::sqrt)
+    }
+
+    pub fn sin(parameter: FunctionParameter) -> NativeCallResult {
+        Self::float_function(parameter, "sin", f64::sin)
+    }
+
+    pub fn cos(parameter: FunctionParameter) -> NativeCallResult {
+        Self::float_function(parameter, "cos", f64::cos)
+    }
+
+    pub fn tan(parameter: FunctionParameter) -> NativeCallResult {
+        Self::float_function(parameter, "tan", f64::tan)
+    }
+
+    pub fn asin(parameter: FunctionParameter) -> NativeCallResult {
+        Self::float_function(parameter, "asin", f64::asin)
+    }
+
+    pub fn acos(parameter: FunctionParameter) -> NativeCallResult {
+        Self::float_function(parameter, "acos", f64::acos)
+    }
+
+    pub fn atan(parameter: FunctionParameter) -> NativeCallResult {
+        Self::float_function(parameter, "atan", f64::atan)
+    }
+
+    pub fn ln(parameter: FunctionParameter) -> NativeCallResult {
+        Self::float_function(parameter, "ln", f64::ln)
+    }
+
+    pub fn log(parameter: FunctionParameter) -> NativeCallResult {
+        let mut iter = parameter.iter();
+        let number = Self::number("log", iter.next().unwrap())?;
+        let base = Self::number("log", iter.next().unwrap())?;
+        let result = match base.as_f64() {
+            /* Dedicated functions give exact results for the powers of the base */
+            10.0 => number.as_f64().log10(),
+            2.0 => number.as_f64().log2(),
+            base => number.as_f64().log(base),
+        };
+        Ok(VmObject::from(result))
+    }
+
+    pub fn abs(parameter: FunctionParameter) -> NativeCallResult {
+        let number = Self::number("mutlak", parameter.iter().next().unwrap())?;
+        match number.compare(&KaramelNumber::Integer(0)) {
+            Some(Ordering::Less) => Ok(VmObject::from(KaramelNumber::Integer(0).sub(&number))),
+            _ => Ok(*parameter.iter().next().unwrap()),
+        }
+    }
+
+    /// Biggest or smallest number of the list or the arguments, original value returned.
+    fn select(parameter: FunctionParameter, function: &str, ordering: Ordering) -> NativeCallResult {
+        let items = match parameter.length() {
+            0 => return expected_parameter_type!(function.to_string(), "Sayı listesi".to_string()),
+            1 => Self::numbers(function, parameter.iter().next().unwrap())?,
+            _ => parameter
+                .iter()
+                .map(|item| Self::number(function, item))
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+
+        let mut selected = items[0].clone();
+        for item in items.into_iter().skip(1) {
+            if item.compare(&selected) == Some(ordering) {
+                selected = item;
+            }
+        }
+        Ok(VmObject::from(selected))
+    }
+
+    pub fn max(parameter: FunctionParameter) -> NativeCallResult {
+        Self::select(parameter, "en_büyük", Ordering::Greater)
+    }
+
+    pub fn min(parameter: FunctionParameter) -> NativeCallResult {
+        Self::select(parameter, "en_küçük", Ordering::Less)
+    }
+
+    pub fn gcd(parameter: FunctionParameter) -> NativeCallResult {
+        let mut iter = parameter.iter();
+        let left = Self::integer("ebob", iter.next().unwrap())?;
+        let right = Self::integer("ebob", iter.next().unwrap())?;
+        Ok(VmObject::from(KaramelNumber::from_big(left.gcd(&right))))
+    }
+
+    pub fn lcm(parameter: FunctionParameter) -> NativeCallResult {
+        let mut iter = parameter.iter();
+        let left = Self::integer("ekok", iter.next().unwrap())?;
+        let right = Self::integer("ekok", iter.next().unwrap())?;
+        Ok(VmObject::from(KaramelNumber::from_big(left.lcm(&right))))
+    }
+
+    /// Multiplication of the integers between 'start' and 'end', both of them included.
+    fn product(start: BigInt, end: &BigInt) -> BigInt {
+        let mut result = BigInt::one();
+        let mut current = start;
+        while &current <= end {
+            result *= &current;
+            current += 1;
+        }
+        result
+    }
+
+    pub fn factorial(parameter: FunctionParameter) -> NativeCallResult {
+        let number = Self::natural("faktöriyel", parameter.iter().next().unwrap())?;
+        Ok(VmObject::from(KaramelNumber::from_big(Self::product(BigInt::one(), &number))))
+    }
+
+    fn combinatorics(parameter: &FunctionParameter, function: &str) -> Result<Option<(BigInt, BigInt)>, KaramelErrorType> {
+        let mut iter = parameter.iter();
+        let n = Self::natural(function, iter.next().unwrap())?;
+        let k = Self::natural(function, iter.next().unwrap())?;
+        match k > n {
+            true => Ok(None),
+            false => Ok(Some((n, k))),
+        }
+    }
+
+    pub fn permutation(parameter: FunctionParameter) -> NativeCallResult {
+        let result = match Self::combinatorics(&parameter, "permütasyon")? {
+            Some((n, k)) => Self::product(&n - &k + 1, &n),
+            None => BigInt::default(),
+        };
+        Ok(VmObject::from(KaramelNumber::from_big(result)))
+    }
+
+    pub fn combination(parameter: FunctionParameter) -> NativeCallResult {
+        let result = match Self::combinatorics(&parameter, "kombinasyon")? {
+            Some((n, k)) => {
+                let k = std::cmp::min(k.clone(), &n - &k);
+                Self::product(&n - &k + 1, &n) / Self::product(BigInt::one(), &k)
+            }
+            None => BigInt::default(),
+        };
+        Ok(VmObject::from(KaramelNumber::from_big(result)))
+    }
+
+    fn total(numbers: &[KaramelNumber]) -> KaramelNumber {
+        numbers
+            .iter()
+            .fold(KaramelNumber::Integer(0), |total, number| total.add(number))
+    }
+
+    pub fn sum(parameter: FunctionParameter) -> NativeCallResult {
+        let numbers = Self::numbers("toplam", parameter.iter().next().unwrap())?;
+        Ok(VmObject::from(Self::total(&numbers)))
+    }
+
+    pub fn mean(parameter: FunctionParameter) -> NativeCallResult {
+        let numbers = Self::numbers("ortalama", parameter.iter().next().unwrap())?;
+        Ok(VmObject::from(Self::total(&numbers).div(&KaramelNumber::Integer(numbers.len() as i64))?))
+    }
+
+    pub fn median(parameter: FunctionParameter) -> NativeCallResult {
+        let mut numbers = Self::numbers("medyan", parameter.iter().next().unwrap())?;
+        numbers.sort_by(|left, right| left.compare(right).unwrap_or(Ordering::Equal));
+
+        let middle = numbers.len() / 2;
+        match numbers.len() % 2 {
+            1 => Ok(VmObject::from(numbers[middle].clone())),
+            _ => Ok(VmObject::from(
+                numbers[middle - 1]
+                    .add(&numbers[middle])
+                    .div(&KaramelNumber::Integer(2))?,
+            )),
+        }
+    }
+
+    /// Population standard deviation of the numbers.
+    pub fn standard_deviation(parameter: FunctionParameter) -> NativeCallResult {
+        let numbers = Self::numbers("standart_sapma", parameter.iter().next().unwrap())?;
+        let count = numbers.len() as f64;
+        let mean = numbers.iter().map(KaramelNumber::as_f64).sum::<f64>() / count;
+        let variance = numbers
+            .iter()
+            .map(|number| (number.as_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        Ok(VmObject::from(variance.sqrt()))
+    }
+}
@@ -1,7 +1,15 @@
 pub mod base_functions;
+pub mod builder;
+pub mod date;
 pub mod debug;
+pub mod file;
 pub mod io;
+pub mod json;
+pub mod math;
 pub mod num;
+pub mod pattern;
+pub mod random;
+pub mod set;
 
 use std::collections::hash_map::Iter;
 
@@ -36,6 +44,11 @@ pub trait Module {
     fn get_modules(&self) -> HashMap<String, Rc<dyn Module>>;
 
     fn get_classes(&self) -> Vec<Rc<dyn Class>>;
+
+    /// Value that could be used with 'modül::isim' in the script.
+    fn get_constant(&self, _: &str) -> Option<Rc<KaramelPrimative>> {
+        None
+    }
 }
 
 pub struct ModuleCollectionIterator<'a> {
@@ -61,7 +74,7 @@ impl ModuleCollection {
         self.modules.insert(module.get_module_name(), module);
     }
 
-    pub fn iter(&self) -> ModuleCollectionIterator {
+    pub fn iter(&self) -> ModuleCollectionIterator<'_> {
         ModuleCollectionIterator { iter: self.modules.iter().clone() }
     }
 
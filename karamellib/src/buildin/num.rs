@@ -1,9 +1,12 @@
 use crate::buildin::{Class, Module};
-use crate::compiler::function::{FunctionParameter, FunctionReference, NativeCall, NativeCallResult};
+use crate::compiler::function::{FunctionArgument, FunctionParameter, FunctionReference, NativeCall, NativeCallResult};
+use crate::compiler::number::KaramelNumber;
 use crate::compiler::value::KaramelPrimative;
 use crate::compiler::value::EMPTY_OBJECT;
+use crate::error::KaramelErrorType;
 use crate::types::VmObject;
 use crate::{expected_parameter_type, n_parameter_expected};
+use num_traits::Zero;
 use std::rc::Rc;
 use std::{cell::RefCell, collections::HashMap};
 
@@ -58,10 +61,66 @@ impl NumModule {
         rc_module
             .methods
             .borrow_mut()
-            .insert("oku".to_string(), FunctionReference::native_function(Self::parse as NativeCall, "tür_bilgisi".to_string(), rc_module.clone()));
+            .insert("oku".to_string(), FunctionReference::native_function(Self::parse as NativeCall, "oku".to_string(), rc_module.clone()));
+
+        for name in ["ondalık", "ondalik"] {
+            rc_module
+                .methods
+                .borrow_mut()
+                .insert(name.to_string(), FunctionReference::native_function_with_arguments(Self::decimal as NativeCall, name.to_string(), vec![FunctionArgument::new("değer")], rc_module.clone()));
+        }
+
+        rc_module.methods.borrow_mut().insert(
+            "kesir".to_string(),
+            FunctionReference::native_function_with_arguments(
+                Self::fraction as NativeCall,
+                "kesir".to_string(),
+                vec![FunctionArgument::new("pay"), FunctionArgument::with_default("payda", Rc::new(KaramelPrimative::Integer(1)))],
+                rc_module.clone(),
+            ),
+        );
         rc_module.clone()
     }
 
+    /// Exact value of the parameter, floating numbers converted with their shortest text.
+    fn exact_number(function: &str, parameter: &VmObject) -> Result<KaramelNumber, KaramelErrorType> {
+        match (parameter.as_numeric(), &*parameter.deref()) {
+            (Some(KaramelNumber::Float(number)), _) if number.is_finite() => Ok(KaramelNumber::Decimal(KaramelNumber::Float(number).to_decimal())),
+            (Some(KaramelNumber::Float(_)), _) => expected_parameter_type!(function.to_string(), "Sayı".to_string()),
+            (Some(number), _) => Ok(number),
+            (None, KaramelPrimative::Text(text)) => match KaramelNumber::parse_decimal(text) {
+                Some(number) => Ok(number),
+                None => expected_parameter_type!(function.to_string(), "Sayı".to_string()),
+            },
+            _ => expected_parameter_type!(function.to_string(), "Sayı".to_string()),
+        }
+    }
+
+    pub fn decimal(parameter: FunctionParameter) -> NativeCallResult {
+        if parameter.length() != 1 {
+            return n_parameter_expected!("ondalık".to_string(), 1, parameter.length());
+        }
+
+        let number = Self::exact_number("ondalık", parameter.iter().next().unwrap())?;
+        Ok(VmObject::from(KaramelNumber::Decimal(number.to_decimal())))
+    }
+
+    pub fn fraction(parameter: FunctionParameter) -> NativeCallResult {
+        if parameter.length() != 2 {
+            return n_parameter_expected!("kesir".to_string(), 2, parameter.length());
+        }
+
+        let mut iter = parameter.iter();
+        let numerator = Self::exact_number("kesir", iter.next().unwrap())?;
+        let denominator = Self::exact_number("kesir", iter.next().unwrap())?;
+
+        if denominator.to_rational().is_zero() {
+            return Err(KaramelErrorType::DivisionByZero);
+        }
+
+        Ok(VmObject::from(KaramelNumber::Fraction(numerator.to_rational() / denominator.to_rational())))
+    }
+
     pub fn parse(parameter: FunctionParameter) -> NativeCallResult {
         if parameter.length() > 1 {
             return n_parameter_expected!("oku".to_string(), 1);
@@ -73,9 +132,9 @@ impl NumModule {
         };
 
         match &*arg {
-            KaramelPrimative::Number(_) => Ok(*parameter.iter().next().unwrap()),
-            KaramelPrimative::Text(text) => match (*text).parse::<f64>() {
-                Ok(num) => Ok(VmObject::from(num)),
+            KaramelPrimative::Number(_) | KaramelPrimative::Integer(_) | KaramelPrimative::BigInteger(_) | KaramelPrimative::Decimal(_) | KaramelPrimative::Fraction(_) => Ok(*parameter.iter().next().unwrap()),
+            KaramelPrimative::Text(text) => match KaramelNumber::parse(text) {
+                Some(num) => Ok(VmObject::from(num)),
                 _ => expected_parameter_type!("oku".to_string(), "Yazı".to_string()),
             },
             _ => Ok(EMPTY_OBJECT),
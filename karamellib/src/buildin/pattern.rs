@@ -0,0 +1,74 @@
+use crate::buildin::class::pattern::compile;
+use crate::buildin::{Class, Module};
+use crate::compiler::function::{FunctionArgument, FunctionParameter, FunctionReference, NativeCall, NativeCallResult};
+use crate::compiler::value::KaramelPrimative;
+use crate::expected_parameter_type;
+use crate::types::VmObject;
+use std::rc::Rc;
+use std::{cell::RefCell, collections::HashMap};
+
+pub struct PatternModule {
+    methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
+    path: Vec<String>,
+}
+
+impl Module for PatternModule {
+    fn get_module_name(&self) -> String {
+        "desen".to_string()
+    }
+
+    fn get_path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    fn get_method(&self, name: &str) -> Option<Rc<FunctionReference>> {
+        self.methods.borrow().get(name).cloned()
+    }
+
+    fn get_module(&self, _: &str) -> Option<Rc<dyn Module>> {
+        None
+    }
+
+    fn get_methods(&self) -> Vec<Rc<FunctionReference>> {
+        let mut response = Vec::new();
+        self.methods
+            .borrow()
+            .iter()
+            .for_each(|(_, reference)| response.push(reference.clone()));
+        response
+    }
+
+    fn get_modules(&self) -> HashMap<String, Rc<dyn Module>> {
+        HashMap::new()
+    }
+
+    fn get_classes(&self) -> Vec<Rc<dyn Class>> {
+        Vec::new()
+    }
+}
+
+impl PatternModule {
+    pub fn new() -> Rc<PatternModule> {
+        let module = PatternModule {
+            methods: RefCell::new(HashMap::new()),
+            path: vec!["desen".to_string()],
+        };
+
+        let rc_module = Rc::new(module);
+        for name in ["oluştur", "olustur"] {
+            rc_module
+                .methods
+                .borrow_mut()
+                .insert(name.to_string(), FunctionReference::native_function_with_arguments(Self::create as NativeCall, name.to_string(), vec![FunctionArgument::new("kalıp")], rc_module.clone()));
+        }
+        rc_module.clone()
+    }
+
+    /// Compiled pattern, it could be used many times without compiling again.
+    pub fn create(parameter: FunctionParameter) -> NativeCallResult {
+        match &*parameter.iter().next().unwrap().deref() {
+            KaramelPrimative::Text(pattern) => Ok(VmObject::native_convert(KaramelPrimative::Pattern(compile(pattern)?))),
+            _ => expected_parameter_type!("oluştur".to_string(), "Yazı".to_string()),
+        }
+    }
+}
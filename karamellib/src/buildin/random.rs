@@ -0,0 +1,249 @@
+use crate::buildin::{Class, Module};
+use crate::compiler::function::{FunctionArgument, FunctionParameter, FunctionReference, NativeCall, NativeCallResult};
+use crate::compiler::value::KaramelPrimative;
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::error::KaramelErrorType;
+use crate::expected_parameter_type;
+use crate::types::VmObject;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::rc::Rc;
+use std::{cell::RefCell, collections::HashMap};
+
+/// SplitMix64 pseudo random number generator. It does not depend on the operating system, so the same seed generates the same numbers on every platform.
+#[derive(Clone, Debug)]
+pub struct RandomGenerator {
+    state: u64,
+}
+
+impl Default for RandomGenerator {
+    fn default() -> Self {
+        /* Hash keys of the standard library are randomized by the operating system if it is supported */
+        RandomGenerator::new(RandomState::new().build_hasher().finish())
+    }
+}
+
+impl RandomGenerator {
+    pub fn new(seed: u64) -> Self {
+        RandomGenerator { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut result = self.state;
+        result = (result ^ (result >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        result = (result ^ (result >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        result ^ (result >> 31)
+    }
+
+    /// Floating number between 0 (included) and 1 (excluded).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Unbiased number between 0 (included) and 'limit' (excluded).
+    pub fn next_below(&mut self, limit: u64) -> u64 {
+        let zone = u64::MAX - (u64::MAX % limit);
+        loop {
+            let number = self.next_u64();
+            if number < zone {
+                return number % limit;
+            }
+        }
+    }
+}
+
+pub struct RandomModule {
+    methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
+    path: Vec<String>,
+}
+
+impl Module for RandomModule {
+    fn get_module_name(&self) -> String {
+        "rastgele".to_string()
+    }
+
+    fn get_path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    fn get_method(&self, name: &str) -> Option<Rc<FunctionReference>> {
+        self.methods.borrow().get(name).cloned()
+    }
+
+    fn get_module(&self, _: &str) -> Option<Rc<dyn Module>> {
+        None
+    }
+
+    fn get_methods(&self) -> Vec<Rc<FunctionReference>> {
+        let mut response = Vec::new();
+        self.methods
+            .borrow()
+            .iter()
+            .for_each(|(_, reference)| response.push(reference.clone()));
+        response
+    }
+
+    fn get_modules(&self) -> HashMap<String, Rc<dyn Module>> {
+        HashMap::new()
+    }
+
+    fn get_classes(&self) -> Vec<Rc<dyn Class>> {
+        Vec::new()
+    }
+}
+
+impl RandomModule {
+    pub fn new() -> Rc<RandomModule> {
+        let module = RandomModule {
+            methods: RefCell::new(HashMap::new()),
+            path: vec!["rastgele".to_string()],
+        };
+
+        let rc_module = Rc::new(module);
+        let functions: [(&[&str], NativeCall, Vec<FunctionArgument>); 6] = [
+            (&["sayı", "sayi"], Self::integer, vec![FunctionArgument::new("en_az"), FunctionArgument::new("en_çok")]),
+            (&["ondalık", "ondalik"], Self::float, Vec::new()),
+            (&["seç", "sec"], Self::choose, vec![FunctionArgument::new("liste")]),
+            (&["karıştır", "karistir"], Self::shuffle, vec![FunctionArgument::new("liste")]),
+            (&["örnekle", "ornekle"], Self::sample, vec![FunctionArgument::new("liste"), FunctionArgument::new("adet")]),
+            (&["tohum"], Self::seed, vec![FunctionArgument::new("sayı")]),
+        ];
+
+        for (names, function, arguments) in functions {
+            for name in names {
+                rc_module
+                    .methods
+                    .borrow_mut()
+                    .insert(name.to_string(), FunctionReference::native_function_with_arguments(function, name.to_string(), arguments.clone(), rc_module.clone()));
+            }
+        }
+
+        rc_module.clone()
+    }
+
+    /// Generator of the running script. Functions called without context, like unit tests, use a new generator.
+    fn generate<T>(parameter: &FunctionParameter, callback: impl FnOnce(&mut RandomGenerator) -> T) -> T {
+        match parameter.context() {
+            Some(context) => callback(&mut context.random.borrow_mut()),
+            None => callback(&mut RandomGenerator::default()),
+        }
+    }
+
+    fn integer_parameter(function: &str, parameter: &VmObject) -> Result<i64, KaramelErrorType> {
+        match &*parameter.deref() {
+            KaramelPrimative::Integer(number) => Ok(*number),
+            KaramelPrimative::Number(number) if number.fract() == 0.0 && (i64::MIN as f64..i64::MAX as f64).contains(number) => Ok(*number as i64),
+            _ => expected_parameter_type!(function.to_string(), "Tam sayı".to_string()),
+        }
+    }
+
+    pub fn integer(parameter: FunctionParameter) -> NativeCallResult {
+        let mut iter = parameter.iter();
+        let minimum = Self::integer_parameter("sayı", iter.next().unwrap())?;
+        let maximum = Self::integer_parameter("sayı", iter.next().unwrap())?;
+
+        if minimum > maximum {
+            return expected_parameter_type!("sayı".to_string(), "Küçükten büyüğe aralık".to_string());
+        }
+
+        let range = maximum.abs_diff(minimum);
+        let offset = match range.checked_add(1) {
+            Some(limit) => Self::generate(&parameter, |generator| generator.next_below(limit)),
+            None => Self::generate(&parameter, |generator| generator.next_u64()),
+        };
+        Ok(VmObject::from(minimum.wrapping_add(offset as i64)))
+    }
+
+    pub fn float(parameter: FunctionParameter) -> NativeCallResult {
+        Ok(VmObject::from(Self::generate(&parameter, |generator| generator.next_f64())))
+    }
+
+    fn items(function: &str, parameter: &VmObject) -> Result<Vec<VmObject>, KaramelErrorType> {
+        match &*parameter.deref() {
+            KaramelPrimative::List(items) => Ok(items.borrow().clone()),
+            KaramelPrimative::Tuple(items) => Ok(items.clone()),
+            _ => expected_parameter_type!(function.to_string(), "Liste".to_string()),
+        }
+    }
+
+    pub fn choose(parameter: FunctionParameter) -> NativeCallResult {
+        let items = Self::items("seç", parameter.iter().next().unwrap())?;
+        match items.is_empty() {
+            true => Ok(EMPTY_OBJECT),
+            false => Ok(items[Self::generate(&parameter, |generator| generator.next_below(items.len() as u64)) as usize]),
+        }
+    }
+
+    /// Fisher-Yates shuffle, the list itself changed.
+    pub fn shuffle(parameter: FunctionParameter) -> NativeCallResult {
+        let list = parameter.iter().next().unwrap().deref();
+        match &*list {
+            KaramelPrimative::List(items) => {
+                let mut items = items.borrow_mut();
+                Self::generate(&parameter, |generator| {
+                    for index in (1..items.len()).rev() {
+                        items.swap(index, generator.next_below(index as u64 + 1) as usize);
+                    }
+                });
+                Ok(EMPTY_OBJECT)
+            }
+            _ => expected_parameter_type!("karıştır".to_string(), "Liste".to_string()),
+        }
+    }
+
+    /// New list with randomly selected items, every position selected only once.
+    pub fn sample(parameter: FunctionParameter) -> NativeCallResult {
+        let mut iter = parameter.iter();
+        let mut items = Self::items("örnekle", iter.next().unwrap())?;
+        let count = Self::integer_parameter("örnekle", iter.next().unwrap())?;
+
+        if count < 0 || count as usize > items.len() {
+            return expected_parameter_type!("örnekle".to_string(), format!("0 ile {} arasında adet", items.len()));
+        }
+
+        let count = count as usize;
+        Self::generate(&parameter, |generator| {
+            for index in 0..count {
+                let selected = index + generator.next_below((items.len() - index) as u64) as usize;
+                items.swap(index, selected);
+            }
+        });
+
+        items.truncate(count);
+        Ok(VmObject::native_convert(KaramelPrimative::List(RefCell::new(items))))
+    }
+
+    pub fn seed(parameter: FunctionParameter) -> NativeCallResult {
+        let seed = Self::integer_parameter("tohum", parameter.iter().next().unwrap())?;
+        if let Some(context) = parameter.context() {
+            *context.random.borrow_mut() = RandomGenerator::new(seed as u64);
+        }
+        Ok(EMPTY_OBJECT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed() {
+        let mut first = RandomGenerator::new(42);
+        let mut second = RandomGenerator::new(42);
+        for _ in 0..100 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut generator = RandomGenerator::new(7);
+        for _ in 0..1000 {
+            assert!(generator.next_below(6) < 6);
+
+            let number = generator.next_f64();
+            assert!((0.0..1.0).contains(&number));
+        }
+    }
+}
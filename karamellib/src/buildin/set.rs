@@ -0,0 +1,91 @@
+use crate::buildin::class::set::{set_item, set_items};
+use crate::buildin::{Class, Module};
+use crate::compiler::function::{FunctionArgument, FunctionParameter, FunctionReference, NativeCall, NativeCallResult};
+use crate::compiler::value::KaramelPrimative;
+use crate::n_parameter_expected;
+use crate::types::VmObject;
+use indexmap::IndexSet;
+use std::rc::Rc;
+use std::{cell::RefCell, collections::HashMap};
+
+pub struct SetModule {
+    methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
+    path: Vec<String>,
+}
+
+impl Module for SetModule {
+    fn get_module_name(&self) -> String {
+        "küme".to_string()
+    }
+
+    fn get_path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    fn get_method(&self, name: &str) -> Option<Rc<FunctionReference>> {
+        self.methods.borrow().get(name).cloned()
+    }
+
+    fn get_module(&self, _: &str) -> Option<Rc<dyn Module>> {
+        None
+    }
+
+    fn get_methods(&self) -> Vec<Rc<FunctionReference>> {
+        let mut response = Vec::new();
+        self.methods
+            .borrow()
+            .iter()
+            .for_each(|(_, reference)| response.push(reference.clone()));
+        response
+    }
+
+    fn get_modules(&self) -> HashMap<String, Rc<dyn Module>> {
+        HashMap::new()
+    }
+
+    fn get_classes(&self) -> Vec<Rc<dyn Class>> {
+        Vec::new()
+    }
+}
+
+impl SetModule {
+    pub fn new() -> Rc<SetModule> {
+        let module = SetModule {
+            methods: RefCell::new(HashMap::new()),
+            path: vec!["küme".to_string()],
+        };
+
+        let rc_module = Rc::new(module);
+        for name in ["oluştur", "olustur"] {
+            rc_module
+                .methods
+                .borrow_mut()
+                .insert(name.to_string(), FunctionReference::native_function(Self::create as NativeCall, name.to_string(), rc_module.clone()));
+        }
+
+        rc_module.methods.borrow_mut().insert(
+            "listeden".to_string(),
+            FunctionReference::native_function_with_arguments(Self::from_list as NativeCall, "listeden".to_string(), vec![FunctionArgument::new("değerler")], rc_module.clone()),
+        );
+        rc_module.clone()
+    }
+
+    /// New set with the given items, same items added only once.
+    pub fn create(parameter: FunctionParameter) -> NativeCallResult {
+        let items = parameter
+            .iter()
+            .map(set_item)
+            .collect::<Result<IndexSet<_>, _>>()?;
+        Ok(VmObject::native_convert(KaramelPrimative::Set(RefCell::new(items))))
+    }
+
+    /// New set with the items of the list, tuple or set.
+    pub fn from_list(parameter: FunctionParameter) -> NativeCallResult {
+        if parameter.length() != 1 {
+            return n_parameter_expected!("listeden".to_string(), 1, parameter.length());
+        }
+
+        let items = set_items("listeden", parameter.iter().next().unwrap())?;
+        Ok(VmObject::native_convert(KaramelPrimative::Set(RefCell::new(items))))
+    }
+}
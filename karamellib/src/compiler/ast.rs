@@ -2,6 +2,7 @@ use std::cell::Cell;
 use std::rc::Rc;
 use std::vec::Vec;
 
+use crate::compiler::function::FunctionArgument;
 use crate::compiler::value::KaramelPrimative;
 use crate::syntax::loops::LoopType;
 use crate::types::KaramelOperatorType;
@@ -20,6 +21,37 @@ pub struct KaramelDictItem {
     pub value: Rc<KaramelAstType>,
 }
 
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum KaramelMatchPattern {
+    Wildcard,
+    Literal(Rc<KaramelPrimative>),
+    Binding(String),
+    List { items: Vec<Rc<KaramelMatchPattern>>, rest: Option<String> },
+    Dict(Vec<(Rc<KaramelPrimative>, Rc<KaramelMatchPattern>)>),
+}
+
+impl KaramelMatchPattern {
+    /// Pattern accepts every value without any check
+    pub fn is_irrefutable(&self) -> bool {
+        matches!(self, KaramelMatchPattern::Wildcard | KaramelMatchPattern::Binding(_))
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KaramelMatchCase {
+    pub patterns: Vec<Rc<KaramelMatchPattern>>,
+    pub guard: Option<Rc<KaramelAstType>>,
+    pub body: Rc<KaramelAstType>,
+}
+
+impl KaramelMatchCase {
+    pub fn new(patterns: Vec<Rc<KaramelMatchPattern>>, guard: Option<Rc<KaramelAstType>>, body: Rc<KaramelAstType>) -> KaramelMatchCase {
+        KaramelMatchCase { patterns, guard, body }
+    }
+}
+
 impl KaramelIfStatementElseItem {
     pub fn new(condition: Rc<KaramelAstType>, body: Rc<KaramelAstType>) -> KaramelIfStatementElseItem {
         KaramelIfStatementElseItem { condition, body }
@@ -37,6 +69,10 @@ pub enum KaramelAstType {
         arguments: Vec<Rc<KaramelAstType>>,
         assign_to_temp: Cell<bool>,
     },
+    NamedArgument {
+        name: String,
+        expression: Rc<KaramelAstType>,
+    },
     AccessorFuncCall {
         source: Rc<KaramelAstType>,
         indexer: Rc<KaramelAstType>,
@@ -73,7 +109,7 @@ pub enum KaramelAstType {
     },
     FunctionDefination {
         name: String,
-        arguments: Vec<String>,
+        arguments: Vec<FunctionArgument>,
         body: Rc<KaramelAstType>,
     },
     Symbol(String),
@@ -81,6 +117,7 @@ pub enum KaramelAstType {
     Load(Vec<String>),
     List(Vec<Rc<KaramelAstType>>),
     Dict(Vec<Rc<KaramelDictItem>>),
+    Tuple(Vec<Rc<KaramelAstType>>),
     Indexer {
         body: Rc<KaramelAstType>,
         indexer: Rc<KaramelAstType>,
@@ -92,4 +129,9 @@ pub enum KaramelAstType {
         loop_type: LoopType,
         body: Rc<KaramelAstType>,
     },
+    Match {
+        value: Rc<KaramelAstType>,
+        variable: String,
+        cases: Vec<Rc<KaramelMatchCase>>,
+    },
 }
@@ -0,0 +1,210 @@
+use std::collections::HashSet;
+use std::fs::canonicalize;
+use std::path::{absolute, Path, PathBuf};
+
+use crate::compiler::function::{FunctionReference, FunctionType};
+use crate::error::KaramelErrorType;
+
+const FILE_MODULE: &str = "dosya";
+const IO_MODULE: &str = "gç";
+const READ_FUNCTIONS: [&str; 2] = ["satıroku", "satiroku"];
+
+/// Permission of the 'yükle' keyword for the script files.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum LoadPermission {
+    #[default]
+    Everywhere,
+
+    /// Only the files inside of the directory could be loaded
+    Inside(PathBuf),
+    Denied,
+}
+
+/// Capabilities (yetkiler) of the script, decides which native modules and functions could be used.
+/// Denied functions give error at compile time, functions that reached as a value are checked before the call.
+///
+/// ```
+/// use karamellib::compiler::capabilities::Capabilities;
+///
+/// let capabilities = Capabilities::sandbox()
+///     .load_inside("ornekler")
+///     .deny_function("matematik", "kök");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Capabilities {
+    file_access: bool,
+    stdin: bool,
+    load: LoadPermission,
+    allowed_modules: Option<HashSet<String>>,
+    denied_modules: HashSet<String>,
+    denied_functions: HashSet<(String, String)>,
+}
+
+impl Default for Capabilities {
+    /// Everything except the file system is allowed.
+    fn default() -> Self {
+        Capabilities {
+            file_access: false,
+            stdin: true,
+            load: LoadPermission::Everywhere,
+            allowed_modules: None,
+            denied_modules: HashSet::new(),
+            denied_functions: HashSet::new(),
+        }
+    }
+}
+
+impl Capabilities {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every module and function could be used, including the file system.
+    pub fn all() -> Self {
+        Self::default().file_access(true)
+    }
+
+    /// No file system, no input and no 'yükle'. Used for the scripts that could not be trusted.
+    pub fn sandbox() -> Self {
+        Self::default().stdin(false).deny_load()
+    }
+
+    /// Permission of the 'dosya' module.
+    pub fn file_access(mut self, allowed: bool) -> Self {
+        self.file_access = allowed;
+        self
+    }
+
+    /// Permission of the 'gç::satıroku' function.
+    pub fn stdin(mut self, allowed: bool) -> Self {
+        self.stdin = allowed;
+        self
+    }
+
+    pub fn load_inside<T: Into<PathBuf>>(mut self, root: T) -> Self {
+        self.load = LoadPermission::Inside(root.into());
+        self
+    }
+
+    pub fn deny_load(mut self) -> Self {
+        self.load = LoadPermission::Denied;
+        self
+    }
+
+    /// Only the given native modules could be used, modules that added later are denied too.
+    pub fn only_modules<T: Into<String>, I: IntoIterator<Item = T>>(mut self, modules: I) -> Self {
+        self.allowed_modules = Some(modules.into_iter().map(|module| module.into()).collect());
+        self
+    }
+
+    pub fn deny_module<T: Into<String>>(mut self, module: T) -> Self {
+        self.denied_modules.insert(module.into());
+        self
+    }
+
+    pub fn deny_function<M: Into<String>, F: Into<String>>(mut self, module: M, function: F) -> Self {
+        self.denied_functions
+            .insert((module.into(), function.into()));
+        self
+    }
+
+    pub fn has_file_access(&self) -> bool {
+        self.file_access
+    }
+
+    pub fn is_module_allowed(&self, module: &str) -> bool {
+        let allowed = match &self.allowed_modules {
+            Some(modules) => modules.contains(module),
+            None => true,
+        };
+        allowed && !self.denied_modules.contains(module) && (self.file_access || module != FILE_MODULE)
+    }
+
+    /// Functions of the scripts are always allowed, native functions are checked with their module path.
+    pub fn check_function(&self, function: &FunctionReference) -> Result<(), KaramelErrorType> {
+        if let FunctionType::Opcode = function.callback {
+            return Ok(());
+        }
+
+        let module = function.module.get_path().join("::");
+        if module == FILE_MODULE && !self.file_access {
+            return Err(KaramelErrorType::FileAccessNotAllowed);
+        }
+
+        if module == IO_MODULE && !self.stdin && READ_FUNCTIONS.contains(&&function.name[..]) {
+            return Err(KaramelErrorType::StdinNotAllowed);
+        }
+
+        if !self.is_module_allowed(&module) {
+            return Err(KaramelErrorType::ModuleNotAllowed(module));
+        }
+
+        match self
+            .denied_functions
+            .contains(&(module.to_string(), function.name.to_string()))
+        {
+            true => Err(KaramelErrorType::FunctionNotAllowed { module, function: function.name.to_string() }),
+            false => Ok(()),
+        }
+    }
+
+    /// Constants of the native modules could be used if the module is allowed.
+    pub fn check_module(&self, module_path: &[String]) -> Result<(), KaramelErrorType> {
+        let module = module_path.join("::");
+        match self.is_module_allowed(&module) {
+            true => Ok(()),
+            false => Err(KaramelErrorType::ModuleNotAllowed(module)),
+        }
+    }
+
+    /// Checks the file of the 'yükle' before reading it.
+    pub fn check_load(&self, path: &Path) -> Result<(), KaramelErrorType> {
+        let allowed = match &self.load {
+            LoadPermission::Everywhere => true,
+            LoadPermission::Denied => false,
+            LoadPermission::Inside(root) => {
+                let root = canonicalize(root).or_else(|_| absolute(root));
+                let path = canonicalize(path).or_else(|_| absolute(path));
+                match (path, root) {
+                    (Ok(path), Ok(root)) => path.starts_with(root),
+                    _ => false,
+                }
+            }
+        };
+
+        match allowed {
+            true => Ok(()),
+            false => Err(KaramelErrorType::LoadNotAllowed(path.to_string_lossy().to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_permission() {
+        let path = Path::new("test_modules").join("modul");
+        assert!(Capabilities::new().check_load(&path).is_ok());
+        assert!(Capabilities::new()
+            .load_inside("test_modules")
+            .check_load(&path)
+            .is_ok());
+        assert_eq!(Capabilities::new().load_inside("src").check_load(&path), Err(KaramelErrorType::LoadNotAllowed(path.to_string_lossy().to_string())));
+        assert!(Capabilities::sandbox().check_load(&path).is_err());
+    }
+
+    #[test]
+    fn test_modules() {
+        let capabilities = Capabilities::new()
+            .only_modules(["gç", "matematik", "dosya"])
+            .deny_module("matematik");
+
+        assert!(capabilities.is_module_allowed("gç"));
+        assert!(!capabilities.is_module_allowed("matematik"));
+        assert!(!capabilities.is_module_allowed("dosya"));
+        assert!(!capabilities.is_module_allowed("rastgele"));
+        assert_eq!(capabilities.check_module(&["rastgele".to_string()]), Err(KaramelErrorType::ModuleNotAllowed("rastgele".to_string())));
+    }
+}
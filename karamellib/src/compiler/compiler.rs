@@ -6,9 +6,8 @@ use std::rc::Rc;
 use std::vec::Vec;
 
 use super::generator::location::OpcodeLocation;
-use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
 use crate::buildin::Module;
-use crate::compiler::ast::{KaramelAstType, KaramelIfStatementElseItem};
+use crate::compiler::ast::{KaramelAstType, KaramelIfStatementElseItem, KaramelMatchCase, KaramelMatchPattern};
 use crate::compiler::function::FunctionReference;
 use crate::compiler::storage_builder::StorageBuilder;
 use crate::compiler::value::KaramelPrimative;
@@ -27,6 +26,13 @@ use super::context::KaramelCompilerContext;
 use super::function::find_function_definition_type;
 use super::module::{get_modules, OpcodeModule};
 
+/// Variable that should be filled after all pattern checks are passed
+struct PatternBinding {
+    name: String,
+    path: Vec<Rc<KaramelPrimative>>,
+    tail: Option<u8>,
+}
+
 pub struct InterpreterCompiler;
 impl InterpreterCompiler {
     pub fn compile(&self, main_ast: Rc<KaramelAstType>, context: &mut KaramelCompilerContext) -> CompilerResult {
@@ -68,6 +74,66 @@ impl InterpreterCompiler {
         Ok(())
     }
 
+    /// Compiles the code after the already compiled program. Variables, functions and modules of the program
+    /// could be used by the code. Returns the position of the first opcode of the code.
+    pub fn compile_incremental(&self, main_ast: Rc<KaramelAstType>, context: &mut KaramelCompilerContext) -> Result<usize, KaramelErrorType> {
+        let main_module = match context.main_module.is_null() {
+            true => return Err(KaramelErrorType::ScriptNotExecuted),
+            false => unsafe {
+                /* Main module is kept by the modules of the context */
+                Rc::increment_strong_count(context.main_module);
+                Rc::from_raw(context.main_module as *const OpcodeModule)
+            },
+        };
+
+        let generator_size = context.opcode_generator.len();
+        let functions = main_module.functions.borrow().clone();
+        let storage_sizes = context
+            .storages
+            .iter()
+            .map(|storage| (storage.variables.len(), storage.constants.len()))
+            .collect::<Vec<_>>();
+
+        match self.compile_code(main_module.clone(), main_ast, context) {
+            Ok(main_location) => {
+                context.opcode_generator.generate(&mut context.opcodes);
+                context.opcodes_top_ptr = context.opcodes.as_mut_ptr();
+                context.opcodes_ptr = context.opcodes_top_ptr;
+                Ok(main_location.get())
+            }
+            Err(error) => {
+                /* Failed code should not change the program */
+                context.opcode_generator.truncate(generator_size);
+                *main_module.functions.borrow_mut() = functions;
+                for (storage, (variables, constants)) in context.storages.iter_mut().zip(storage_sizes) {
+                    storage.variables.truncate(variables);
+                    storage.constants.truncate(constants);
+                }
+                Err(error)
+            }
+        }
+    }
+
+    fn compile_code(&self, main_module: Rc<OpcodeModule>, main_ast: Rc<KaramelAstType>, context: &mut KaramelCompilerContext) -> Result<Rc<OpcodeLocation>, KaramelErrorType> {
+        let storage_builder: StorageBuilder = StorageBuilder::new();
+        let modules = self.detect_modules(main_ast.clone(), context)?;
+        find_function_definition_type(main_module.clone(), main_ast.clone(), context, 0, true)?;
+        storage_builder.prepare(main_module.clone(), &main_ast, 0, context)?;
+
+        let mut functions = Vec::new();
+        for module in modules.iter() {
+            self.get_function_definations(module.clone(), module.main_ast.clone(), &mut functions, context, module.storage_index)?;
+        }
+
+        self.get_function_definations(main_module.clone(), main_ast.clone(), &mut functions, context, 0)?;
+        self.generate_functions(main_module.clone(), &functions, context)?;
+
+        let main_location = context.opcode_generator.current_location();
+        self.generate_opcode(main_module.clone(), &main_ast, &KaramelAstType::None, context, 0)?;
+        context.opcode_generator.add_opcode(VmOpCode::Halt);
+        Ok(main_location)
+    }
+
     pub fn detect_modules(&self, main_ast: Rc<KaramelAstType>, context: &mut KaramelCompilerContext) -> Result<Vec<Rc<OpcodeModule>>, KaramelErrorType> {
         Ok(get_modules(main_ast.clone(), context)?)
     }
@@ -82,16 +148,12 @@ impl InterpreterCompiler {
         Ok(module.clone())
     }
 
-    pub fn check_prohibited_names<T: Borrow<String>>(&self, variable: T) -> Result<(), KaramelErrorType> {
+    pub fn check_prohibited_names<T: Borrow<String>>(&self, variable: T, context: &KaramelCompilerContext) -> Result<(), KaramelErrorType> {
         if KEYWORDS.iter().any(|(key, _)| variable.borrow() == *key) {
             return Err(KaramelErrorType::ReservedName(variable.borrow().to_string()));
         }
 
-        if PRIMATIVE_CLASS_NAMES
-            .lock()
-            .unwrap()
-            .contains(variable.borrow())
-        {
+        if context.is_class_name(variable.borrow()) {
             return Err(KaramelErrorType::ReservedName(variable.borrow().to_string()));
         }
 
@@ -140,12 +202,12 @@ impl InterpreterCompiler {
     fn generate_functions(&self, module: Rc<OpcodeModule>, functions: &[Rc<FunctionReference>], context: &mut KaramelCompilerContext) -> CompilerResult {
         for function in functions.iter() {
             /* Validate function name and parameters */
-            self.check_prohibited_names(&function.name)?;
+            self.check_prohibited_names(&function.name, context)?;
             for argument in &function.arguments {
-                self.check_prohibited_names(argument)?;
+                self.check_prohibited_names(&argument.name, context)?;
             }
 
-            self.check_prohibited_names(&function.name)?;
+            self.check_prohibited_names(&function.name, context)?;
             context
                 .opcode_generator
                 .create_function_definition(function.clone());
@@ -165,7 +227,9 @@ impl InterpreterCompiler {
             KaramelAstType::Primative(primative) => self.generate_primative(primative.clone(), upper_ast, context, storage_index),
             KaramelAstType::List(list) => self.generate_list(module.clone(), list, upper_ast, context, storage_index),
             KaramelAstType::Dict(dict) => self.generate_dict(module.clone(), dict, upper_ast, context, storage_index),
+            KaramelAstType::Tuple(items) => self.generate_tuple(module.clone(), items, upper_ast, context, storage_index),
             KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp } => self.generate_func_call(module.clone(), func_name_expression, arguments, assign_to_temp.get(), upper_ast, context, storage_index),
+            KaramelAstType::NamedArgument { name: _, expression: _ } => Err(KaramelErrorType::InvalidExpression),
             KaramelAstType::AccessorFuncCall { source, indexer, assign_to_temp } => self.generate_accessor_func_call(module.clone(), source, indexer, assign_to_temp.get(), upper_ast, context, storage_index),
             KaramelAstType::PrefixUnary { operator, expression, assign_to_temp } => self.generate_prefix_unary(module.clone(), operator, expression, assign_to_temp, upper_ast, context, storage_index),
             KaramelAstType::SuffixUnary(operator, expression) => self.generate_suffix_unary(operator, expression, upper_ast, context, storage_index),
@@ -180,6 +244,7 @@ impl InterpreterCompiler {
             KaramelAstType::FunctionDefination { name: _, arguments: _, body: _ } => Ok(()),
             KaramelAstType::ModulePath(name) => self.generate_function_map(name, context, storage_index),
             KaramelAstType::Load(names) => self.generate_load_module(names, context),
+            KaramelAstType::Match { value, variable, cases } => self.generate_match(module.clone(), value, variable, cases, upper_ast, context, storage_index),
         }
     }
 
@@ -232,19 +297,20 @@ impl InterpreterCompiler {
         let name = params[params.len() - 1].to_string();
         let module_path = params[0..(params.len() - 1)].to_vec();
 
-        let function_search = context.get_function(&name, &module_path, storage_index);
-        match function_search {
-            Some(reference) => {
-                let result = storage.get_constant_location(Rc::new(KaramelPrimative::Function(reference, None)));
-                match result {
-                    Some(index) => {
-                        context.opcode_generator.create_constant(index);
-                        Ok(())
-                    }
-                    _ => Err(KaramelErrorType::FunctionNotFoundInStorage(name.to_string())),
-                }
+        let constant = match context.get_function(&name, &module_path, storage_index) {
+            Some(reference) => Rc::new(KaramelPrimative::Function(reference, None)),
+            None => match context.get_module_constant(&name, &module_path) {
+                Some(constant) => constant,
+                None => return Err(KaramelErrorType::FunctionNotFoundInStorage(name.to_string())),
+            },
+        };
+
+        match storage.get_constant_location(constant) {
+            Some(index) => {
+                context.opcode_generator.create_constant(index);
+                Ok(())
             }
-            None => Err(KaramelErrorType::FunctionNotFoundInStorage(name.to_string())),
+            _ => Err(KaramelErrorType::FunctionNotFoundInStorage(name.to_string())),
         }
     }
 
@@ -269,6 +335,14 @@ impl InterpreterCompiler {
         Ok(())
     }
 
+    fn generate_tuple(&self, module: Rc<OpcodeModule>, items: &[Rc<KaramelAstType>], upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        for item in items.iter().rev() {
+            self.generate_opcode(module.clone(), item, upper_ast, context, storage_index)?;
+        }
+        context.opcode_generator.create_init_tuple(items.len());
+        Ok(())
+    }
+
     fn generate_dict(&self, module: Rc<OpcodeModule>, dict: &[Rc<KaramelDictItem>], upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         for item in dict.iter().rev() {
             self.generate_primative(item.key.clone(), upper_ast, context, storage_index)?;
@@ -278,7 +352,30 @@ impl InterpreterCompiler {
         Ok(())
     }
 
-    fn generate_func_call_by_name(&self, name: &String, module_path: &Vec<String>, arguments: &[Rc<KaramelAstType>], assign_to_temp: bool, context: &mut KaramelCompilerContext, storage_index: usize) -> Result<bool, KaramelErrorType> {
+    /* Positional arguments are pushed as is, named arguments are packed into a dictionary and passed as the last argument */
+    fn generate_call_arguments(&self, module: Rc<OpcodeModule>, arguments: &[Rc<KaramelAstType>], upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> Result<(u8, bool), KaramelErrorType> {
+        let mut named_size = 0;
+
+        for argument in arguments {
+            match &**argument {
+                KaramelAstType::NamedArgument { name, expression } => {
+                    self.generate_primative(Rc::new(KaramelPrimative::Text(Rc::new(name.to_string()))), upper_ast, context, storage_index)?;
+                    self.generate_opcode(module.clone(), expression, upper_ast, context, storage_index)?;
+                    named_size += 1;
+                }
+                _ => self.generate_opcode(module.clone(), argument, upper_ast, context, storage_index)?,
+            }
+        }
+
+        if named_size > 0 {
+            context.opcode_generator.create_init_dict(named_size);
+        }
+
+        Ok(((arguments.len() - named_size + (named_size > 0) as usize) as u8, named_size > 0))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn generate_func_call_by_name(&self, name: &String, module_path: &Vec<String>, argument_size: u8, named_arguments: bool, assign_to_temp: bool, context: &mut KaramelCompilerContext, storage_index: usize) -> Result<bool, KaramelErrorType> {
         let function_search = context.get_function(name.to_string(), module_path, storage_index);
 
         if let Some(function_ref) = function_search {
@@ -287,7 +384,7 @@ impl InterpreterCompiler {
                 Some(location) => {
                     context
                         .opcode_generator
-                        .create_call(location, arguments.len() as u8, assign_to_temp);
+                        .create_call(location, argument_size, named_arguments, assign_to_temp);
                     return Ok(true);
                 }
                 _ => return Err(KaramelErrorType::FunctionNotFound(function_ref.name.to_string())),
@@ -298,7 +395,7 @@ impl InterpreterCompiler {
             context.opcode_generator.create_load(location);
             context
                 .opcode_generator
-                .create_call_stack(arguments.len() as u8, assign_to_temp);
+                .create_call_stack(argument_size, named_arguments, assign_to_temp);
             return Ok(true);
         };
 
@@ -311,9 +408,7 @@ impl InterpreterCompiler {
             match &**func_name_expression {
                 KaramelAstType::Symbol(function_name) => {
                     /* Build arguments */
-                    for argument in arguments {
-                        self.generate_opcode(module.clone(), argument, upper_ast, context, storage_index)?;
-                    }
+                    let (argument_size, named_arguments) = self.generate_call_arguments(module.clone(), arguments, upper_ast, context, storage_index)?;
 
                     self.generate_opcode(module.clone(), source, &KaramelAstType::None, context, storage_index)?;
                     //todo: Pass real object to function as a parameter.
@@ -325,7 +420,7 @@ impl InterpreterCompiler {
                             context.opcode_generator.add_opcode(VmOpCode::GetItem);
                             context
                                 .opcode_generator
-                                .create_call_stack(arguments.len() as u8, assign_to_temp);
+                                .create_call_stack(argument_size, named_arguments, assign_to_temp);
                             Ok(())
                         }
                         _ => Err(KaramelErrorType::FunctionNotFound(function_name.to_string())),
@@ -343,13 +438,11 @@ impl InterpreterCompiler {
     #[allow(clippy::too_many_arguments)]
     fn generate_func_call(&self, module: Rc<OpcodeModule>, func_name_expression: &KaramelAstType, arguments: &[Rc<KaramelAstType>], assign_to_temp: bool, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         /* Build arguments */
-        for argument in arguments {
-            self.generate_opcode(module.clone(), argument, upper_ast, context, storage_index)?;
-        }
+        let (argument_size, named_arguments) = self.generate_call_arguments(module.clone(), arguments, upper_ast, context, storage_index)?;
 
         match &func_name_expression {
             KaramelAstType::Symbol(function_name) => {
-                let result = self.generate_func_call_by_name(function_name, module.get_path(), arguments, assign_to_temp, context, storage_index)?;
+                let result = self.generate_func_call_by_name(function_name, module.get_path(), argument_size, named_arguments, assign_to_temp, context, storage_index)?;
                 match result {
                     true => Ok(()),
                     false => {
@@ -367,12 +460,12 @@ impl InterpreterCompiler {
                 self.generate_func_call(module.clone(), func_name_expression, inner_arguments, true, upper_ast, context, storage_index)?;
                 context
                     .opcode_generator
-                    .create_call_stack(arguments.len() as u8, assign_to_temp);
+                    .create_call_stack(argument_size, named_arguments, assign_to_temp);
                 Ok(())
             }
 
             KaramelAstType::ModulePath(names) => {
-                let result = self.generate_func_call_by_name(&names[names.len() - 1].to_string(), &names[0..(names.len() - 1)].to_vec(), arguments, assign_to_temp, context, storage_index)?;
+                let result = self.generate_func_call_by_name(&names[names.len() - 1].to_string(), &names[0..(names.len() - 1)].to_vec(), argument_size, named_arguments, assign_to_temp, context, storage_index)?;
                 match result {
                     true => Ok(()),
                     false => Err(KaramelErrorType::FunctionNotFound(names[names.len() - 1].to_string())),
@@ -382,7 +475,7 @@ impl InterpreterCompiler {
                 self.generate_opcode(module.clone(), func_name_expression, upper_ast, context, storage_index)?;
                 context
                     .opcode_generator
-                    .create_call_stack(arguments.len() as u8, assign_to_temp);
+                    .create_call_stack(argument_size, named_arguments, assign_to_temp);
                 Ok(())
             }
         }
@@ -506,6 +599,11 @@ impl InterpreterCompiler {
             KaramelOperatorType::NotEqual => VmOpCode::NotEqual,
             KaramelOperatorType::GreaterThan => VmOpCode::GreaterThan,
             KaramelOperatorType::GreaterEqualThan => VmOpCode::GreaterEqualThan,
+            KaramelOperatorType::In => VmOpCode::In,
+            KaramelOperatorType::NotIn => {
+                context.opcode_generator.add_opcode(VmOpCode::In);
+                VmOpCode::Not
+            }
             _ => return Err(KaramelErrorType::OperatorNotValid),
         };
 
@@ -518,7 +616,7 @@ impl InterpreterCompiler {
             KaramelAstType::Symbol(symbol) => {
                 /* Validate function name and parameters */
                 if let KaramelAstType::Symbol(variable_name) = variable {
-                    self.check_prohibited_names(variable_name)?;
+                    self.check_prohibited_names(variable_name, context)?;
                 }
 
                 let location = context
@@ -549,15 +647,9 @@ impl InterpreterCompiler {
 
                     self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
 
-                    let opcode = match operator {
-                        KaramelOperatorType::AssignAddition => VmOpCode::Addition,
-                        KaramelOperatorType::AssignDivision => VmOpCode::Division,
-                        KaramelOperatorType::AssignMultiplication => VmOpCode::Multiply,
-                        KaramelOperatorType::AssignSubtraction => VmOpCode::Subraction,
-                        _ => return Err(KaramelErrorType::OperatorNotValid),
-                    };
-
-                    context.opcode_generator.add_opcode(opcode);
+                    context
+                        .opcode_generator
+                        .add_opcode(Self::get_assignment_opcode(operator)?);
                 } else {
                     self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
                 }
@@ -569,15 +661,96 @@ impl InterpreterCompiler {
             KaramelAstType::Indexer { body, indexer } => {
                 self.generate_opcode(module.clone(), body, &KaramelAstType::None, context, storage_index)?;
                 self.generate_opcode(module.clone(), indexer, &KaramelAstType::None, context, storage_index)?;
-                self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
+
+                if *operator != KaramelOperatorType::Assign {
+                    /* Object and indexer evaluated only once, copies used for reading current value */
+                    context.opcode_generator.add_opcode(VmOpCode::DublicatePair);
+                    context.opcode_generator.add_opcode(VmOpCode::GetItem);
+
+                    self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
+                    context
+                        .opcode_generator
+                        .add_opcode(Self::get_assignment_opcode(operator)?);
+                } else {
+                    self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
+                }
 
                 context.opcode_generator.add_opcode(VmOpCode::SetItem);
                 Ok(())
             }
-            _ => Ok(()),
+
+            KaramelAstType::Tuple(_) | KaramelAstType::List(_) | KaramelAstType::Dict(_) => {
+                if *operator != KaramelOperatorType::Assign {
+                    return Err(KaramelErrorType::OperatorNotValid);
+                }
+
+                self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
+                self.generate_destructuring(variable, context, storage_index)
+            }
+            _ => Err(KaramelErrorType::InvalidAssignmentTarget),
+        }
+    }
+
+    fn get_assignment_opcode(operator: &KaramelOperatorType) -> Result<VmOpCode, KaramelErrorType> {
+        match operator {
+            KaramelOperatorType::AssignAddition => Ok(VmOpCode::Addition),
+            KaramelOperatorType::AssignDivision => Ok(VmOpCode::Division),
+            KaramelOperatorType::AssignMultiplication => Ok(VmOpCode::Multiply),
+            KaramelOperatorType::AssignSubtraction => Ok(VmOpCode::Subraction),
+            _ => Err(KaramelErrorType::OperatorNotValid),
         }
     }
 
+    /*
+    Value at the top of the stack is split into the targets.
+
+    a, [b, c], {'d': d} = değer
+    ╔════════════════════╗
+    ║       değer        ║
+    ╚════════════════════╝
+             ▼  Unpack 3
+    ╔════════════════════╗
+    ║   {'d': d} value   ║
+    ╠════════════════════╣
+    ║   [b, c] value     ║
+    ╠════════════════════╣
+    ║   a value          ║ ◀ Store a
+    ╚════════════════════╝
+    */
+    fn generate_destructuring(&self, target: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        match target {
+            KaramelAstType::Symbol(symbol) => {
+                self.check_prohibited_names(symbol, context)?;
+                let location = context.storages[storage_index].add_variable(symbol);
+                context.opcode_generator.create_store(location);
+            }
+
+            KaramelAstType::Tuple(items) | KaramelAstType::List(items) => {
+                context.opcode_generator.create_unpack(items.len() as u8);
+                for item in items {
+                    self.generate_destructuring(item, context, storage_index)?;
+                }
+            }
+
+            KaramelAstType::Dict(items) => {
+                for item in items {
+                    self.generate_primative(item.key.clone(), &KaramelAstType::None, context, storage_index)?;
+                }
+
+                context
+                    .opcode_generator
+                    .create_unpack_dict(items.len() as u8);
+                for item in items {
+                    self.generate_destructuring(&item.value, context, storage_index)?;
+                }
+            }
+
+            _ => return Err(KaramelErrorType::InvalidAssignmentTarget),
+        };
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn generate_binary(&self, module: Rc<OpcodeModule>, left_ast: &KaramelAstType, operator: &KaramelOperatorType, right_ast: &KaramelAstType, _: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         self.generate_opcode(module.clone(), left_ast, &KaramelAstType::None, context, storage_index)?;
@@ -587,7 +760,14 @@ impl InterpreterCompiler {
             KaramelOperatorType::Subtraction => VmOpCode::Subraction,
             KaramelOperatorType::Multiplication => VmOpCode::Multiply,
             KaramelOperatorType::Division => VmOpCode::Division,
+            KaramelOperatorType::FloorDivision => VmOpCode::FloorDivision,
             KaramelOperatorType::Modulo => VmOpCode::Module,
+            KaramelOperatorType::Power => VmOpCode::Power,
+            KaramelOperatorType::BitwiseAnd => VmOpCode::BitwiseAnd,
+            KaramelOperatorType::BitwiseOr => VmOpCode::BitwiseOr,
+            KaramelOperatorType::BitwiseXor => VmOpCode::BitwiseXor,
+            KaramelOperatorType::LeftShift => VmOpCode::LeftShift,
+            KaramelOperatorType::RightShift => VmOpCode::RightShift,
             _ => return Err(KaramelErrorType::OperatorNotValid),
         };
 
@@ -601,6 +781,12 @@ impl InterpreterCompiler {
             return self.generate_not(module.clone(), expression, context, storage_index);
         }
 
+        if *operator == KaramelOperatorType::BitwiseNot {
+            self.generate_opcode(module.clone(), expression, &KaramelAstType::None, context, storage_index)?;
+            context.opcode_generator.add_opcode(VmOpCode::BitwiseNot);
+            return Ok(());
+        }
+
         if let KaramelAstType::Symbol(variable) = expression {
             let location = match context
                 .storages
@@ -726,6 +912,224 @@ impl InterpreterCompiler {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn generate_match(&self, module: Rc<OpcodeModule>, value: &KaramelAstType, variable: &str, cases: &[Rc<KaramelMatchCase>], upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        /*
+        ╔════════════════════╗
+        ║   VALUE            ║
+        ╠════════════════════╣
+        ║   STORE VALUE      ║
+        ╠════════════════════╣
+        ║   PATTERN CHECKS   ║
+        ║   JUMP TO NEXT     ║
+        ║   PATTERN OR CASE  ║
+        ╠════════════════════╣
+        ║   BINDINGS         ║
+        ╠════════════════════╣
+        ║   GUARD            ║
+        ║   JUMP TO NEXT     ║
+        ║   CASE LOCATION    ║
+        ╠════════════════════╣
+        ║   CASE BODY        ║
+        ╠════════════════════╣
+        ║   JUMP TO OUT OF   ║
+        ║   MATCH            ║
+        ╚════════════════════╝
+        */
+        self.check_match_exhaustiveness(cases, context);
+
+        self.generate_opcode(module.clone(), value, upper_ast, context, storage_index)?;
+        let location = match context.storages[storage_index].get_variable_location(variable) {
+            Some(location) => location,
+            None => return Err(KaramelErrorType::ValueNotFoundInStorage),
+        };
+        context.opcode_generator.create_store(location);
+
+        let mut exit_locations: Vec<Rc<OpcodeLocation>> = Vec::new();
+
+        for case in cases {
+            let mut case_failed_locations: Vec<Rc<OpcodeLocation>> = Vec::new();
+            let mut body_locations: Vec<Rc<OpcodeLocation>> = Vec::new();
+
+            for (index, pattern) in case.patterns.iter().enumerate() {
+                let mut pattern_failed_locations = Vec::new();
+                let mut bindings = Vec::new();
+
+                self.generate_pattern_check(pattern, &[], location, &mut pattern_failed_locations, &mut bindings, context, storage_index)?;
+                self.generate_pattern_bindings(&bindings, location, context, storage_index)?;
+
+                if index == case.patterns.len() - 1 {
+                    case_failed_locations.append(&mut pattern_failed_locations);
+                } else {
+                    /* Pattern matched, jump to case body. Otherwise try next pattern */
+                    self.create_exit_jump(context, &mut body_locations);
+                    self.set_compare_locations(&pattern_failed_locations, context);
+                }
+            }
+
+            for body_location in body_locations {
+                context.opcode_generator.set_current_location(body_location);
+            }
+
+            if let Some(guard) = &case.guard {
+                self.generate_opcode(module.clone(), guard, upper_ast, context, storage_index)?;
+                case_failed_locations.push(self.create_compare(context));
+            }
+
+            self.generate_opcode(module.clone(), &case.body, upper_ast, context, storage_index)?;
+            self.create_exit_jump(context, &mut exit_locations);
+            self.set_compare_locations(&case_failed_locations, context);
+        }
+
+        for exit_location in exit_locations {
+            context.opcode_generator.set_current_location(exit_location);
+        }
+
+        Ok(())
+    }
+
+    fn check_match_exhaustiveness(&self, cases: &[Rc<KaramelMatchCase>], context: &mut KaramelCompilerContext) {
+        let mut exhaustive = false;
+        let mut has_true = false;
+        let mut has_false = false;
+
+        for (index, case) in cases.iter().enumerate() {
+            if exhaustive {
+                context
+                    .warnings
+                    .push(KaramelWarningType::UnreachableMatchCase(index + 1));
+                continue;
+            }
+
+            if case.guard.is_some() {
+                continue;
+            }
+
+            for pattern in case.patterns.iter() {
+                match &**pattern {
+                    KaramelMatchPattern::Wildcard | KaramelMatchPattern::Binding(_) => exhaustive = true,
+                    KaramelMatchPattern::Literal(primative) => match **primative {
+                        KaramelPrimative::Bool(true) => has_true = true,
+                        KaramelPrimative::Bool(false) => has_false = true,
+                        _ => (),
+                    },
+                    _ => (),
+                };
+            }
+
+            /* 'doğru' and 'yanlış' cover all bool values */
+            exhaustive |= has_true && has_false;
+        }
+
+        if !exhaustive {
+            context
+                .warnings
+                .push(KaramelWarningType::NonExhaustiveMatch);
+        }
+    }
+
+    fn set_compare_locations(&self, locations: &[Rc<OpcodeLocation>], context: &mut KaramelCompilerContext) {
+        if locations.is_empty() {
+            return;
+        }
+
+        let current_location = context.opcode_generator.build_current_location();
+        for location in locations {
+            context
+                .opcode_generator
+                .subtract_location(location.clone(), current_location.clone(), location.clone());
+        }
+    }
+
+    fn generate_pattern_load(&self, path: &[Rc<KaramelPrimative>], location: u8, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        context.opcode_generator.create_load(location);
+        for key in path {
+            self.generate_primative(key.clone(), &KaramelAstType::None, context, storage_index)?;
+            context.opcode_generator.add_opcode(VmOpCode::MatchItem);
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn generate_pattern_check(&self, pattern: &KaramelMatchPattern, path: &[Rc<KaramelPrimative>], location: u8, failed_locations: &mut Vec<Rc<OpcodeLocation>>, bindings: &mut Vec<PatternBinding>, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        match pattern {
+            KaramelMatchPattern::Wildcard => (),
+            KaramelMatchPattern::Binding(name) => bindings.push(PatternBinding {
+                name: name.to_string(),
+                path: path.to_vec(),
+                tail: None,
+            }),
+            KaramelMatchPattern::Literal(primative) => {
+                self.generate_pattern_load(path, location, context, storage_index)?;
+                self.generate_primative(primative.clone(), &KaramelAstType::None, context, storage_index)?;
+                context.opcode_generator.add_opcode(VmOpCode::Equal);
+                failed_locations.push(self.create_compare(context));
+            }
+            KaramelMatchPattern::List { items, rest } => {
+                if items.len() > u8::MAX as usize {
+                    return Err(KaramelErrorType::InvalidMatchPattern);
+                }
+
+                self.generate_pattern_load(path, location, context, storage_index)?;
+                context
+                    .opcode_generator
+                    .create_match_list(items.len() as u8, rest.is_some());
+                failed_locations.push(self.create_compare(context));
+
+                for (index, item) in items.iter().enumerate() {
+                    let mut item_path = path.to_vec();
+                    item_path.push(Rc::new(KaramelPrimative::Integer(index as i64)));
+                    self.generate_pattern_check(item, &item_path, location, failed_locations, bindings, context, storage_index)?;
+                }
+
+                if let Some(rest) = rest {
+                    if rest != "_" {
+                        bindings.push(PatternBinding {
+                            name: rest.to_string(),
+                            path: path.to_vec(),
+                            tail: Some(items.len() as u8),
+                        });
+                    }
+                }
+            }
+            KaramelMatchPattern::Dict(items) => {
+                self.generate_pattern_load(path, location, context, storage_index)?;
+                context.opcode_generator.add_opcode(VmOpCode::MatchDict);
+                failed_locations.push(self.create_compare(context));
+
+                for (key, item) in items.iter() {
+                    self.generate_primative(key.clone(), &KaramelAstType::None, context, storage_index)?;
+                    self.generate_pattern_load(path, location, context, storage_index)?;
+                    context.opcode_generator.add_opcode(VmOpCode::In);
+                    failed_locations.push(self.create_compare(context));
+
+                    let mut item_path = path.to_vec();
+                    item_path.push(key.clone());
+                    self.generate_pattern_check(item, &item_path, location, failed_locations, bindings, context, storage_index)?;
+                }
+            }
+        };
+        Ok(())
+    }
+
+    fn generate_pattern_bindings(&self, bindings: &[PatternBinding], location: u8, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        for binding in bindings {
+            self.check_prohibited_names(&binding.name, context)?;
+            self.generate_pattern_load(&binding.path, location, context, storage_index)?;
+
+            if let Some(start) = binding.tail {
+                context.opcode_generator.create_list_tail(start);
+            }
+
+            let variable_location = match context.storages[storage_index].get_variable_location(&binding.name) {
+                Some(variable_location) => variable_location,
+                None => return Err(KaramelErrorType::ValueNotFoundInStorage),
+            };
+            context.opcode_generator.create_store(variable_location);
+        }
+        Ok(())
+    }
+
     fn generate_indexer(&self, module: Rc<OpcodeModule>, body: &KaramelAstType, indexer: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         self.generate_opcode(module.clone(), body, upper_ast, context, storage_index)?;
         self.generate_opcode(module.clone(), indexer, upper_ast, context, storage_index)?;
@@ -777,9 +1181,10 @@ mod tests {
     use super::module::OpcodeModule;
     use crate::buildin::DummyModule;
     use crate::compiler::ast::KaramelAstType;
-    use crate::compiler::function::FunctionReference;
+    use crate::compiler::function::{FunctionArgument, FunctionReference};
     use crate::compiler::storage_builder::StorageBuilder;
     use crate::compiler::*;
+    use crate::types::KaramelOperatorType;
     use std::rc::Rc;
     use std::vec::Vec;
 
@@ -793,12 +1198,11 @@ mod tests {
 
         let function_define = FunctionReference::opcode_function("test".to_string(), Vec::new(), Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
-        let mut functions = Vec::new();
-        functions.push(function_define);
+        let functions = vec![function_define];
 
         let module = Rc::new(OpcodeModule::new("".to_string(), "".to_string(), Rc::new(KaramelAstType::None)));
         storage_builder.prepare(module.clone(), &KaramelAstType::None, 0, &mut context)?;
-        compiler.generate_functions(module.clone(), &mut functions, &mut context)?;
+        compiler.generate_functions(module.clone(), &functions, &mut context)?;
         Ok(())
     }
 
@@ -810,12 +1214,11 @@ mod tests {
 
         let function_define = FunctionReference::opcode_function("yazı".to_string(), Vec::new(), Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
-        let mut functions = Vec::new();
-        functions.push(function_define);
+        let functions = vec![function_define];
 
         let module = Rc::new(OpcodeModule::new("".to_string(), "".to_string(), Rc::new(KaramelAstType::None)));
         storage_builder.prepare(module.clone(), &KaramelAstType::None, 0, &mut context)?;
-        let result = compiler.generate_functions(module.clone(), &mut functions, &mut context);
+        let result = compiler.generate_functions(module.clone(), &functions, &mut context);
 
         match result {
             Ok(_) => Err(KaramelErrorType::GeneralError("Yazı geçerli bir fonksiyon ismi değil".to_string())),
@@ -831,12 +1234,11 @@ mod tests {
 
         let function_define = FunctionReference::opcode_function("döndür".to_string(), Vec::new(), Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
-        let mut functions = Vec::new();
-        functions.push(function_define);
+        let functions = vec![function_define];
 
         let module = Rc::new(OpcodeModule::new("".to_string(), "".to_string(), Rc::new(KaramelAstType::None)));
         storage_builder.prepare(module.clone(), &KaramelAstType::None, 0, &mut context)?;
-        let result = compiler.generate_functions(module.clone(), &mut functions, &mut context);
+        let result = compiler.generate_functions(module.clone(), &functions, &mut context);
 
         match result {
             Ok(_) => Err(KaramelErrorType::GeneralError("yazı geçerli bir fonksiyon ismi değil".to_string())),
@@ -852,12 +1254,11 @@ mod tests {
 
         let function_define = FunctionReference::opcode_function("sayı".to_string(), Vec::new(), Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
-        let mut functions = Vec::new();
-        functions.push(function_define);
+        let functions = vec![function_define];
 
         let module = Rc::new(OpcodeModule::new("".to_string(), "".to_string(), Rc::new(KaramelAstType::None)));
         storage_builder.prepare(module.clone(), &KaramelAstType::None, 0, &mut context)?;
-        let result = compiler.generate_functions(module.clone(), &mut functions, &mut context);
+        let result = compiler.generate_functions(module.clone(), &functions, &mut context);
 
         match result {
             Ok(_) => Err(KaramelErrorType::GeneralError("sayı geçerli bir fonksiyon ismi değil".to_string())),
@@ -871,14 +1272,13 @@ mod tests {
         let compiler = InterpreterCompiler {};
         let storage_builder: StorageBuilder = StorageBuilder::new();
 
-        let function_define = FunctionReference::opcode_function("test".to_string(), vec!["test".to_string()], Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
+        let function_define = FunctionReference::opcode_function("test".to_string(), vec![FunctionArgument::new("test")], Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
-        let mut functions = Vec::new();
-        functions.push(function_define);
+        let functions = vec![function_define];
 
         let module = Rc::new(OpcodeModule::new("".to_string(), "".to_string(), Rc::new(KaramelAstType::None)));
         storage_builder.prepare(module.clone(), &KaramelAstType::None, 0, &mut context)?;
-        compiler.generate_functions(module.clone(), &mut functions, &mut context)?;
+        compiler.generate_functions(module.clone(), &functions, &mut context)?;
         Ok(())
     }
 
@@ -888,14 +1288,13 @@ mod tests {
         let compiler = InterpreterCompiler {};
         let storage_builder: StorageBuilder = StorageBuilder::new();
 
-        let function_define = FunctionReference::opcode_function("test".to_string(), vec!["sayı".to_string()], Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
+        let function_define = FunctionReference::opcode_function("test".to_string(), vec![FunctionArgument::new("sayı")], Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
-        let mut functions = Vec::new();
-        functions.push(function_define);
+        let functions = vec![function_define];
 
         let module = Rc::new(OpcodeModule::new("".to_string(), "".to_string(), Rc::new(KaramelAstType::None)));
         storage_builder.prepare(module.clone(), &KaramelAstType::None, 0, &mut context)?;
-        let result = compiler.generate_functions(module.clone(), &mut functions, &mut context);
+        let result = compiler.generate_functions(module.clone(), &functions, &mut context);
 
         match result {
             Ok(_) => Err(KaramelErrorType::GeneralError("sayı geçerli bir fonksiyon parametresi değil".to_string())),
@@ -909,14 +1308,13 @@ mod tests {
         let compiler = InterpreterCompiler {};
         let storage_builder: StorageBuilder = StorageBuilder::new();
 
-        let function_define = FunctionReference::opcode_function("döndür".to_string(), vec!["sayı".to_string()], Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
+        let function_define = FunctionReference::opcode_function("döndür".to_string(), vec![FunctionArgument::new("sayı")], Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
-        let mut functions = Vec::new();
-        functions.push(function_define);
+        let functions = vec![function_define];
 
         let module = Rc::new(OpcodeModule::new("".to_string(), "".to_string(), Rc::new(KaramelAstType::None)));
         storage_builder.prepare(module.clone(), &KaramelAstType::None, 0, &mut context)?;
-        let result = compiler.generate_functions(module.clone(), &mut functions, &mut context);
+        let result = compiler.generate_functions(module.clone(), &functions, &mut context);
 
         match result {
             Ok(_) => Err(KaramelErrorType::GeneralError("döndür geçerli bir fonksiyon parametresi değil".to_string())),
@@ -927,22 +1325,22 @@ mod tests {
     #[test]
     fn test_8() -> Result<(), KaramelErrorType> {
         let compiler = InterpreterCompiler {};
-        KaramelCompilerContext::new();
-        compiler.check_prohibited_names("test".to_string())
+        let context = KaramelCompilerContext::new();
+        compiler.check_prohibited_names("test".to_string(), &context)
     }
 
     #[test]
     fn test_9() -> Result<(), KaramelErrorType> {
         let compiler = InterpreterCompiler {};
-        KaramelCompilerContext::new();
-        compiler.check_prohibited_names("abc".to_string())
+        let context = KaramelCompilerContext::new();
+        compiler.check_prohibited_names("abc".to_string(), &context)
     }
 
     #[test]
     fn test_10() -> Result<(), KaramelErrorType> {
         let compiler = InterpreterCompiler {};
-        KaramelCompilerContext::new();
-        match compiler.check_prohibited_names("sayı".to_string()) {
+        let context = KaramelCompilerContext::new();
+        match compiler.check_prohibited_names("sayı".to_string(), &context) {
             Ok(_) => Err(KaramelErrorType::GeneralError("sayı tipi kullanılamaz".to_string())),
             _ => Ok(()),
         }
@@ -951,10 +1349,24 @@ mod tests {
     #[test]
     fn test_11() -> Result<(), KaramelErrorType> {
         let compiler = InterpreterCompiler {};
-        KaramelCompilerContext::new();
-        match compiler.check_prohibited_names("döndür".to_string()) {
+        let context = KaramelCompilerContext::new();
+        match compiler.check_prohibited_names("döndür".to_string(), &context) {
             Ok(_) => Err(KaramelErrorType::GeneralError("sayı tipi kullanılamaz".to_string())),
             _ => Ok(()),
         }
     }
+
+    #[test]
+    fn test_12() -> Result<(), KaramelErrorType> {
+        let mut context = KaramelCompilerContext::new();
+        let compiler = InterpreterCompiler {};
+        let module = Rc::new(OpcodeModule::new("".to_string(), "".to_string(), Rc::new(KaramelAstType::None)));
+        let target = KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)));
+        let expression = KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)));
+
+        match compiler.generate_assignment(module, &target, &KaramelOperatorType::AssignAddition, &expression, &mut context, 0) {
+            Err(KaramelErrorType::InvalidAssignmentTarget) => Ok(()),
+            _ => Err(KaramelErrorType::GeneralError("atama hedefi kontrol edilmedi".to_string())),
+        }
+    }
 }
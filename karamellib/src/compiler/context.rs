@@ -1,16 +1,30 @@
+use crate::buildin::date::{Clock, DateModule, SystemClock};
+use crate::buildin::file::FileModule;
+use crate::buildin::json::JsonModule;
+use crate::buildin::math::MathModule;
 use crate::buildin::num::NumModule;
+use crate::buildin::pattern::PatternModule;
+use crate::buildin::random::{RandomGenerator, RandomModule};
+use crate::buildin::set::SetModule;
+use std::any::{Any, TypeId};
 use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
 use std::{cell::RefCell, ptr, rc::Rc};
 
+use crate::compiler::capabilities::Capabilities;
+use crate::error::KaramelWarningType;
+use crate::io::{ConsoleIo, KaramelIo};
+use crate::logger::default_log_level;
 use crate::types::VmObject;
 use crate::{
     buildin::{
         base_functions,
-        class::{dict, get_empty_class, list, number, proxy, text},
+        class::{date, dict, get_empty_class, list, number, pattern, proxy, set, text, tuple},
         debug, io, Class, Module, ModuleCollection,
     },
     compiler::scope::Scope,
 };
+use log::LevelFilter;
 
 use super::generator::OpcodeGenerator;
 use super::{
@@ -40,16 +54,33 @@ pub struct KaramelCompilerContext {
     pub scope_index: usize,
     pub functions: Vec<Rc<FunctionReference>>,
     pub classes: Vec<Rc<dyn Class>>,
-    pub stdout: Option<RefCell<String>>,
-    pub stderr: Option<RefCell<String>>,
+
+    /// Input and output streams of the script
+    pub io: Rc<dyn KaramelIo>,
+
+    /// Log messages above this level are not written to the 'io'
+    pub log_level: LevelFilter,
     pub memory_dump: Option<String>,
     pub opcode_dump: Option<String>,
+    pub warnings: Vec<KaramelWarningType>,
     pub opcodes_ptr: *mut u8,
     pub opcodes_top_ptr: *mut u8,
     pub primative_classes: Vec<Rc<dyn Class>>,
+
+    /// Names of the primative classes, they could not be used as a variable name
+    pub class_names: HashSet<String>,
     pub opcode_generator: OpcodeGenerator,
     pub stack: [VmObject; MAX_STACK],
     pub stack_ptr: *mut VmObject,
+    pub random: RefCell<RandomGenerator>,
+    pub clock: Rc<dyn Clock>,
+    pub capabilities: Capabilities,
+
+    /// Values of the host application, native functions reach them by their types
+    pub user_data: HashMap<TypeId, Box<dyn Any>>,
+
+    /// Native stack position of the outermost call from a native function back to the script, 0 if there is no call
+    pub call_stack_base: usize,
 }
 
 impl Default for KaramelCompilerContext {
@@ -72,37 +103,52 @@ impl KaramelCompilerContext {
             scope_index: 0,
             functions: Vec::new(),
             classes: Vec::new(),
-            stdout: None,
-            stderr: None,
+            io: Rc::new(ConsoleIo),
+            log_level: default_log_level(),
             opcodes_ptr: ptr::null_mut(),
             opcodes_top_ptr: ptr::null_mut(),
             primative_classes: Vec::new(),
+            class_names: HashSet::new(),
             main_module: ptr::null_mut(),
             opcode_generator: OpcodeGenerator::new(),
-            stack: [VmObject(0); MAX_STACK],
+            stack: [VmObject::from_bits(0); MAX_STACK],
             stack_ptr: ptr::null_mut(),
             memory_dump: None,
             opcode_dump: None,
+            warnings: Vec::new(),
+            random: RefCell::new(RandomGenerator::default()),
+            clock: Rc::new(SystemClock),
+            capabilities: Capabilities::default(),
+            user_data: HashMap::new(),
+            call_stack_base: 0,
         };
 
-        compiler
-            .primative_classes
-            .push(number::get_primative_class());
-        compiler.primative_classes.push(text::get_primative_class());
-        compiler.primative_classes.push(list::get_primative_class());
-        compiler.primative_classes.push(dict::get_primative_class());
-        compiler.primative_classes.push(get_empty_class());
+        compiler.add_primative_class(number::get_primative_class());
+        compiler.add_primative_class(text::get_primative_class());
+        compiler.add_primative_class(list::get_primative_class());
+        compiler.add_primative_class(dict::get_primative_class());
         compiler.primative_classes.push(get_empty_class());
         compiler.primative_classes.push(get_empty_class());
         compiler.primative_classes.push(get_empty_class());
         compiler
             .primative_classes
             .push(proxy::get_primative_class());
-        compiler.primative_classes.push(get_empty_class());
+        compiler.add_primative_class(tuple::get_primative_class());
+        compiler.add_primative_class(set::get_primative_class());
+        compiler.add_primative_class(date::get_primative_class());
+        compiler.add_primative_class(date::get_duration_class());
+        compiler.add_primative_class(pattern::get_primative_class());
 
         compiler.add_module(base_functions::BaseFunctionsModule::new());
         compiler.add_module(io::IoModule::new());
+        compiler.add_module(MathModule::new());
+        compiler.add_module(RandomModule::new());
+        compiler.add_module(DateModule::new());
+        compiler.add_module(FileModule::new());
+        compiler.add_module(JsonModule::new());
         compiler.add_module(NumModule::new());
+        compiler.add_module(SetModule::new());
+        compiler.add_module(PatternModule::new());
         compiler.add_module(debug::DebugModule::new());
 
         for _ in 0..32 {
@@ -131,10 +177,20 @@ impl KaramelCompilerContext {
         self.functions.push(information);
     }
 
-    pub fn add_class(&mut self, class_info: Rc<dyn Class + Sync + Send>) {
+    pub fn add_class(&mut self, class_info: Rc<dyn Class>) {
         self.classes.push(class_info.clone());
     }
 
+    /// Primative classes are kept in the order of the primative types.
+    fn add_primative_class(&mut self, class_info: Rc<dyn Class>) {
+        self.class_names.insert(class_info.get_class_name());
+        self.primative_classes.push(class_info);
+    }
+
+    pub fn is_class_name(&self, name: &str) -> bool {
+        self.class_names.contains(name)
+    }
+
     pub fn get_function<T: Borrow<String>>(&self, name: T, module_path: &Vec<String>, start_storage_index: usize) -> Option<Rc<FunctionReference>> {
         let mut search_storage = start_storage_index;
         loop {
@@ -142,7 +198,7 @@ impl KaramelCompilerContext {
             for (_, module) in self.modules.iter() {
                 for function_reference in module.get_methods().iter() {
                     let result = match &function_reference.callback {
-                        FunctionType::Native(_) => function_reference.module.get_path() == module_path && &function_reference.name == name.borrow(),
+                        FunctionType::Native(_) | FunctionType::Closure(_) => function_reference.module.get_path() == module_path && &function_reference.name == name.borrow(),
                         FunctionType::Opcode => {
                             &function_reference.name == name.borrow()
                                 && function_reference.module.get_path() == module_path
@@ -169,6 +225,24 @@ impl KaramelCompilerContext {
         }
     }
 
+    pub fn get_module_constant<T: Borrow<String>>(&self, name: T, module_path: &Vec<String>) -> Option<Rc<KaramelPrimative>> {
+        self.modules
+            .iter()
+            .find(|(_, module)| module.get_path() == module_path)
+            .and_then(|(_, module)| module.get_constant(name.borrow()))
+    }
+
+    /// Stores the value of the host application, previous value with the same type is replaced.
+    pub fn set_user_data<T: Any>(&mut self, value: T) {
+        self.user_data.insert(TypeId::of::<T>(), Box::new(value));
+    }
+
+    pub fn get_user_data<T: Any>(&self) -> Option<&T> {
+        self.user_data
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+
     pub fn get_class(&self, value: &KaramelPrimative) -> Rc<dyn Class> {
         unsafe {
             self.primative_classes
@@ -190,5 +264,6 @@ impl KaramelCompilerContext {
 
     pub fn reset(&mut self) {
         self.opcodes = Vec::new();
+        self.opcode_generator = OpcodeGenerator::new();
     }
 }
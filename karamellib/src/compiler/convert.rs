@@ -0,0 +1,488 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use crate::compiler::function::{FunctionParameter, NativeCallResult, NativeClosure};
+use crate::compiler::number::KaramelNumber;
+use crate::compiler::value::{KaramelDictKey, KaramelPrimative, EMPTY_OBJECT};
+use crate::error::KaramelErrorType;
+use crate::types::VmObject;
+
+/// Rust value that could be read from a Karamel value.
+pub trait FromKaramel: Sized {
+    /// Expected type name for the error messages.
+    fn type_name() -> String;
+
+    fn from_karamel(value: &VmObject) -> Option<Self>;
+}
+
+/// Rust value that could be converted to a Karamel value.
+pub trait IntoKaramel {
+    fn into_karamel(self) -> VmObject;
+}
+
+/// Reads the parameter of the function, wrong types reported as 'FunctionExpectedThatParameterType'.
+pub fn from_parameter<T: FromKaramel>(function: &str, value: &VmObject) -> Result<T, KaramelErrorType> {
+    T::from_karamel(value).ok_or_else(|| KaramelErrorType::FunctionExpectedThatParameterType {
+        function: function.to_string(),
+        expected: T::type_name(),
+    })
+}
+
+fn integer(value: &VmObject) -> Option<KaramelNumber> {
+    value.as_numeric()?.to_integer()
+}
+
+macro_rules! integer_conversion {
+    ($($type:ty),*) => {
+        $(
+            impl FromKaramel for $type {
+                fn type_name() -> String {
+                    "Tam sayı".to_string()
+                }
+
+                fn from_karamel(value: &VmObject) -> Option<Self> {
+                    match integer(value)? {
+                        KaramelNumber::Integer(number) => <$type>::try_from(number).ok(),
+                        KaramelNumber::BigInteger(number) => <$type>::try_from(number).ok(),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl IntoKaramel for $type {
+                fn into_karamel(self) -> VmObject {
+                    match i64::try_from(self) {
+                        Ok(number) => VmObject::from(number),
+                        Err(_) => VmObject::native_convert(KaramelPrimative::BigInteger(BigInt::from(self))),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer_conversion!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl FromKaramel for BigInt {
+    fn type_name() -> String {
+        "Tam sayı".to_string()
+    }
+
+    fn from_karamel(value: &VmObject) -> Option<Self> {
+        match integer(value)? {
+            KaramelNumber::Integer(number) => Some(BigInt::from(number)),
+            KaramelNumber::BigInteger(number) => Some(number),
+            _ => None,
+        }
+    }
+}
+
+impl IntoKaramel for BigInt {
+    fn into_karamel(self) -> VmObject {
+        match self.to_i64() {
+            Some(number) => VmObject::from(number),
+            None => VmObject::native_convert(KaramelPrimative::BigInteger(self)),
+        }
+    }
+}
+
+impl FromKaramel for f64 {
+    fn type_name() -> String {
+        "Sayı".to_string()
+    }
+
+    fn from_karamel(value: &VmObject) -> Option<Self> {
+        value.as_numeric().map(|number| number.as_f64())
+    }
+}
+
+impl IntoKaramel for f64 {
+    fn into_karamel(self) -> VmObject {
+        VmObject::from(self)
+    }
+}
+
+impl FromKaramel for f32 {
+    fn type_name() -> String {
+        "Sayı".to_string()
+    }
+
+    fn from_karamel(value: &VmObject) -> Option<Self> {
+        f64::from_karamel(value).map(|number| number as f32)
+    }
+}
+
+impl IntoKaramel for f32 {
+    fn into_karamel(self) -> VmObject {
+        VmObject::from(self as f64)
+    }
+}
+
+impl FromKaramel for bool {
+    fn type_name() -> String {
+        "Bool".to_string()
+    }
+
+    fn from_karamel(value: &VmObject) -> Option<Self> {
+        match &*value.deref() {
+            KaramelPrimative::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl IntoKaramel for bool {
+    fn into_karamel(self) -> VmObject {
+        VmObject::from(self)
+    }
+}
+
+impl FromKaramel for String {
+    fn type_name() -> String {
+        "Yazı".to_string()
+    }
+
+    fn from_karamel(value: &VmObject) -> Option<Self> {
+        match &*value.deref() {
+            KaramelPrimative::Text(text) => Some(text.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl IntoKaramel for String {
+    fn into_karamel(self) -> VmObject {
+        VmObject::from(self)
+    }
+}
+
+impl IntoKaramel for &str {
+    fn into_karamel(self) -> VmObject {
+        VmObject::from(self.to_string())
+    }
+}
+
+impl FromKaramel for Rc<String> {
+    fn type_name() -> String {
+        "Yazı".to_string()
+    }
+
+    fn from_karamel(value: &VmObject) -> Option<Self> {
+        match &*value.deref() {
+            KaramelPrimative::Text(text) => Some(text.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl IntoKaramel for Rc<String> {
+    fn into_karamel(self) -> VmObject {
+        VmObject::from(self)
+    }
+}
+
+/// Any value, without conversion.
+impl FromKaramel for VmObject {
+    fn type_name() -> String {
+        "Değer".to_string()
+    }
+
+    fn from_karamel(value: &VmObject) -> Option<Self> {
+        Some(*value)
+    }
+}
+
+impl IntoKaramel for VmObject {
+    fn into_karamel(self) -> VmObject {
+        self
+    }
+}
+
+impl IntoKaramel for KaramelPrimative {
+    fn into_karamel(self) -> VmObject {
+        VmObject::native_convert(self)
+    }
+}
+
+impl IntoKaramel for () {
+    fn into_karamel(self) -> VmObject {
+        EMPTY_OBJECT
+    }
+}
+
+/// Items of the list or tuple.
+impl<T: FromKaramel> FromKaramel for Vec<T> {
+    fn type_name() -> String {
+        format!("{} listesi", T::type_name())
+    }
+
+    fn from_karamel(value: &VmObject) -> Option<Self> {
+        match &*value.deref() {
+            KaramelPrimative::List(items) => items.borrow().iter().map(T::from_karamel).collect(),
+            KaramelPrimative::Tuple(items) => items.iter().map(T::from_karamel).collect(),
+            _ => None,
+        }
+    }
+}
+
+impl<T: IntoKaramel> IntoKaramel for Vec<T> {
+    fn into_karamel(self) -> VmObject {
+        VmObject::native_convert(KaramelPrimative::List(RefCell::new(self.into_iter().map(T::into_karamel).collect())))
+    }
+}
+
+impl<T: FromKaramel> FromKaramel for HashMap<String, T> {
+    fn type_name() -> String {
+        format!("{} sözlüğü", T::type_name())
+    }
+
+    fn from_karamel(value: &VmObject) -> Option<Self> {
+        match &*value.deref() {
+            KaramelPrimative::Dict(items) => items
+                .borrow()
+                .iter()
+                .map(|(key, value)| match key {
+                    KaramelDictKey::Text(key) => Some((key.to_string(), T::from_karamel(value)?)),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+impl<T: IntoKaramel> IntoKaramel for HashMap<String, T> {
+    fn into_karamel(self) -> VmObject {
+        let items = self
+            .into_iter()
+            .map(|(key, value)| (KaramelDictKey::from(key), value.into_karamel()))
+            .collect::<HashMap<_, _>>();
+        VmObject::from(items)
+    }
+}
+
+/// 'boş' is 'None', other values should be convertible to the inner type.
+impl<T: FromKaramel> FromKaramel for Option<T> {
+    fn type_name() -> String {
+        format!("{} ya da boş", T::type_name())
+    }
+
+    fn from_karamel(value: &VmObject) -> Option<Self> {
+        match &*value.deref() {
+            KaramelPrimative::Empty => Some(None),
+            _ => T::from_karamel(value).map(Some),
+        }
+    }
+}
+
+impl<T: IntoKaramel> IntoKaramel for Option<T> {
+    fn into_karamel(self) -> VmObject {
+        match self {
+            Some(value) => value.into_karamel(),
+            None => EMPTY_OBJECT,
+        }
+    }
+}
+
+macro_rules! tuple_conversion {
+    ($size:expr, $($name:ident),+) => {
+        /// Items of the tuple or list with the same size.
+        impl<$($name: FromKaramel),+> FromKaramel for ($($name,)+) {
+            fn type_name() -> String {
+                format!("({})", [$($name::type_name()),+].join(", "))
+            }
+
+            fn from_karamel(value: &VmObject) -> Option<Self> {
+                let items = match &*value.deref() {
+                    KaramelPrimative::List(items) => items.borrow().clone(),
+                    KaramelPrimative::Tuple(items) => items.clone(),
+                    _ => return None,
+                };
+
+                if items.len() != $size {
+                    return None;
+                }
+
+                let mut iter = items.iter();
+                Some(($($name::from_karamel(iter.next()?)?,)+))
+            }
+        }
+
+        impl<$($name: IntoKaramel),+> IntoKaramel for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn into_karamel(self) -> VmObject {
+                let ($($name,)+) = self;
+                VmObject::native_convert(KaramelPrimative::Tuple(vec![$($name.into_karamel()),+]))
+            }
+        }
+    };
+}
+
+tuple_conversion!(1, A);
+tuple_conversion!(2, A, B);
+tuple_conversion!(3, A, B, C);
+tuple_conversion!(4, A, B, C, D);
+tuple_conversion!(5, A, B, C, D, E);
+tuple_conversion!(6, A, B, C, D, E, F);
+
+/// Arguments of the Karamel function that called from Rust.
+pub trait IntoKaramelArguments {
+    fn into_arguments(self) -> Vec<VmObject>;
+}
+
+impl IntoKaramelArguments for () {
+    fn into_arguments(self) -> Vec<VmObject> {
+        Vec::new()
+    }
+}
+
+impl IntoKaramelArguments for Vec<VmObject> {
+    fn into_arguments(self) -> Vec<VmObject> {
+        self
+    }
+}
+
+macro_rules! arguments_conversion {
+    ($($name:ident),+) => {
+        impl<$($name: IntoKaramel),+> IntoKaramelArguments for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn into_arguments(self) -> Vec<VmObject> {
+                let ($($name,)+) = self;
+                vec![$($name.into_karamel()),+]
+            }
+        }
+    };
+}
+
+arguments_conversion!(A);
+arguments_conversion!(A, B);
+arguments_conversion!(A, B, C);
+arguments_conversion!(A, B, C, D);
+arguments_conversion!(A, B, C, D, E);
+arguments_conversion!(A, B, C, D, E, F);
+
+/// Return value of the typed native function, errors are passed to the script.
+pub trait IntoNativeResult {
+    fn into_native_result(self) -> NativeCallResult;
+}
+
+impl<T: IntoKaramel> IntoNativeResult for T {
+    fn into_native_result(self) -> NativeCallResult {
+        Ok(self.into_karamel())
+    }
+}
+
+impl<T: IntoKaramel> IntoNativeResult for Result<T, KaramelErrorType> {
+    fn into_native_result(self) -> NativeCallResult {
+        self.map(T::into_karamel)
+    }
+}
+
+/// Rust closure with typed parameters. 'Args' is the tuple of the parameter types.
+pub trait IntoNativeFunction<Args> {
+    /// Native function that checks the argument count and converts the parameters before calling the closure.
+    fn into_native_function(self, name: String) -> Rc<NativeClosure>;
+}
+
+macro_rules! native_function {
+    ($size:expr $(, $name:ident)*) => {
+        impl<Function, Return, $($name),*> IntoNativeFunction<($($name,)*)> for Function
+        where
+            Function: Fn($($name),*) -> Return + 'static,
+            Return: IntoNativeResult,
+            $($name: FromKaramel),*
+        {
+            #[allow(non_snake_case, unused_variables, unused_mut)]
+            fn into_native_function(self, name: String) -> Rc<NativeClosure> {
+                Rc::new(move |parameter: FunctionParameter| {
+                    if parameter.length() != $size {
+                        return Err(KaramelErrorType::FunctionArgumentNotMatching {
+                            function: name.to_string(),
+                            expected: $size,
+                            found: parameter.length(),
+                        });
+                    }
+
+                    let mut iter = parameter.iter();
+                    $(let $name = from_parameter::<$name>(&name, iter.next().unwrap())?;)*
+                    (self)($($name),*).into_native_result()
+                })
+            }
+        }
+    };
+}
+
+native_function!(0);
+native_function!(1, A);
+native_function!(2, A, B);
+native_function!(3, A, B, C);
+native_function!(4, A, B, C, D);
+native_function!(5, A, B, C, D, E);
+native_function!(6, A, B, C, D, E, F);
+
+/// Wraps the typed Rust closure into a native function.
+///
+/// ```
+/// use karamellib::compiler::convert::native_closure;
+///
+/// let add = native_closure("topla", |left: i64, right: i64| left + right);
+/// ```
+pub fn native_closure<Args, Function: IntoNativeFunction<Args>>(name: &str, function: Function) -> Rc<NativeClosure> {
+    function.into_native_function(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T: IntoKaramel + FromKaramel>(value: T) -> Option<T> {
+        T::from_karamel(&value.into_karamel())
+    }
+
+    #[test]
+    fn test_primatives() {
+        assert_eq!(round_trip(42_i64), Some(42));
+        assert_eq!(round_trip(u64::MAX), Some(u64::MAX));
+        assert_eq!(round_trip(2.5_f64), Some(2.5));
+        assert_eq!(round_trip(true), Some(true));
+        assert_eq!(round_trip("karamel".to_string()), Some("karamel".to_string()));
+        assert_eq!(u8::from_karamel(&VmObject::from(300_i64)), None);
+        assert_eq!(i64::from_karamel(&VmObject::from(2.0)), Some(2));
+        assert_eq!(i64::from_karamel(&VmObject::from(2.5)), None);
+    }
+
+    #[test]
+    fn test_collections() {
+        assert_eq!(round_trip(vec![1_i64, 2, 3]), Some(vec![1, 2, 3]));
+        assert_eq!(round_trip(Some(vec![Some(true), None])), Some(Some(vec![Some(true), None])));
+        assert_eq!(round_trip((1_i64, "a".to_string(), 2.5_f64)), Some((1, "a".to_string(), 2.5)));
+        assert_eq!(<(i64, i64)>::from_karamel(&vec![1_i64].into_karamel()), None);
+
+        let mut scores = HashMap::new();
+        scores.insert("ali".to_string(), 10_i64);
+        assert_eq!(round_trip(scores.clone()), Some(scores));
+    }
+
+    #[test]
+    fn test_parameter_error() {
+        match from_parameter::<Vec<i64>>("topla", &VmObject::from(1_i64)) {
+            Err(KaramelErrorType::FunctionExpectedThatParameterType { function, expected }) => assert_eq!((function.as_str(), expected.as_str()), ("topla", "Tam sayı listesi")),
+            _ => panic!("Parametre hatası bekleniyordu"),
+        }
+    }
+
+    #[test]
+    fn test_native_closure() {
+        let stack = vec![VmObject::from(2_i64), VmObject::from(3_i64)];
+        let add = native_closure("topla", |left: i64, right: i64| left + right);
+
+        let result = add(FunctionParameter::new(&stack, None, 2, 2)).unwrap();
+        assert_eq!(i64::from_karamel(&result), Some(5));
+
+        assert!(add(FunctionParameter::new(&stack, None, 2, 1)).is_err());
+    }
+}
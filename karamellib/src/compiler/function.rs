@@ -1,17 +1,24 @@
 use bitflags::bitflags;
+use std::any::Any;
 use std::borrow::Borrow;
 use std::cell::Cell;
 use std::cell::RefCell;
+use std::cmp::min;
+use std::collections::HashMap;
+use std::fmt;
 use std::iter::Take;
+use std::ptr;
 use std::slice::Iter;
 use std::{iter::Skip, rc::Rc, vec::Vec};
 
 use crate::buildin::{DummyModule, Module};
 use crate::compiler::context::KaramelCompilerContext;
+use crate::compiler::generator::call::CallFlag;
 use crate::compiler::scope::Scope;
 use crate::error::KaramelErrorType;
+use crate::io::{ConsoleIo, KaramelIo};
 use crate::types::*;
-use crate::{dec_memory_index, get_memory_index, inc_memory_index};
+use crate::vm::interpreter::call_function;
 
 use super::ast::KaramelAstType;
 use super::module::OpcodeModule;
@@ -20,18 +27,29 @@ use super::{KaramelPrimative, StaticStorage};
 
 pub type NativeCallResult = Result<VmObject, KaramelErrorType>;
 pub type NativeCall = fn(FunctionParameter) -> NativeCallResult;
+pub type NativeClosure = dyn Fn(FunctionParameter) -> NativeCallResult;
 pub type IndexerGetCall = fn(VmObject, f64) -> NativeCallResult;
 pub type IndexerSetCall = fn(VmObject, f64, VmObject) -> NativeCallResult;
 
-#[derive(Debug)]
 pub struct FunctionParameter<'a> {
     stack: &'a [VmObject],
     source: Option<VmObject>,
     last_position: usize,
     arg_size: u8,
-    stdout: &'a Option<RefCell<String>>,
-    #[allow(dead_code)]
-    stderr: &'a Option<RefCell<String>>,
+
+    /// Context of the running script, native functions keep their per script state at the context
+    context: *mut KaramelCompilerContext,
+}
+
+impl fmt::Debug for FunctionParameter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FunctionParameter")
+            .field("stack", &self.stack)
+            .field("source", &self.source)
+            .field("last_position", &self.last_position)
+            .field("arg_size", &self.arg_size)
+            .finish()
+    }
 }
 
 pub struct FunctionParameterIterator<'a> {
@@ -39,8 +57,40 @@ pub struct FunctionParameterIterator<'a> {
 }
 
 impl<'a> FunctionParameter<'a> {
-    pub fn new(stack: &'a [VmObject], source: Option<VmObject>, last_position: usize, arg_size: u8, stdout: &'a Option<RefCell<String>>, stderr: &'a Option<RefCell<String>>) -> Self {
-        FunctionParameter { stack, source, last_position, arg_size, stdout, stderr }
+    pub fn new(stack: &'a [VmObject], source: Option<VmObject>, last_position: usize, arg_size: u8) -> Self {
+        /* Slots after the last argument are used by the functions that called back from the native function */
+        FunctionParameter {
+            stack: &stack[..min(last_position, stack.len())],
+            source,
+            last_position,
+            arg_size,
+            context: ptr::null_mut(),
+        }
+    }
+
+    /// # Safety
+    /// Context must outlive the parameter and must not be accessed by anything else while the native function runs.
+    pub(crate) unsafe fn with_context(mut self, context: *mut KaramelCompilerContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    pub fn context(&self) -> Option<&KaramelCompilerContext> {
+        unsafe { self.context.as_ref() }
+    }
+
+    /// Value of the host application that stored with 'set_user_data'.
+    pub fn user_data<T: Any>(&self) -> Option<&T> {
+        self.context()?.get_user_data::<T>()
+    }
+
+    /// Calls the function value, like the callbacks that passed to the native function. Result of the function is returned.
+    /// Script could change the context, so the references that taken from the parameter should be released before the call.
+    pub fn call(&mut self, function: VmObject, arguments: &[VmObject]) -> NativeCallResult {
+        match self.context.is_null() {
+            true => Err(KaramelErrorType::ContextNotAvailable),
+            false => unsafe { call_function(&mut *self.context, function, arguments) },
+        }
     }
 
     pub fn source(&self) -> Option<VmObject> {
@@ -51,22 +101,28 @@ impl<'a> FunctionParameter<'a> {
         self.arg_size
     }
 
+    /// Streams of the script, standard streams of the process are used without context.
+    pub fn io(&self) -> &dyn KaramelIo {
+        match self.context() {
+            Some(context) => &*context.io,
+            None => &ConsoleIo,
+        }
+    }
+
     pub fn write_to_stdout(&self, data: &str) {
-        match self.stdout {
-            Some(out) => match out.try_borrow_mut() {
-                Ok(mut out_mut) => out_mut.push_str(data),
-                _ => println!("{}", data),
-            },
-            _ => println!("{}", data),
-        };
+        self.io().write_stdout(data);
     }
 
-    pub fn iter(&self) -> FunctionParameterIterator {
+    pub fn write_to_stderr(&self, data: &str) {
+        self.io().write_stderr(data);
+    }
+
+    pub fn iter(&self) -> FunctionParameterIterator<'_> {
         FunctionParameterIterator {
             iter: self
                 .stack
                 .iter()
-                .skip((self.last_position - 1) - (self.arg_size as usize - 1))
+                .skip(self.last_position - self.arg_size as usize)
                 .take(self.arg_size as usize)
                 .clone(),
         }
@@ -91,12 +147,42 @@ bitflags! {
     }
 }
 
+/// Function parameter signature. Used by both of opcode and native functions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FunctionArgument {
+    pub name: String,
+
+    /// Value that will be used if the caller does not pass that argument
+    pub default: Option<Rc<KaramelPrimative>>,
+
+    /// Collects all remaining positional arguments into a list ('*geri_kalan')
+    pub variadic: bool,
+}
+
+impl FunctionArgument {
+    pub fn new(name: &str) -> Self {
+        FunctionArgument { name: name.to_string(), default: None, variadic: false }
+    }
+
+    pub fn with_default(name: &str, default: Rc<KaramelPrimative>) -> Self {
+        FunctionArgument {
+            name: name.to_string(),
+            default: Some(default),
+            variadic: false,
+        }
+    }
+
+    pub fn variadic(name: &str) -> Self {
+        FunctionArgument { name: name.to_string(), default: None, variadic: true }
+    }
+}
+
 #[derive(Clone)]
 pub struct FunctionReference {
     pub callback: FunctionType,
     pub flags: FunctionFlag,
     pub name: String,
-    pub arguments: Vec<String>,
+    pub arguments: Vec<FunctionArgument>,
     pub defined_storage_index: usize,
     pub storage_index: usize,
     pub opcode_location: Cell<usize>,
@@ -105,12 +191,11 @@ pub struct FunctionReference {
     pub module: Rc<dyn Module>,
 }
 
-unsafe impl Send for FunctionReference {}
-unsafe impl Sync for FunctionReference {}
-
 #[derive(Clone, Default)]
 pub enum FunctionType {
     Native(NativeCall),
+    /// Native function that keeps its own state, like the typed Rust closures
+    Closure(Rc<NativeClosure>),
     #[default]
     Opcode,
 }
@@ -118,8 +203,9 @@ pub enum FunctionType {
 impl FunctionReference {
     pub fn execute(&self, compiler: &mut KaramelCompilerContext, base: Option<VmObject>) -> Result<(), KaramelErrorType> {
         unsafe {
-            match self.callback {
+            match &self.callback {
                 FunctionType::Native(func) => FunctionReference::native_function_call(self, func, compiler, base),
+                FunctionType::Closure(func) => FunctionReference::native_function_call(self, &**func, compiler, base),
                 FunctionType::Opcode => FunctionReference::opcode_function_call(self, compiler),
             }
         }
@@ -157,7 +243,41 @@ impl FunctionReference {
         Rc::new(reference)
     }
 
-    pub fn opcode_function(name: String, arguments: Vec<String>, body: Rc<KaramelAstType>, module: Rc<dyn Module>, storage_index: usize, defined_storage_index: usize, module_level: bool) -> Rc<FunctionReference> {
+    /// Native function with parameter signature. Arguments are checked and placed with the same rules of the opcode functions.
+    pub fn native_function_with_arguments(func: NativeCall, name: String, arguments: Vec<FunctionArgument>, module: Rc<dyn Module>) -> Rc<FunctionReference> {
+        let reference = FunctionReference {
+            callback: FunctionType::Native(func),
+            flags: FunctionFlag::STATIC,
+            name,
+            arguments,
+            storage_index: 0,
+            opcode_location: Cell::new(0),
+            used_locations: RefCell::new(Vec::new()),
+            defined_storage_index: 0,
+            opcode_body: None,
+            module,
+        };
+        Rc::new(reference)
+    }
+
+    /// Native function that keeps its own state. Arguments are passed as is, the closure should check them.
+    pub fn native_closure(func: Rc<NativeClosure>, name: String, module: Rc<dyn Module>) -> Rc<FunctionReference> {
+        let reference = FunctionReference {
+            callback: FunctionType::Closure(func),
+            flags: FunctionFlag::STATIC,
+            name,
+            arguments: Vec::new(),
+            storage_index: 0,
+            opcode_location: Cell::new(0),
+            used_locations: RefCell::new(Vec::new()),
+            defined_storage_index: 0,
+            opcode_body: None,
+            module,
+        };
+        Rc::new(reference)
+    }
+
+    pub fn opcode_function(name: String, arguments: Vec<FunctionArgument>, body: Rc<KaramelAstType>, module: Rc<dyn Module>, storage_index: usize, defined_storage_index: usize, module_level: bool) -> Rc<FunctionReference> {
         let mut reference = FunctionReference {
             callback: FunctionType::Opcode,
             flags: FunctionFlag::STATIC,
@@ -178,10 +298,28 @@ impl FunctionReference {
         Rc::new(reference)
     }
 
-    unsafe fn native_function_call(_: &FunctionReference, func: NativeCall, compiler: &mut KaramelCompilerContext, source: Option<VmObject>) -> Result<(), KaramelErrorType> {
-        let total_args = *compiler.opcodes_ptr.offset(1);
-        let call_return_assign_to_temp = *compiler.opcodes_ptr.offset(2) != 0;
-        let parameter = FunctionParameter::new(&compiler.stack, source, get_memory_index!(compiler) as usize, karamel_dbg!(total_args), &compiler.stdout, &compiler.stderr);
+    unsafe fn native_function_call(reference: &FunctionReference, func: &NativeClosure, compiler: &mut KaramelCompilerContext, source: Option<VmObject>) -> Result<(), KaramelErrorType> {
+        let argument_size = *compiler.opcodes_ptr.offset(1);
+        let call_flags = CallFlag::from_bits_truncate(*compiler.opcodes_ptr.offset(2));
+
+        /* Functions could be reached as a value, so the permission is checked at the call too */
+        if let Err(error) = compiler.capabilities.check_function(reference) {
+            dec_memory_index!(compiler, argument_size as usize);
+            return Err(error);
+        }
+
+        /* Native functions without signature receive arguments as is */
+        let total_args = match reference.arguments.is_empty() {
+            true if call_flags.contains(CallFlag::NAMED_ARGUMENTS) => {
+                dec_memory_index!(compiler, argument_size as usize);
+                return Err(KaramelErrorType::NamedArgumentsNotSupported(reference.name.to_string()));
+            }
+            true => argument_size,
+            false => reference.prepare_arguments(compiler, argument_size, call_flags)?,
+        };
+
+        let context = compiler as *mut KaramelCompilerContext;
+        let parameter = FunctionParameter::new(&compiler.stack, source, get_memory_index!(compiler) as usize, karamel_dbg!(total_args)).with_context(context);
 
         dump_data!(compiler, "native_function_call");
 
@@ -189,7 +327,7 @@ impl FunctionReference {
             Ok(result) => {
                 dec_memory_index!(compiler, total_args as usize);
 
-                if call_return_assign_to_temp {
+                if call_flags.contains(CallFlag::ASSIGN_TO_TEMP) {
                     *compiler.stack_ptr = result;
                     inc_memory_index!(compiler, 1);
                 }
@@ -205,23 +343,108 @@ impl FunctionReference {
         }
     }
 
+    /// Places the call arguments according to function signature. Named arguments are passed as a dictionary at the last stack position,
+    /// missing arguments are filled with their default values and extra positional arguments are collected into the variadic argument.
+    /// Returns how many arguments are placed at the stack.
+    unsafe fn prepare_arguments(&self, context: &mut KaramelCompilerContext, argument_size: u8, call_flags: CallFlag) -> Result<u8, KaramelErrorType> {
+        let has_named = call_flags.contains(CallFlag::NAMED_ARGUMENTS);
+        let has_variadic = self
+            .arguments
+            .last()
+            .is_some_and(|argument| argument.variadic);
+
+        /* Fast path, nothing to arrange */
+        if !has_named && !has_variadic && argument_size as usize == self.arguments.len() {
+            return Ok(argument_size);
+        }
+
+        let named = match has_named {
+            true => match &*pop_raw!(context, "named arguments").deref() {
+                KaramelPrimative::Dict(dict) => dict.borrow().clone(),
+                _ => HashMap::new(),
+            },
+            false => HashMap::new(),
+        };
+
+        let positional_size = argument_size as usize - has_named as usize;
+        let fixed_size = self.arguments.len() - has_variadic as usize;
+        let base = context.stack_ptr.sub(positional_size);
+
+        if positional_size > fixed_size && !has_variadic {
+            return Err(KaramelErrorType::FunctionArgumentNotMatching {
+                function: self.name.to_string(),
+                expected: fixed_size as u8,
+                found: positional_size as u8,
+            });
+        }
+
+        let mut values: Vec<Option<VmObject>> = vec![None; self.arguments.len()];
+        for (index, value) in values
+            .iter_mut()
+            .enumerate()
+            .take(min(positional_size, fixed_size))
+        {
+            *value = Some(*base.add(index));
+        }
+
+        if has_variadic {
+            let rest = (fixed_size..positional_size)
+                .map(|index| *base.add(index))
+                .collect::<Vec<VmObject>>();
+            values[fixed_size] = Some(VmObject::from(rest));
+        }
+
+        context.stack_ptr = base;
+        for (key, value) in named.iter() {
+            let name = key.to_primative().get_text();
+            match self.arguments[..fixed_size]
+                .iter()
+                .position(|argument| argument.name == name)
+            {
+                Some(index) if values[index].is_some() => {
+                    return Err(KaramelErrorType::FunctionArgumentAlreadyAssigned {
+                        function: self.name.to_string(),
+                        argument: name.to_string(),
+                    })
+                }
+                Some(index) => values[index] = Some(*value),
+                None => {
+                    return Err(KaramelErrorType::FunctionArgumentNotFound {
+                        function: self.name.to_string(),
+                        argument: name.to_string(),
+                    })
+                }
+            };
+        }
+
+        for (index, argument) in self.arguments.iter().enumerate() {
+            *base.add(index) = match (values[index], &argument.default) {
+                (Some(value), _) => value,
+                (None, Some(default)) => VmObject::convert(default.clone()),
+                (None, None) => {
+                    return Err(KaramelErrorType::FunctionArgumentMissing {
+                        function: self.name.to_string(),
+                        argument: argument.name.to_string(),
+                    })
+                }
+            };
+        }
+
+        context.stack_ptr = base.add(self.arguments.len());
+        Ok(self.arguments.len() as u8)
+    }
+
     fn opcode_function_call(reference: &FunctionReference, options: &mut KaramelCompilerContext) -> Result<(), KaramelErrorType> {
         unsafe {
             let argument_size = *options.opcodes_ptr.offset(1);
-            let call_return_assign_to_temp = *options.opcodes_ptr.offset(2) != 0;
+            let call_flags = CallFlag::from_bits_truncate(*options.opcodes_ptr.offset(2));
+            let argument_size = reference.prepare_arguments(options, argument_size, call_flags)?;
+
             let old_index = options.opcodes_ptr.offset(2);
             let location = reference.opcode_location.get() as isize;
             options.opcodes_ptr = options.opcodes_top_ptr.offset(location);
             options.scope_index += 1;
 
-            if argument_size != *options.opcodes_ptr {
-                return Err(KaramelErrorType::FunctionArgumentNotMatching {
-                    function: reference.name.to_string(),
-                    expected: argument_size,
-                    found: *options.opcodes_ptr,
-                });
-            }
-
             dec_memory_index!(options, argument_size.into());
             dump_data!(options, "Current");
 
@@ -239,10 +462,12 @@ impl FunctionReference {
             (*scope).top_stack = options.stack_ptr;
 
             (*scope).location = old_index;
-            (*scope).call_return_assign_to_temp = call_return_assign_to_temp;
+            (*scope).call_return_assign_to_temp = call_flags.contains(CallFlag::ASSIGN_TO_TEMP);
 
             options.current_scope = scope;
-            inc_memory_index!(options, argument_size.into());
+
+            /* Reserve stack for all function variables, not only for arguments */
+            inc_memory_index!(options, (*storage).variables.len());
         }
         Ok(())
     }
@@ -266,16 +491,17 @@ pub fn find_function_definition_type(module: Rc<OpcodeModule>, ast: Rc<KaramelAs
                 return Err(KaramelErrorType::FunctionAlreadyDefined(name.to_string()));
             }
 
+            /* Arguments are placed at the beginning of the function stack, so they should be the first variables */
+            for argument in arguments {
+                options.storages[new_storage_index].add_variable(&argument.name);
+            }
+
             find_function_definition_type(module.clone(), body.clone(), options, new_storage_index, false)?;
 
             let storage_builder = StorageBuilder::new();
             storage_builder.prepare(module.clone(), ast.borrow(), new_storage_index, options)?;
 
             options.storages[current_storage_index].add_constant(Rc::new(KaramelPrimative::Function(function.clone(), None)));
-
-            for argument in arguments {
-                options.storages[new_storage_index].add_variable(argument);
-            }
         }
         KaramelAstType::Block(blocks) => {
             for block in blocks {
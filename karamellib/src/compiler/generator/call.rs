@@ -3,10 +3,22 @@ use std::{
     sync::atomic::{AtomicUsize, Ordering},
 };
 
+use bitflags::bitflags;
+
 use crate::compiler::VmOpCode;
 
 use super::{DumpBuilder, OpcodeGeneratorTrait};
 
+bitflags! {
+    /// Last operand of the call opcodes.
+    #[derive(Default)]
+    pub struct CallFlag: u8 {
+        const NONE            = 0b00000000;
+        const ASSIGN_TO_TEMP  = 0b00000001;
+        const NAMED_ARGUMENTS = 0b00000010;
+    }
+}
+
 #[derive(Clone)]
 
 /// Function call type. Karamel is support two type of function call mechanism
@@ -29,6 +41,18 @@ pub struct CallGenerator {
 
     /// Function return value needs to be assigned to stack location or discarded
     pub assign_to_temp: bool,
+
+    /// Named arguments are passed as a dictionary at the last argument position
+    pub named_arguments: bool,
+}
+
+impl CallGenerator {
+    fn flags(&self) -> CallFlag {
+        let mut flags = CallFlag::NONE;
+        flags.set(CallFlag::ASSIGN_TO_TEMP, self.assign_to_temp);
+        flags.set(CallFlag::NAMED_ARGUMENTS, self.named_arguments);
+        flags
+    }
 }
 
 /// Generate function call opcodes based on givin parameters
@@ -42,19 +66,19 @@ impl OpcodeGeneratorTrait for CallGenerator {
             CallType::CallStack => opcodes.push(VmOpCode::CallStack.into()),
         };
         opcodes.push(self.argument_size);
-        opcodes.push(self.assign_to_temp.into());
+        opcodes.push(self.flags().bits());
     }
 
-    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, _: &Vec<u8>) {
+    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, _: &[u8]) {
         let opcode_index = index.fetch_add(3, Ordering::SeqCst);
 
         match self.call_type {
             CallType::Call { constant_location } => {
                 index.fetch_add(1, Ordering::SeqCst);
-                builder.add(opcode_index, VmOpCode::Call, constant_location.to_string(), self.argument_size.to_string(), (self.assign_to_temp as u8).to_string());
+                builder.add(opcode_index, VmOpCode::Call, constant_location.to_string(), self.argument_size.to_string(), self.flags().bits().to_string());
             }
             CallType::CallStack => {
-                builder.add(opcode_index, VmOpCode::CallStack, self.argument_size.to_string(), (self.assign_to_temp as u8).to_string(), "".to_string());
+                builder.add(opcode_index, VmOpCode::CallStack, self.argument_size.to_string(), self.flags().bits().to_string(), "".to_string());
             }
         };
     }
@@ -71,6 +95,7 @@ mod tests {
             call_type: CallType::Call { constant_location: 100 },
             argument_size: 1,
             assign_to_temp: false,
+            named_arguments: false,
         };
 
         generator.generate(&mut opcodes);
@@ -89,6 +114,7 @@ mod tests {
             call_type: CallType::CallStack,
             argument_size: 5,
             assign_to_temp: true,
+            named_arguments: false,
         };
 
         generator.generate(&mut opcodes);
@@ -106,6 +132,7 @@ mod tests {
             call_type: CallType::Call { constant_location: 100 },
             argument_size: 5,
             assign_to_temp: true,
+            named_arguments: false,
         };
 
         generator.generate(&mut opcodes);
@@ -116,4 +143,22 @@ mod tests {
         assert_eq!(opcodes[2], 5);
         assert_eq!(opcodes[3], 1);
     }
+
+    #[test]
+    fn test_4() {
+        let mut opcodes = Vec::new();
+        let generator = CallGenerator {
+            call_type: CallType::CallStack,
+            argument_size: 3,
+            assign_to_temp: true,
+            named_arguments: true,
+        };
+
+        generator.generate(&mut opcodes);
+
+        assert_eq!(opcodes.len(), 3);
+        assert_eq!(opcodes[0], VmOpCode::CallStack.into());
+        assert_eq!(opcodes[1], 3);
+        assert_eq!(opcodes[2], (CallFlag::ASSIGN_TO_TEMP | CallFlag::NAMED_ARGUMENTS).bits());
+    }
 }
@@ -17,7 +17,7 @@ impl OpcodeGeneratorTrait for ConstantGenerator {
         opcodes.push(self.location);
     }
 
-    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, _: &Vec<u8>) {
+    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, _: &[u8]) {
         let opcode_index = index.fetch_add(2, Ordering::SeqCst);
         builder.add(opcode_index, VmOpCode::Constant, self.location.to_string(), "".to_string(), "".to_string());
     }
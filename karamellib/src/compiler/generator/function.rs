@@ -19,7 +19,7 @@ impl OpcodeGeneratorTrait for FunctionGenerator {
         opcodes.push(self.function.arguments.len() as u8);
     }
 
-    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, opcodes: &Vec<u8>) {
+    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, opcodes: &[u8]) {
         let opcode_index = index.fetch_add(1, Ordering::SeqCst);
         builder.add_with_text(opcode_index, format!("[FUNCTION: {}]", self.function.name), opcodes[opcode_index].to_string(), "".to_string(), "".to_string());
     }
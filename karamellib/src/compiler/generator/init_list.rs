@@ -19,7 +19,7 @@ impl OpcodeGeneratorTrait for InitListGenerator {
         opcodes.push(self.argument_size as u8);
     }
 
-    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, _: &Vec<u8>) {
+    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, _: &[u8]) {
         let opcode_index = index.fetch_add(3, Ordering::SeqCst);
         builder.add(opcode_index, VmOpCode::Init, "1".to_string(), self.argument_size.to_string(), "".to_string());
     }
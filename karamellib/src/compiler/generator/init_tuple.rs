@@ -0,0 +1,26 @@
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::compiler::VmOpCode;
+
+use super::{DumpBuilder, OpcodeGeneratorTrait};
+
+#[derive(Debug, Clone)]
+pub struct InitTupleGenerator {
+    pub argument_size: usize,
+}
+
+impl OpcodeGeneratorTrait for InitTupleGenerator {
+    fn generate(&self, opcodes: &mut Vec<u8>) {
+        opcodes.push(VmOpCode::Init.into());
+        opcodes.push(2);
+        opcodes.push(self.argument_size as u8);
+    }
+
+    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, _: &[u8]) {
+        let opcode_index = index.fetch_add(3, Ordering::SeqCst);
+        builder.add(opcode_index, VmOpCode::Init, "2".to_string(), self.argument_size.to_string(), "".to_string());
+    }
+}
@@ -54,7 +54,7 @@ impl OpcodeLocation {
         }
     }
 
-    pub fn set(&self, location: usize, opcodes: &mut Vec<u8>) {
+    pub fn set(&self, location: usize, opcodes: &mut [u8]) {
         *self.location.borrow_mut() = LocationType::Fixed(location);
 
         for used_location in self.used_location.borrow().iter() {
@@ -87,7 +87,7 @@ impl OpcodeGeneratorTrait for CurrentLocationUpdateGenerator {
         self.location.set(opcodes.len(), opcodes);
     }
 
-    fn dump(&self, _: &DumpBuilder, _: Rc<AtomicUsize>, _: &Vec<u8>) {
+    fn dump(&self, _: &DumpBuilder, _: Rc<AtomicUsize>, _: &[u8]) {
         // Dynamically location calculator
     }
 }
@@ -103,7 +103,7 @@ impl OpcodeGeneratorTrait for DynamicLocationUpdateGenerator {
         self.target.set(self.source.get(), opcodes);
     }
 
-    fn dump(&self, _: &DumpBuilder, _: Rc<AtomicUsize>, _: &Vec<u8>) {
+    fn dump(&self, _: &DumpBuilder, _: Rc<AtomicUsize>, _: &[u8]) {
         // Dynamically location calculator
     }
 }
@@ -123,7 +123,7 @@ impl OpcodeGeneratorTrait for SubtractionGenerator {
         self.target.set(left - right, opcodes);
     }
 
-    fn dump(&self, _: &DumpBuilder, _: Rc<AtomicUsize>, _: &Vec<u8>) {
+    fn dump(&self, _: &DumpBuilder, _: Rc<AtomicUsize>, _: &[u8]) {
         // Dynamically location calculator
     }
 }
@@ -19,11 +19,13 @@ use self::{
     function::FunctionGenerator,
     init_dict::InitDictGenerator,
     init_list::InitListGenerator,
+    init_tuple::InitTupleGenerator,
     jump::JumpGenerator,
     load::LoadGenerator,
     location::{CurrentLocationUpdateGenerator, OpcodeLocation, SubtractionGenerator},
     location_group::OpcodeLocationGroup,
     opcode_item::OpcodeItem,
+    pattern::{PatternGenerator, PatternType},
     store::{StoreGenerator, StoreType},
 };
 
@@ -35,16 +37,18 @@ pub mod constant;
 pub mod function;
 pub mod init_dict;
 pub mod init_list;
+pub mod init_tuple;
 pub mod jump;
 pub mod load;
 pub mod location;
 pub mod location_group;
 pub mod opcode_item;
+pub mod pattern;
 pub mod store;
 
 pub trait OpcodeGeneratorTrait {
     fn generate(&self, opcodes: &mut Vec<u8>);
-    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, opcodes: &Vec<u8>);
+    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, opcodes: &[u8]);
 }
 
 pub fn dump_single_opcode<T: Borrow<String>>(builder: &DumpBuilder, index: usize, opcode: T, buffer: &mut String) {
@@ -178,6 +182,9 @@ impl DumpBuilder {
 pub struct OpcodeGenerator {
     generators: RefCell<Vec<Rc<dyn OpcodeGeneratorTrait>>>,
     loop_groups: RefCell<VecDeque<LoopItem>>,
+
+    /// Generators before this position are already written to the opcodes
+    generated: Cell<usize>,
 }
 
 impl Default for OpcodeGenerator {
@@ -191,6 +198,7 @@ impl OpcodeGenerator {
         OpcodeGenerator {
             generators: RefCell::new(Vec::new()),
             loop_groups: RefCell::new(VecDeque::new()),
+            generated: Cell::new(0),
         }
     }
 
@@ -334,21 +342,23 @@ impl OpcodeGenerator {
         generator
     }
 
-    pub fn create_call(&self, function_location: u8, argument_size: u8, assign_to_temp: bool) -> Rc<CallGenerator> {
+    pub fn create_call(&self, function_location: u8, argument_size: u8, named_arguments: bool, assign_to_temp: bool) -> Rc<CallGenerator> {
         let generator = Rc::new(CallGenerator {
             call_type: CallType::Call { constant_location: function_location },
             argument_size,
             assign_to_temp,
+            named_arguments,
         });
         self.generators.borrow_mut().push(generator.clone());
         generator
     }
 
-    pub fn create_call_stack(&self, argument_size: u8, assign_to_temp: bool) -> Rc<CallGenerator> {
+    pub fn create_call_stack(&self, argument_size: u8, named_arguments: bool, assign_to_temp: bool) -> Rc<CallGenerator> {
         let generator = Rc::new(CallGenerator {
             call_type: CallType::CallStack,
             argument_size,
             assign_to_temp,
+            named_arguments,
         });
         self.generators.borrow_mut().push(generator.clone());
         generator
@@ -366,21 +376,70 @@ impl OpcodeGenerator {
         generator
     }
 
+    pub fn create_init_tuple(&self, argument_size: usize) -> Rc<InitTupleGenerator> {
+        let generator = Rc::new(InitTupleGenerator { argument_size });
+        self.generators.borrow_mut().push(generator.clone());
+        generator
+    }
+
     pub fn create_init_dict(&self, argument_size: usize) -> Rc<InitDictGenerator> {
         let generator = Rc::new(InitDictGenerator { argument_size });
         self.generators.borrow_mut().push(generator.clone());
         generator
     }
+
+    pub fn create_match_list(&self, size: u8, has_rest: bool) -> Rc<PatternGenerator> {
+        let generator = Rc::new(PatternGenerator { pattern_type: PatternType::List { size, has_rest } });
+        self.generators.borrow_mut().push(generator.clone());
+        generator
+    }
+
+    pub fn create_list_tail(&self, start: u8) -> Rc<PatternGenerator> {
+        let generator = Rc::new(PatternGenerator { pattern_type: PatternType::ListTail(start) });
+        self.generators.borrow_mut().push(generator.clone());
+        generator
+    }
+
+    pub fn create_unpack(&self, size: u8) -> Rc<PatternGenerator> {
+        let generator = Rc::new(PatternGenerator { pattern_type: PatternType::Unpack(size) });
+        self.generators.borrow_mut().push(generator.clone());
+        generator
+    }
+
+    pub fn create_unpack_dict(&self, size: u8) -> Rc<PatternGenerator> {
+        let generator = Rc::new(PatternGenerator { pattern_type: PatternType::UnpackDict(size) });
+        self.generators.borrow_mut().push(generator.clone());
+        generator
+    }
 }
 
 impl OpcodeGenerator {
+    /// Appends the opcodes of the generators that added after the last generation.
     pub fn generate(&self, opcodes: &mut Vec<u8>) {
-        for generator in self.generators.borrow().iter() {
+        let generators = self.generators.borrow();
+        for generator in generators.iter().skip(self.generated.get()) {
             generator.generate(opcodes);
         }
+        self.generated.set(generators.len());
+    }
+
+    pub fn len(&self) -> usize {
+        self.generators.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.generators.borrow().is_empty()
+    }
+
+    /// Removes the generators that not generated yet, used when the compilation failed.
+    pub fn truncate(&self, len: usize) {
+        self.generators
+            .borrow_mut()
+            .truncate(len.max(self.generated.get()));
+        self.loop_groups.borrow_mut().clear();
     }
 
-    pub fn dump(&self, opcodes: &Vec<u8>) -> String {
+    pub fn dump(&self, opcodes: &[u8]) -> String {
         let builder = DumpBuilder::new();
         let indexer = Rc::new(AtomicUsize::new(0));
 
@@ -619,7 +678,7 @@ mod tests {
         let generator = OpcodeGenerator::new();
 
         generator.add_opcode(VmOpCode::Halt);
-        generator.create_call(20, 1, true);
+        generator.create_call(20, 1, false, true);
 
         generator.generate(&mut opcodes);
         let generated = generator.dump(&opcodes);
@@ -645,7 +704,7 @@ mod tests {
         let generator = OpcodeGenerator::new();
 
         generator.add_opcode(VmOpCode::Halt);
-        generator.create_call(10, 0, false);
+        generator.create_call(10, 0, false, false);
 
         generator.generate(&mut opcodes);
         let generated = generator.dump(&opcodes);
@@ -671,7 +730,7 @@ mod tests {
         let generator = OpcodeGenerator::new();
 
         generator.add_opcode(VmOpCode::Halt);
-        generator.create_call_stack(0, false);
+        generator.create_call_stack(0, false, false);
 
         generator.generate(&mut opcodes);
         let generated = generator.dump(&opcodes);
@@ -697,7 +756,7 @@ mod tests {
         let generator = OpcodeGenerator::new();
 
         generator.add_opcode(VmOpCode::Halt);
-        generator.create_call_stack(5, true);
+        generator.create_call_stack(5, false, true);
 
         generator.generate(&mut opcodes);
         let generated = generator.dump(&opcodes);
@@ -0,0 +1,123 @@
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::compiler::VmOpCode;
+
+use super::{DumpBuilder, OpcodeGeneratorTrait};
+
+#[derive(Debug, Clone)]
+pub enum PatternType {
+    List { size: u8, has_rest: bool },
+    ListTail(u8),
+    Unpack(u8),
+    UnpackDict(u8),
+}
+
+#[derive(Debug, Clone)]
+/// Generate pattern matching and destructuring opcodes.
+pub struct PatternGenerator {
+    pub pattern_type: PatternType,
+}
+
+impl OpcodeGeneratorTrait for PatternGenerator {
+    fn generate(&self, opcodes: &mut Vec<u8>) {
+        match self.pattern_type {
+            PatternType::List { size, has_rest } => {
+                opcodes.push(VmOpCode::MatchList.into());
+                opcodes.push(size);
+                opcodes.push(has_rest as u8);
+            }
+            PatternType::ListTail(start) => {
+                opcodes.push(VmOpCode::ListTail.into());
+                opcodes.push(start);
+            }
+            PatternType::Unpack(size) => {
+                opcodes.push(VmOpCode::Unpack.into());
+                opcodes.push(size);
+            }
+            PatternType::UnpackDict(size) => {
+                opcodes.push(VmOpCode::UnpackDict.into());
+                opcodes.push(size);
+            }
+        };
+    }
+
+    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, _: &[u8]) {
+        match self.pattern_type {
+            PatternType::List { size, has_rest } => {
+                let opcode_index = index.fetch_add(3, Ordering::SeqCst);
+                builder.add(opcode_index, VmOpCode::MatchList, size.to_string(), (has_rest as u8).to_string(), "".to_string());
+            }
+            PatternType::ListTail(start) => {
+                let opcode_index = index.fetch_add(2, Ordering::SeqCst);
+                builder.add(opcode_index, VmOpCode::ListTail, start.to_string(), "".to_string(), "".to_string());
+            }
+            PatternType::Unpack(size) => {
+                let opcode_index = index.fetch_add(2, Ordering::SeqCst);
+                builder.add(opcode_index, VmOpCode::Unpack, size.to_string(), "".to_string(), "".to_string());
+            }
+            PatternType::UnpackDict(size) => {
+                let opcode_index = index.fetch_add(2, Ordering::SeqCst);
+                builder.add(opcode_index, VmOpCode::UnpackDict, size.to_string(), "".to_string(), "".to_string());
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let mut opcodes = Vec::new();
+        let generator = PatternGenerator {
+            pattern_type: PatternType::List { size: 2, has_rest: true },
+        };
+
+        generator.generate(&mut opcodes);
+
+        assert_eq!(opcodes.len(), 3);
+        assert_eq!(opcodes[0], VmOpCode::MatchList.into());
+        assert_eq!(opcodes[1], 2);
+        assert_eq!(opcodes[2], 1);
+    }
+
+    #[test]
+    fn test_2() {
+        let mut opcodes = Vec::new();
+        let generator = PatternGenerator { pattern_type: PatternType::ListTail(3) };
+
+        generator.generate(&mut opcodes);
+
+        assert_eq!(opcodes.len(), 2);
+        assert_eq!(opcodes[0], VmOpCode::ListTail.into());
+        assert_eq!(opcodes[1], 3);
+    }
+
+    #[test]
+    fn test_3() {
+        let mut opcodes = Vec::new();
+        let generator = PatternGenerator { pattern_type: PatternType::Unpack(2) };
+
+        generator.generate(&mut opcodes);
+
+        assert_eq!(opcodes.len(), 2);
+        assert_eq!(opcodes[0], VmOpCode::Unpack.into());
+        assert_eq!(opcodes[1], 2);
+    }
+
+    #[test]
+    fn test_4() {
+        let mut opcodes = Vec::new();
+        let generator = PatternGenerator { pattern_type: PatternType::UnpackDict(3) };
+
+        generator.generate(&mut opcodes);
+
+        assert_eq!(opcodes.len(), 2);
+        assert_eq!(opcodes[0], VmOpCode::UnpackDict.into());
+        assert_eq!(opcodes[1], 3);
+    }
+}
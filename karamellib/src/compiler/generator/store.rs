@@ -38,7 +38,7 @@ impl OpcodeGeneratorTrait for StoreGenerator {
         };
     }
 
-    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, _: &Vec<u8>) {
+    fn dump(&self, builder: &DumpBuilder, index: Rc<AtomicUsize>, _: &[u8]) {
         let opcode_index = index.fetch_add(2, Ordering::SeqCst);
 
         match self.store_type {
@@ -5,10 +5,15 @@ mod static_storage;
 mod storage_builder;
 
 pub mod ast;
+pub mod capabilities;
 pub mod context;
+pub mod convert;
 pub mod generator;
 pub mod module;
+pub mod number;
 pub mod scope;
+#[cfg(feature = "serde")]
+pub mod serialize;
 pub mod value;
 
 pub use self::compiler::*;
@@ -90,6 +95,43 @@ pub enum VmOpCode {
     SetItem = 32,
     Constant = 33,
     Halt = 34,
+
+    /// Check if left value is a member of right value (list item, dict key or sub text).
+    In = 35,
+
+    /// Check if value is a list or tuple with given size. Second parameter indicate that list could have more items.
+    MatchList = 36,
+
+    /// Check if value is a dict.
+    MatchDict = 37,
+
+    /// Get item from list, tuple or dict without class property lookup.
+    MatchItem = 38,
+
+    /// Create new list from the items that starting at given index.
+    ListTail = 39,
+
+    /// Split list or tuple into the stack. First item placed at the top of the stack.
+    Unpack = 40,
+
+    /// Pop given number of keys and dict, than push values of the keys into the stack. First key's value placed at the top of the stack.
+    UnpackDict = 41,
+
+    /// Dublicate last two stack values and keep their order.
+    DublicatePair = 42,
+
+    /// Bitwise operations. Operands must be integer valued numbers.
+    BitwiseAnd = 43,
+    BitwiseOr = 44,
+    BitwiseXor = 45,
+    BitwiseNot = 46,
+    LeftShift = 47,
+    RightShift = 48,
+
+    Power = 49,
+
+    /// Division rounded toward negative infinity. Integer operands produce integer.
+    FloorDivision = 50,
 }
 
 impl From<VmOpCode> for u8 {
@@ -18,8 +18,6 @@ use super::ast::KaramelAstType;
 use super::context::KaramelCompilerContext;
 use super::function::FunctionReference;
 
-use crate::error::*;
-
 pub struct OpcodeModule {
     pub name: String,
     pub storage_index: usize,
@@ -104,6 +102,10 @@ pub fn load_module(params: &[String], modules: &mut Vec<Rc<OpcodeModule>>, optio
     }
 
     path.push(module.clone());
+    options
+        .capabilities
+        .check_load(&path)
+        .map_err(|error| KaramelError::new(0, 0, error))?;
 
     let content = match read_module_or_script(path.to_str().unwrap(), options) {
         Ok(content) => content,
@@ -126,7 +128,7 @@ pub fn load_module(params: &[String], modules: &mut Vec<Rc<OpcodeModule>>, optio
 
             let module = Rc::new(module);
             find_load_type(module.main_ast.clone(), options, modules, module.storage_index)?;
-            find_function_definition_type(module.clone(), ast.clone(), options, module_storage, true).map_err(KaramelErrorType::from)?;
+            find_function_definition_type(module.clone(), ast.clone(), options, module_storage, true)?;
             Ok(module.clone())
         }
         Err(error) => Err(error),
@@ -135,12 +137,10 @@ pub fn load_module(params: &[String], modules: &mut Vec<Rc<OpcodeModule>>, optio
 
 fn find_load_type(ast: Rc<KaramelAstType>, options: &mut KaramelCompilerContext, modules: &mut Vec<Rc<OpcodeModule>>, upper_storage_index: usize) -> CompilerResult {
     match &*ast {
-        KaramelAstType::Load(module_name) => {
-            if !options.has_module(module_name) {
-                let module = load_module(module_name, modules, options, upper_storage_index)?;
-                options.add_module(module.clone());
-                modules.push(module.clone());
-            }
+        KaramelAstType::Load(module_name) if !options.has_module(module_name) => {
+            let module = load_module(module_name, modules, options, upper_storage_index)?;
+            options.add_module(module.clone());
+            modules.push(module.clone());
         }
         KaramelAstType::Block(blocks) => {
             for block in blocks {
@@ -196,7 +196,7 @@ mod tests {
     {
         setup();
 
-        let result = panic::catch_unwind(|| test());
+        let result = panic::catch_unwind(test);
 
         teardown(to_be_removed);
 
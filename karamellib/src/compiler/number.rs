@@ -0,0 +1,500 @@
+use std::cmp::Ordering;
+
+use bigdecimal::{BigDecimal, RoundingMode};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{FromPrimitive, Pow, Signed, ToPrimitive, Zero};
+
+use crate::compiler::value::KaramelPrimative;
+use crate::error::KaramelErrorType;
+use crate::types::VmObject;
+
+/// Significant digits kept after dividing decimal numbers.
+pub const DECIMAL_DIVISION_PRECISION: u64 = 28;
+
+/// Left shift creates big integers, amount limited to keep the number in a reasonable size.
+pub const MAX_SHIFT_AMOUNT: i64 = 1 << 20;
+
+/// Bit size limit of the exact power results, larger results take too much time and memory.
+pub const MAX_POWER_BITS: u64 = 1 << 20;
+
+/// Numeric view of a value. Integer operations stay exact, overflowed results promoted to big integer.
+///
+/// Promotion order is integer, decimal, fraction and float. Binary operations produce the wider type of the operands,
+/// so exact types stay exact until a floating number joins the calculation.
+#[derive(Clone, Debug)]
+pub enum KaramelNumber {
+    Integer(i64),
+    BigInteger(BigInt),
+    Decimal(BigDecimal),
+    Fraction(BigRational),
+    Float(f64),
+}
+
+impl KaramelNumber {
+    pub fn from_primative(primative: &KaramelPrimative) -> Option<KaramelNumber> {
+        match primative {
+            KaramelPrimative::Number(number) => Some(KaramelNumber::Float(*number)),
+            KaramelPrimative::Integer(number) => Some(KaramelNumber::Integer(*number)),
+            KaramelPrimative::BigInteger(number) => Some(KaramelNumber::BigInteger(number.clone())),
+            KaramelPrimative::Decimal(number) => Some(KaramelNumber::Decimal(number.clone())),
+            KaramelPrimative::Fraction(number) => Some(KaramelNumber::Fraction(number.clone())),
+            _ => None,
+        }
+    }
+
+    /// Big integer converted to small integer if it fits.
+    pub fn from_big(number: BigInt) -> KaramelNumber {
+        match number.to_i64() {
+            Some(number) => KaramelNumber::Integer(number),
+            None => KaramelNumber::BigInteger(number),
+        }
+    }
+
+    /// Parse integer or floating number from text.
+    pub fn parse(text: &str) -> Option<KaramelNumber> {
+        let text = text.trim();
+        if let Ok(number) = text.parse::<i64>() {
+            return Some(KaramelNumber::Integer(number));
+        }
+
+        if let Ok(number) = text.parse::<BigInt>() {
+            return Some(KaramelNumber::BigInteger(number));
+        }
+
+        text.parse::<f64>().ok().map(KaramelNumber::Float)
+    }
+
+    /// Parse decimal number from text without losing any digit.
+    pub fn parse_decimal(text: &str) -> Option<KaramelNumber> {
+        text.trim()
+            .parse::<BigDecimal>()
+            .ok()
+            .map(KaramelNumber::Decimal)
+    }
+
+    /// Integer and big integer values.
+    pub fn is_integer(&self) -> bool {
+        matches!(self, KaramelNumber::Integer(_) | KaramelNumber::BigInteger(_))
+    }
+
+    /// Every type except floating number keeps the exact value.
+    pub fn is_exact(&self) -> bool {
+        !matches!(self, KaramelNumber::Float(_))
+    }
+
+    /// Decimal and fraction values.
+    pub fn is_rational(&self) -> bool {
+        matches!(self, KaramelNumber::Decimal(_) | KaramelNumber::Fraction(_))
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            KaramelNumber::Integer(number) => *number as f64,
+            KaramelNumber::BigInteger(number) => number.to_f64().unwrap_or(f64::NAN),
+            KaramelNumber::Decimal(number) => number.to_f64().unwrap_or(f64::NAN),
+            KaramelNumber::Fraction(number) => number.to_f64().unwrap_or(f64::NAN),
+            KaramelNumber::Float(number) => *number,
+        }
+    }
+
+    /// Number converted to integer if it has not any fractional part.
+    pub fn to_integer(&self) -> Option<KaramelNumber> {
+        match self {
+            KaramelNumber::Float(number) if number.is_finite() && number.fract() == 0.0 => BigInt::from_f64(*number).map(KaramelNumber::from_big),
+            KaramelNumber::Float(_) => None,
+            KaramelNumber::Decimal(_) | KaramelNumber::Fraction(_) => {
+                let number = self.to_rational();
+                match number.is_integer() {
+                    true => Some(KaramelNumber::from_big(number.to_integer())),
+                    false => None,
+                }
+            }
+            _ => Some(self.clone()),
+        }
+    }
+
+    /// Number rounded with given functions and converted to integer. Exact types rounded without losing precision.
+    pub fn round_with(&self, float: fn(f64) -> f64, exact: fn(&BigRational) -> BigRational) -> KaramelNumber {
+        match self {
+            KaramelNumber::Float(number) => {
+                let rounded = KaramelNumber::Float(float(*number));
+                rounded.to_integer().unwrap_or(rounded)
+            }
+            KaramelNumber::Decimal(_) | KaramelNumber::Fraction(_) => KaramelNumber::from_big(exact(&self.to_rational()).to_integer()),
+            _ => self.clone(),
+        }
+    }
+
+    /// Number rounded to given fractional digits, half values rounded away from zero.
+    pub fn round_digits(&self, digits: i64) -> KaramelNumber {
+        match self {
+            KaramelNumber::Float(number) => {
+                let multiplier = f64::powi(10.0, digits as i32);
+                KaramelNumber::Float((number * multiplier).round() / multiplier)
+            }
+            KaramelNumber::Decimal(_) | KaramelNumber::Fraction(_) => KaramelNumber::Decimal(
+                self.to_decimal()
+                    .with_scale_round(digits, RoundingMode::HalfUp),
+            ),
+            _ => self.clone(),
+        }
+    }
+
+    pub fn to_big(&self) -> BigInt {
+        match self {
+            KaramelNumber::Integer(number) => BigInt::from(*number),
+            KaramelNumber::BigInteger(number) => number.clone(),
+            KaramelNumber::Decimal(_) | KaramelNumber::Fraction(_) => self.to_rational().to_integer(),
+            KaramelNumber::Float(number) => BigInt::from_f64(*number).unwrap_or_default(),
+        }
+    }
+
+    pub fn to_decimal(&self) -> BigDecimal {
+        match self {
+            KaramelNumber::Integer(number) => BigDecimal::from(*number),
+            KaramelNumber::BigInteger(number) => BigDecimal::from(number.clone()),
+            KaramelNumber::Decimal(number) => number.clone(),
+            KaramelNumber::Fraction(number) => Self::divide_decimal(&BigDecimal::from(number.numer().clone()), &BigDecimal::from(number.denom().clone())),
+            /* Shortest text of the floating number used, so '0.1' converted to '0.1' instead of binary approximation */
+            KaramelNumber::Float(number) => number.to_string().parse::<BigDecimal>().unwrap_or_default(),
+        }
+    }
+
+    pub fn to_rational(&self) -> BigRational {
+        match self {
+            KaramelNumber::Integer(number) => BigRational::from_integer(BigInt::from(*number)),
+            KaramelNumber::BigInteger(number) => BigRational::from_integer(number.clone()),
+            KaramelNumber::Decimal(number) => {
+                let (digits, scale) = number.as_bigint_and_exponent();
+                let power: BigInt = Pow::pow(BigInt::from(10), scale.unsigned_abs());
+                match scale >= 0 {
+                    true => BigRational::new(digits, power),
+                    false => BigRational::from_integer(digits * power),
+                }
+            }
+            KaramelNumber::Fraction(number) => number.clone(),
+            KaramelNumber::Float(number) => BigRational::from_float(*number).unwrap_or_else(BigRational::zero),
+        }
+    }
+
+    fn divide_decimal(left: &BigDecimal, right: &BigDecimal) -> BigDecimal {
+        let result = left / right;
+        match result.digits() > DECIMAL_DIVISION_PRECISION {
+            true => result.with_prec(DECIMAL_DIVISION_PRECISION).normalized(),
+            false => result,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            KaramelNumber::Integer(number) => *number == 0,
+            KaramelNumber::BigInteger(number) => number.is_zero(),
+            KaramelNumber::Decimal(number) => number.is_zero(),
+            KaramelNumber::Fraction(number) => number.is_zero(),
+            KaramelNumber::Float(number) => *number == 0.0,
+        }
+    }
+
+    fn has_fraction(&self, other: &KaramelNumber) -> bool {
+        matches!(self, KaramelNumber::Fraction(_)) || matches!(other, KaramelNumber::Fraction(_))
+    }
+
+    fn operation(&self, other: &KaramelNumber, small: fn(i64, i64) -> Option<i64>, big: fn(BigInt, BigInt) -> BigInt, decimal: fn(BigDecimal, BigDecimal) -> BigDecimal, fraction: fn(BigRational, BigRational) -> BigRational, float: fn(f64, f64) -> f64) -> KaramelNumber {
+        match (self, other) {
+            (KaramelNumber::Integer(left), KaramelNumber::Integer(right)) => match small(*left, *right) {
+                Some(number) => KaramelNumber::Integer(number),
+                None => KaramelNumber::from_big(big(BigInt::from(*left), BigInt::from(*right))),
+            },
+            (left, right) if left.is_integer() && right.is_integer() => KaramelNumber::from_big(big(left.to_big(), right.to_big())),
+            (left, right) if !left.is_exact() || !right.is_exact() => KaramelNumber::Float(float(left.as_f64(), right.as_f64())),
+            (left, right) if left.has_fraction(right) => KaramelNumber::Fraction(fraction(left.to_rational(), right.to_rational())),
+            (left, right) => KaramelNumber::Decimal(decimal(left.to_decimal(), right.to_decimal())),
+        }
+    }
+
+    pub fn add(&self, other: &KaramelNumber) -> KaramelNumber {
+        self.operation(other, i64::checked_add, |left, right| left + right, |left, right| left + right, |left, right| left + right, |left, right| left + right)
+    }
+
+    pub fn sub(&self, other: &KaramelNumber) -> KaramelNumber {
+        self.operation(other, i64::checked_sub, |left, right| left - right, |left, right| left - right, |left, right| left - right, |left, right| left - right)
+    }
+
+    pub fn mul(&self, other: &KaramelNumber) -> KaramelNumber {
+        self.operation(other, i64::checked_mul, |left, right| left * right, |left, right| left * right, |left, right| left * right, |left, right| left * right)
+    }
+
+    /// Division of integers produce floating number, decimal and fraction divisions stay exact.
+    pub fn div(&self, other: &KaramelNumber) -> Result<KaramelNumber, KaramelErrorType> {
+        if !self.is_exact() || !other.is_exact() || (self.is_integer() && other.is_integer()) {
+            return Ok(KaramelNumber::Float(self.as_f64() / other.as_f64()));
+        }
+
+        if other.is_zero() {
+            return Err(KaramelErrorType::DivisionByZero);
+        }
+
+        match self.has_fraction(other) {
+            true => Ok(KaramelNumber::Fraction(self.to_rational() / other.to_rational())),
+            false => Ok(KaramelNumber::Decimal(Self::divide_decimal(&self.to_decimal(), &other.to_decimal()))),
+        }
+    }
+
+    /// Division rounded toward negative infinity. Exact types produce integer.
+    pub fn floor_div(&self, other: &KaramelNumber) -> Result<KaramelNumber, KaramelErrorType> {
+        if self.is_exact() && other.is_exact() && other.is_zero() {
+            return Err(KaramelErrorType::DivisionByZero);
+        }
+
+        Ok(self
+            .operation(
+                other,
+                |left, right| {
+                    left.checked_div(right)
+                        .map(|_| Integer::div_floor(&left, &right))
+                },
+                |left, right| Integer::div_floor(&left, &right),
+                |left, right| {
+                    BigDecimal::from(
+                        (KaramelNumber::Decimal(left).to_rational() / KaramelNumber::Decimal(right).to_rational())
+                            .floor()
+                            .to_integer(),
+                    )
+                },
+                |left, right| (left / right).floor(),
+                |left, right| (left / right).floor(),
+            )
+            .normalize_exact())
+    }
+
+    /// Remainder takes the sign of the dividend.
+    pub fn rem(&self, other: &KaramelNumber) -> Result<KaramelNumber, KaramelErrorType> {
+        if self.is_exact() && other.is_exact() && other.is_zero() {
+            return Err(KaramelErrorType::DivisionByZero);
+        }
+
+        Ok(self.operation(other, i64::checked_rem, |left, right| left % right, |left, right| left % right, |left, right| left % right, |left, right| left % right))
+    }
+
+    /// Integral decimal and fraction values converted to integer.
+    fn normalize_exact(self) -> KaramelNumber {
+        match self {
+            KaramelNumber::Decimal(_) | KaramelNumber::Fraction(_) => self.to_integer().unwrap_or(self),
+            _ => self,
+        }
+    }
+
+    pub fn pow(&self, other: &KaramelNumber) -> Result<KaramelNumber, KaramelErrorType> {
+        /* Result of the exact types grows with the exponent, size of the result is estimated before the calculation */
+        if let KaramelNumber::Integer(exponent) = other {
+            if self
+                .power_base_bits()
+                .saturating_mul(exponent.unsigned_abs())
+                > MAX_POWER_BITS
+            {
+                return Err(KaramelErrorType::PowerResultTooLarge(MAX_POWER_BITS));
+            }
+        }
+
+        Ok(match (self, other.to_u32_exponent()) {
+            (KaramelNumber::Integer(base), Some(exponent)) => match base.checked_pow(exponent) {
+                Some(number) => KaramelNumber::Integer(number),
+                None => KaramelNumber::from_big(Pow::pow(BigInt::from(*base), exponent)),
+            },
+            (KaramelNumber::BigInteger(base), Some(exponent)) => KaramelNumber::from_big(Pow::pow(base, exponent)),
+            (KaramelNumber::Decimal(base), Some(exponent)) => KaramelNumber::Decimal(base.powi(exponent as i64)),
+            (KaramelNumber::Fraction(base), _) => match other {
+                KaramelNumber::Integer(exponent) if i32::try_from(*exponent).is_ok() && (!base.is_zero() || *exponent >= 0) => KaramelNumber::Fraction(Pow::pow(base, *exponent as i32)),
+                _ => KaramelNumber::Float(self.as_f64().powf(other.as_f64())),
+            },
+            (base, _) => KaramelNumber::Float(base.as_f64().powf(other.as_f64())),
+        })
+    }
+
+    /// Bits that every multiplication adds to the exact result, zero for floating numbers and the bases that do not grow.
+    fn power_base_bits(&self) -> u64 {
+        let bits = match self {
+            KaramelNumber::Integer(number) => 64 - number.unsigned_abs().leading_zeros() as u64,
+            KaramelNumber::BigInteger(number) => number.bits(),
+            KaramelNumber::Decimal(number) => number.as_bigint_and_exponent().0.bits(),
+            KaramelNumber::Fraction(number) => number.numer().bits().max(number.denom().bits()),
+            KaramelNumber::Float(_) => 0,
+        };
+        bits.saturating_sub(1)
+    }
+
+    fn to_u32_exponent(&self) -> Option<u32> {
+        match self {
+            KaramelNumber::Integer(number) => u32::try_from(*number).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn compare(&self, other: &KaramelNumber) -> Option<Ordering> {
+        match (self, other) {
+            (KaramelNumber::Integer(left), KaramelNumber::Integer(right)) => Some(left.cmp(right)),
+            (left, right) if left.is_integer() && right.is_integer() => Some(left.to_big().cmp(&right.to_big())),
+            (KaramelNumber::Float(left), KaramelNumber::Float(right)) => left.partial_cmp(right),
+            /* Decimal and fraction numbers compared with the exact value of the other number */
+            (left, right) if (left.is_rational() || right.is_rational()) && left.as_f64().is_finite() && right.as_f64().is_finite() => Some(left.to_rational().cmp(&right.to_rational())),
+            (left, right) => left.as_f64().partial_cmp(&right.as_f64()),
+        }
+    }
+
+    pub fn bitwise(&self, other: &KaramelNumber, small: fn(i64, i64) -> i64, big: fn(&BigInt, &BigInt) -> BigInt) -> KaramelNumber {
+        match (self, other) {
+            (KaramelNumber::Integer(left), KaramelNumber::Integer(right)) => KaramelNumber::Integer(small(*left, *right)),
+            (left, right) => KaramelNumber::from_big(big(&left.to_big(), &right.to_big())),
+        }
+    }
+
+    pub fn shift_left(&self, amount: usize) -> KaramelNumber {
+        KaramelNumber::from_big(self.to_big() << amount)
+    }
+
+    pub fn shift_right(&self, amount: usize) -> KaramelNumber {
+        match self {
+            KaramelNumber::Integer(number) => KaramelNumber::Integer(number >> amount.min(63)),
+            number => KaramelNumber::from_big(number.to_big() >> amount),
+        }
+    }
+
+    pub fn not(&self) -> KaramelNumber {
+        match self {
+            KaramelNumber::Integer(number) => KaramelNumber::Integer(!number),
+            number => KaramelNumber::from_big(!number.to_big()),
+        }
+    }
+
+    pub fn is_positive(&self) -> bool {
+        match self {
+            KaramelNumber::Integer(number) => *number > 0,
+            KaramelNumber::BigInteger(number) => number.is_positive(),
+            KaramelNumber::Decimal(number) => number.is_positive(),
+            KaramelNumber::Fraction(number) => number.is_positive(),
+            KaramelNumber::Float(number) => *number > 0.0,
+        }
+    }
+
+    pub fn to_primative(self) -> KaramelPrimative {
+        match self {
+            KaramelNumber::Integer(number) => KaramelPrimative::Integer(number),
+            KaramelNumber::BigInteger(number) => KaramelPrimative::BigInteger(number),
+            KaramelNumber::Decimal(number) => KaramelPrimative::Decimal(number),
+            KaramelNumber::Fraction(number) => KaramelPrimative::Fraction(number),
+            KaramelNumber::Float(number) => KaramelPrimative::Number(number),
+        }
+    }
+}
+
+impl PartialEq for KaramelNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.compare(other) == Some(Ordering::Equal)
+    }
+}
+
+impl From<KaramelNumber> for VmObject {
+    fn from(source: KaramelNumber) -> Self {
+        match source {
+            KaramelNumber::Integer(number) => VmObject::from(number),
+            KaramelNumber::Float(number) => VmObject::from(number),
+            number => VmObject::native_convert(number.to_primative()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overflow_promotion() {
+        let result = KaramelNumber::Integer(i64::MAX).add(&KaramelNumber::Integer(1));
+        assert!(matches!(result, KaramelNumber::BigInteger(_)));
+        assert_eq!(result.sub(&KaramelNumber::Integer(1)), KaramelNumber::Integer(i64::MAX));
+        assert!(matches!(result.sub(&KaramelNumber::Integer(1)), KaramelNumber::Integer(_)));
+    }
+
+    #[test]
+    fn test_floor_div() {
+        assert_eq!(
+            KaramelNumber::Integer(7)
+                .floor_div(&KaramelNumber::Integer(2))
+                .unwrap(),
+            KaramelNumber::Integer(3)
+        );
+        assert_eq!(
+            KaramelNumber::Integer(-7)
+                .floor_div(&KaramelNumber::Integer(2))
+                .unwrap(),
+            KaramelNumber::Integer(-4)
+        );
+        assert_eq!(
+            KaramelNumber::Float(7.5)
+                .floor_div(&KaramelNumber::Integer(2))
+                .unwrap(),
+            KaramelNumber::Float(3.0)
+        );
+        assert!(KaramelNumber::Integer(i64::MIN)
+            .floor_div(&KaramelNumber::Integer(-1))
+            .unwrap()
+            .is_integer());
+        assert_eq!(KaramelNumber::Integer(1).floor_div(&KaramelNumber::Integer(0)), Err(KaramelErrorType::DivisionByZero));
+    }
+
+    #[test]
+    fn test_pow() {
+        let result = KaramelNumber::Integer(2)
+            .pow(&KaramelNumber::Integer(100))
+            .unwrap();
+        assert_eq!(format!("{}", VmObject::from(result)), "1267650600228229401496703205376");
+        assert_eq!(KaramelNumber::Integer(2).pow(&KaramelNumber::Integer(-1)), Ok(KaramelNumber::Float(0.5)));
+        assert_eq!(KaramelNumber::Integer(1).pow(&KaramelNumber::Integer(i64::MAX)), Ok(KaramelNumber::Integer(1)));
+        assert_eq!(KaramelNumber::Integer(10).pow(&KaramelNumber::Integer(1_000_000_000)), Err(KaramelErrorType::PowerResultTooLarge(MAX_POWER_BITS)));
+        assert!(KaramelNumber::Float(10.0)
+            .pow(&KaramelNumber::Integer(1_000_000_000))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(KaramelNumber::parse("123"), Some(KaramelNumber::Integer(123)));
+        assert!(matches!(KaramelNumber::parse("123456789012345678901234567890"), Some(KaramelNumber::BigInteger(_))));
+        assert_eq!(KaramelNumber::parse("1.5"), Some(KaramelNumber::Float(1.5)));
+        assert_eq!(KaramelNumber::parse("karamel"), None);
+    }
+
+    #[test]
+    fn test_decimal() {
+        let first = KaramelNumber::parse_decimal("0.10").unwrap();
+        let second = KaramelNumber::parse_decimal("0.20").unwrap();
+        assert_eq!(format!("{}", VmObject::from(first.add(&second))), "0.30");
+        assert!(matches!(first.add(&KaramelNumber::Integer(1)), KaramelNumber::Decimal(_)));
+        assert!(matches!(first.add(&KaramelNumber::Float(1.0)), KaramelNumber::Float(_)));
+        assert_eq!(
+            format!(
+                "{}",
+                VmObject::from(
+                    KaramelNumber::Integer(1)
+                        .div(&KaramelNumber::parse_decimal("3").unwrap())
+                        .unwrap()
+                )
+            ),
+            "0.3333333333333333333333333333"
+        );
+        assert_eq!(first.div(&KaramelNumber::Integer(0)), Err(KaramelErrorType::DivisionByZero));
+    }
+
+    #[test]
+    fn test_fraction() {
+        let third = KaramelNumber::Fraction(BigRational::new(BigInt::from(1), BigInt::from(3)));
+        let result = third.add(&third).add(&third);
+        assert!(matches!(result, KaramelNumber::Fraction(_)));
+        assert_eq!(result, KaramelNumber::Integer(1));
+        assert!(matches!(third.add(&KaramelNumber::parse_decimal("0.5").unwrap()), KaramelNumber::Fraction(_)));
+        assert_eq!(format!("{}", VmObject::from(third.pow(&KaramelNumber::Integer(-2)).unwrap())), "9");
+        assert_ne!(KaramelNumber::parse_decimal("0.1").unwrap(), KaramelNumber::Float(0.1));
+    }
+}
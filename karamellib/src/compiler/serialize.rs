@@ -0,0 +1,704 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use num_traits::ToPrimitive;
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor};
+use serde::ser::{self, Serialize, SerializeMap, SerializeTuple, Serializer};
+use serde::{forward_to_deserialize_any, Deserialize, Deserializer};
+
+use crate::compiler::number::KaramelNumber;
+use crate::compiler::value::{KaramelDictKey, KaramelPrimative, EMPTY_OBJECT};
+use crate::compiler::GetType;
+use crate::error::KaramelErrorType;
+use crate::types::VmObject;
+
+/// Converts the Rust value to a Karamel value. Structs and maps become 'sözlük', sequences become 'liste' and tuples become 'demet'.
+///
+/// ```
+/// use karamellib::compiler::serialize::{from_value, to_value};
+///
+/// let value = to_value(&vec![1, 2, 3]).unwrap();
+/// let items: Vec<i64> = from_value(&value).unwrap();
+/// ```
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<VmObject, KaramelErrorType> {
+    value.serialize(ValueSerializer)
+}
+
+/// Reads the Rust value from a Karamel value. 'fonksiyon' and 'sınıf' values could not be read.
+pub fn from_value<T: DeserializeOwned>(value: &VmObject) -> Result<T, KaramelErrorType> {
+    T::deserialize(ValueDeserializer(*value))
+}
+
+impl ser::Error for KaramelErrorType {
+    fn custom<T: Display>(message: T) -> Self {
+        KaramelErrorType::SerializationFailed(message.to_string())
+    }
+}
+
+impl de::Error for KaramelErrorType {
+    fn custom<T: Display>(message: T) -> Self {
+        KaramelErrorType::SerializationFailed(message.to_string())
+    }
+}
+
+impl Serialize for KaramelPrimative {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            KaramelPrimative::Empty => serializer.serialize_unit(),
+            KaramelPrimative::Number(number) => serializer.serialize_f64(*number),
+            KaramelPrimative::Integer(number) => serializer.serialize_i64(*number),
+            KaramelPrimative::BigInteger(number) => match (number.to_u64(), number.to_i128()) {
+                (Some(number), _) => serializer.serialize_u64(number),
+                (None, Some(number)) => serializer.serialize_i128(number),
+                _ => serializer.serialize_str(&number.to_string()),
+            },
+            KaramelPrimative::Decimal(_) | KaramelPrimative::Fraction(_) => serializer.serialize_f64(self.as_f64().unwrap_or_default()),
+            KaramelPrimative::Bool(value) => serializer.serialize_bool(*value),
+            KaramelPrimative::Text(text) => serializer.serialize_str(text),
+            KaramelPrimative::Pattern(pattern) => serializer.serialize_str(pattern.as_str()),
+            KaramelPrimative::Date(millis) | KaramelPrimative::Duration(millis) => serializer.serialize_i64(*millis),
+            KaramelPrimative::List(items) => serializer.collect_seq(items.borrow().iter()),
+            KaramelPrimative::Set(items) => serializer.collect_seq(items.borrow().iter().map(|item| item.to_primative())),
+            KaramelPrimative::Tuple(items) => {
+                let mut tuple = serializer.serialize_tuple(items.len())?;
+                for item in items.iter() {
+                    tuple.serialize_element(item)?;
+                }
+                tuple.end()
+            }
+            KaramelPrimative::Dict(items) => {
+                let items = items.borrow();
+                let mut map = serializer.serialize_map(Some(items.len()))?;
+                for (key, value) in items.iter() {
+                    map.serialize_entry(&key.to_primative(), value)?;
+                }
+                map.end()
+            }
+            KaramelPrimative::Function(_, _) | KaramelPrimative::Class(_) => Err(ser::Error::custom(KaramelErrorType::ValueNotSerializable(self.get_type()))),
+        }
+    }
+}
+
+impl Serialize for VmObject {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.deref().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for VmObject {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for KaramelPrimative {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        VmObject::deserialize(deserializer).map(|value| value.deref_clean())
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = VmObject;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("Karamel değeri")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<VmObject, E> {
+        Ok(VmObject::from(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<VmObject, E> {
+        Ok(VmObject::from(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<VmObject, E> {
+        Ok(integer_object(value))
+    }
+
+    fn visit_i128<E>(self, value: i128) -> Result<VmObject, E> {
+        Ok(integer_object(value))
+    }
+
+    fn visit_u128<E>(self, value: u128) -> Result<VmObject, E> {
+        Ok(integer_object(value))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<VmObject, E> {
+        Ok(VmObject::from(value))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<VmObject, E> {
+        Ok(VmObject::from(value.to_string()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<VmObject, E> {
+        Ok(VmObject::from(value))
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<VmObject, E> {
+        Ok(VmObject::from(
+            value
+                .iter()
+                .map(|byte| VmObject::from(*byte as i64))
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    fn visit_unit<E>(self) -> Result<VmObject, E> {
+        Ok(EMPTY_OBJECT)
+    }
+
+    fn visit_none<E>(self) -> Result<VmObject, E> {
+        Ok(EMPTY_OBJECT)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<VmObject, D::Error> {
+        VmObject::deserialize(deserializer)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<VmObject, D::Error> {
+        VmObject::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<VmObject, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(item) = seq.next_element::<VmObject>()? {
+            items.push(item);
+        }
+        Ok(VmObject::from(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<VmObject, A::Error> {
+        let mut items = HashMap::new();
+        while let Some((key, value)) = map.next_entry::<VmObject, VmObject>()? {
+            items.insert(dict_key(&key).map_err(de::Error::custom)?, value);
+        }
+        Ok(VmObject::from(items))
+    }
+}
+
+fn integer_object<T: TryInto<i64> + Into<num_bigint::BigInt> + Copy>(value: T) -> VmObject {
+    match value.try_into() {
+        Ok(number) => VmObject::from(number),
+        Err(_) => VmObject::native_convert(KaramelPrimative::BigInteger(value.into())),
+    }
+}
+
+/// Only the text and tuple values could be used as a dictionary key.
+fn dict_key(key: &VmObject) -> Result<KaramelDictKey, KaramelErrorType> {
+    let key = key.deref();
+    KaramelDictKey::new(&key).ok_or(KaramelErrorType::DictKeyNotValid(key))
+}
+
+fn variant_object(variant: &str, value: VmObject) -> VmObject {
+    VmObject::from(HashMap::from([(KaramelDictKey::from(variant), value)]))
+}
+
+/// Serializer that builds Karamel values. Enum variants become a text or a 'sözlük' with the variant name as the key.
+pub struct ValueSerializer;
+
+impl Serializer for ValueSerializer {
+    type Ok = VmObject;
+    type Error = KaramelErrorType;
+
+    type SerializeSeq = SerializeItems;
+    type SerializeTuple = SerializeItems;
+    type SerializeTupleStruct = SerializeItems;
+    type SerializeTupleVariant = SerializeItems;
+    type SerializeMap = SerializeDict;
+    type SerializeStruct = SerializeDict;
+    type SerializeStructVariant = SerializeDict;
+
+    fn serialize_bool(self, value: bool) -> Result<VmObject, KaramelErrorType> {
+        Ok(VmObject::from(value))
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<VmObject, KaramelErrorType> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<VmObject, KaramelErrorType> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<VmObject, KaramelErrorType> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<VmObject, KaramelErrorType> {
+        Ok(VmObject::from(value))
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<VmObject, KaramelErrorType> {
+        Ok(integer_object(value))
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<VmObject, KaramelErrorType> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<VmObject, KaramelErrorType> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<VmObject, KaramelErrorType> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<VmObject, KaramelErrorType> {
+        Ok(integer_object(value))
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<VmObject, KaramelErrorType> {
+        Ok(integer_object(value))
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<VmObject, KaramelErrorType> {
+        self.serialize_f64(value as f64)
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<VmObject, KaramelErrorType> {
+        Ok(VmObject::from(value))
+    }
+
+    fn serialize_char(self, value: char) -> Result<VmObject, KaramelErrorType> {
+        Ok(VmObject::from(value.to_string()))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<VmObject, KaramelErrorType> {
+        Ok(VmObject::from(value.to_string()))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<VmObject, KaramelErrorType> {
+        self.collect_seq(value)
+    }
+
+    fn serialize_none(self) -> Result<VmObject, KaramelErrorType> {
+        Ok(EMPTY_OBJECT)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<VmObject, KaramelErrorType> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<VmObject, KaramelErrorType> {
+        Ok(EMPTY_OBJECT)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<VmObject, KaramelErrorType> {
+        Ok(EMPTY_OBJECT)
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<VmObject, KaramelErrorType> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<VmObject, KaramelErrorType> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _: &'static str, _: u32, variant: &'static str, value: &T) -> Result<VmObject, KaramelErrorType> {
+        Ok(variant_object(variant, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeItems, KaramelErrorType> {
+        Ok(SerializeItems::new(len.unwrap_or_default(), false, None))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeItems, KaramelErrorType> {
+        Ok(SerializeItems::new(len, true, None))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, len: usize) -> Result<SerializeItems, KaramelErrorType> {
+        Ok(SerializeItems::new(len, true, None))
+    }
+
+    fn serialize_tuple_variant(self, _: &'static str, _: u32, variant: &'static str, len: usize) -> Result<SerializeItems, KaramelErrorType> {
+        Ok(SerializeItems::new(len, true, Some(variant)))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<SerializeDict, KaramelErrorType> {
+        Ok(SerializeDict::new(None))
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<SerializeDict, KaramelErrorType> {
+        Ok(SerializeDict::new(None))
+    }
+
+    fn serialize_struct_variant(self, _: &'static str, _: u32, variant: &'static str, _: usize) -> Result<SerializeDict, KaramelErrorType> {
+        Ok(SerializeDict::new(Some(variant)))
+    }
+}
+
+/// Items of the 'liste' or 'demet'.
+pub struct SerializeItems {
+    items: Vec<VmObject>,
+    tuple: bool,
+    variant: Option<&'static str>,
+}
+
+impl SerializeItems {
+    fn new(capacity: usize, tuple: bool, variant: Option<&'static str>) -> Self {
+        SerializeItems { items: Vec::with_capacity(capacity), tuple, variant }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), KaramelErrorType> {
+        self.items.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<VmObject, KaramelErrorType> {
+        let value = match self.tuple {
+            true => VmObject::native_convert(KaramelPrimative::Tuple(self.items)),
+            false => VmObject::from(self.items),
+        };
+
+        Ok(match self.variant {
+            Some(variant) => variant_object(variant, value),
+            None => value,
+        })
+    }
+}
+
+impl ser::SerializeSeq for SerializeItems {
+    type Ok = VmObject;
+    type Error = KaramelErrorType;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), KaramelErrorType> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<VmObject, KaramelErrorType> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeItems {
+    type Ok = VmObject;
+    type Error = KaramelErrorType;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), KaramelErrorType> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<VmObject, KaramelErrorType> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeItems {
+    type Ok = VmObject;
+    type Error = KaramelErrorType;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), KaramelErrorType> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<VmObject, KaramelErrorType> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeItems {
+    type Ok = VmObject;
+    type Error = KaramelErrorType;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), KaramelErrorType> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<VmObject, KaramelErrorType> {
+        self.finish()
+    }
+}
+
+/// Items of the 'sözlük'.
+pub struct SerializeDict {
+    items: HashMap<KaramelDictKey, VmObject>,
+    key: Option<KaramelDictKey>,
+    variant: Option<&'static str>,
+}
+
+impl SerializeDict {
+    fn new(variant: Option<&'static str>) -> Self {
+        SerializeDict { items: HashMap::new(), key: None, variant }
+    }
+
+    fn insert<T: Serialize + ?Sized>(&mut self, key: KaramelDictKey, value: &T) -> Result<(), KaramelErrorType> {
+        self.items.insert(key, value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<VmObject, KaramelErrorType> {
+        let value = VmObject::from(self.items);
+        Ok(match self.variant {
+            Some(variant) => variant_object(variant, value),
+            None => value,
+        })
+    }
+}
+
+impl ser::SerializeMap for SerializeDict {
+    type Ok = VmObject;
+    type Error = KaramelErrorType;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), KaramelErrorType> {
+        self.key = Some(dict_key(&key.serialize(ValueSerializer)?)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), KaramelErrorType> {
+        match self.key.take() {
+            Some(key) => self.insert(key, value),
+            None => Err(ser::Error::custom("anahtar olmadan değer eklenemez")),
+        }
+    }
+
+    fn end(self) -> Result<VmObject, KaramelErrorType> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeDict {
+    type Ok = VmObject;
+    type Error = KaramelErrorType;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), KaramelErrorType> {
+        self.insert(KaramelDictKey::from(key), value)
+    }
+
+    fn end(self) -> Result<VmObject, KaramelErrorType> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeDict {
+    type Ok = VmObject;
+    type Error = KaramelErrorType;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), KaramelErrorType> {
+        self.insert(KaramelDictKey::from(key), value)
+    }
+
+    fn end(self) -> Result<VmObject, KaramelErrorType> {
+        self.finish()
+    }
+}
+
+/// Deserializer that reads Rust values from a Karamel value.
+pub struct ValueDeserializer(pub VmObject);
+
+impl<'de> IntoDeserializer<'de, KaramelErrorType> for ValueDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer {
+    type Error = KaramelErrorType;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, KaramelErrorType> {
+        let value = self.0.deref();
+        match &*value {
+            KaramelPrimative::Empty => visitor.visit_unit(),
+            /* Floating numbers without fractional part could be read as integer */
+            KaramelPrimative::Number(number) => match KaramelNumber::Float(*number).to_integer() {
+                Some(KaramelNumber::Integer(integer)) => visitor.visit_i64(integer),
+                _ => visitor.visit_f64(*number),
+            },
+            KaramelPrimative::Integer(number) => visitor.visit_i64(*number),
+            KaramelPrimative::BigInteger(number) => match (number.to_u64(), number.to_i128()) {
+                (Some(number), _) => visitor.visit_u64(number),
+                (None, Some(number)) => visitor.visit_i128(number),
+                _ => visitor.visit_string(number.to_string()),
+            },
+            KaramelPrimative::Decimal(_) | KaramelPrimative::Fraction(_) => visitor.visit_f64(value.as_f64().unwrap_or_default()),
+            KaramelPrimative::Bool(value) => visitor.visit_bool(*value),
+            KaramelPrimative::Text(text) => visitor.visit_str(text),
+            KaramelPrimative::Pattern(pattern) => visitor.visit_str(pattern.as_str()),
+            KaramelPrimative::Date(millis) | KaramelPrimative::Duration(millis) => visitor.visit_i64(*millis),
+            KaramelPrimative::List(items) => visit_items(items.borrow().clone(), visitor),
+            KaramelPrimative::Tuple(items) => visit_items(items.clone(), visitor),
+            KaramelPrimative::Set(items) => visit_items(
+                items
+                    .borrow()
+                    .iter()
+                    .map(|item| VmObject::native_convert(item.to_primative()))
+                    .collect(),
+                visitor,
+            ),
+            KaramelPrimative::Dict(items) => {
+                let items = items
+                    .borrow()
+                    .iter()
+                    .map(|(key, value)| (ValueDeserializer(VmObject::native_convert(key.to_primative())), ValueDeserializer(*value)))
+                    .collect::<Vec<_>>();
+                let mut map = MapDeserializer::new(items.into_iter());
+                let result = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(result)
+            }
+            KaramelPrimative::Function(_, _) | KaramelPrimative::Class(_) => Err(KaramelErrorType::ValueNotSerializable(value.get_type())),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, KaramelErrorType> {
+        match *self.0.deref() {
+            KaramelPrimative::Empty => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value, KaramelErrorType> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _: &'static str, _: &'static [&'static str], visitor: V) -> Result<V::Value, KaramelErrorType> {
+        let value = self.0.deref();
+        match &*value {
+            KaramelPrimative::Text(_) => visitor.visit_enum(EnumDeserializer { variant: value.get_text(), value: EMPTY_OBJECT }),
+            KaramelPrimative::Dict(items) if items.borrow().len() == 1 => match items.borrow().iter().next() {
+                Some((KaramelDictKey::Text(variant), value)) => visitor.visit_enum(EnumDeserializer { variant: variant.to_string(), value: *value }),
+                _ => Err(de::Error::invalid_type(de::Unexpected::Map, &"yazı anahtarlı sözlük")),
+            },
+            _ => Err(de::Error::invalid_type(de::Unexpected::Other(&value.get_type()), &"yazı ya da tek elemanlı sözlük")),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+fn visit_items<'de, V: Visitor<'de>>(items: Vec<VmObject>, visitor: V) -> Result<V::Value, KaramelErrorType> {
+    let mut seq = SeqDeserializer::new(items.into_iter().map(ValueDeserializer));
+    let result = visitor.visit_seq(&mut seq)?;
+    seq.end()?;
+    Ok(result)
+}
+
+struct EnumDeserializer {
+    variant: String,
+    value: VmObject,
+}
+
+impl<'de> EnumAccess<'de> for EnumDeserializer {
+    type Error = KaramelErrorType;
+    type Variant = ValueDeserializer;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, ValueDeserializer), KaramelErrorType> {
+        let variant = seed.deserialize(IntoDeserializer::<KaramelErrorType>::into_deserializer(self.variant))?;
+        Ok((variant, ValueDeserializer(self.value)))
+    }
+}
+
+impl<'de> VariantAccess<'de> for ValueDeserializer {
+    type Error = KaramelErrorType;
+
+    fn unit_variant(self) -> Result<(), KaramelErrorType> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, KaramelErrorType> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, KaramelErrorType> {
+        self.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, _: &'static [&'static str], visitor: V) -> Result<V::Value, KaramelErrorType> {
+        self.deserialize_any(visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::rc::Rc;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::buildin::class::baseclass::BasicInnerClass;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Mode {
+        Quiet,
+        Limit(u32),
+        Range { start: i64, end: i64 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        name: String,
+        retry: u8,
+        ratio: f64,
+        enabled: bool,
+        tags: Vec<String>,
+        point: (i32, i32),
+        parent: Option<Box<Config>>,
+        limits: BTreeMap<String, u64>,
+        modes: Vec<Mode>,
+    }
+
+    fn config() -> Config {
+        Config {
+            name: "karamel".to_string(),
+            retry: 3,
+            ratio: 0.5,
+            enabled: true,
+            tags: vec!["a".to_string(), "b".to_string()],
+            point: (-1, 2),
+            parent: None,
+            limits: BTreeMap::from([("büyük".to_string(), u64::MAX), ("küçük".to_string(), 1)]),
+            modes: vec![Mode::Quiet, Mode::Limit(10), Mode::Range { start: 1, end: 5 }],
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let value = to_value(&config()).unwrap();
+        let dict = match &*value.deref() {
+            KaramelPrimative::Dict(items) => items.borrow().clone(),
+            _ => panic!("sözlük bekleniyordu"),
+        };
+
+        assert_eq!(*dict[&KaramelDictKey::from("name")].deref(), KaramelPrimative::Text(Rc::new("karamel".to_string())));
+        assert_eq!(*dict[&KaramelDictKey::from("parent")].deref(), KaramelPrimative::Empty);
+        assert!(matches!(*dict[&KaramelDictKey::from("point")].deref(), KaramelPrimative::Tuple(_)));
+        assert!(matches!(*dict[&KaramelDictKey::from("tags")].deref(), KaramelPrimative::List(_)));
+        assert_eq!(from_value::<Config>(&value).unwrap(), config());
+    }
+
+    #[test]
+    fn test_karamel_values() {
+        let list = VmObject::from(vec![VmObject::from(1.0), VmObject::from(2.5), VmObject::from("üç".to_string())]);
+        assert_eq!(from_value::<(u8, f32, String)>(&list).unwrap(), (1, 2.5, "üç".to_string()));
+        assert_eq!(from_value::<Option<i64>>(&EMPTY_OBJECT).unwrap(), None);
+        assert_eq!(to_value(&Some(5_u64)).unwrap(), VmObject::from(5_i64));
+        assert_eq!(*to_value(&u64::MAX).unwrap().deref(), KaramelPrimative::BigInteger(u64::MAX.into()));
+
+        let copy: VmObject = from_value(&list).unwrap();
+        assert_eq!(*copy.deref(), *list.deref());
+    }
+
+    #[test]
+    fn test_not_serializable() {
+        let class = VmObject::native_convert(KaramelPrimative::Class(Rc::new(BasicInnerClass::default())));
+        assert_eq!(from_value::<i64>(&class), Err(KaramelErrorType::ValueNotSerializable("sınıf".to_string())));
+
+        let list = VmObject::from(vec![class]);
+        assert_eq!(from_value::<Vec<VmObject>>(&list), Err(KaramelErrorType::ValueNotSerializable("sınıf".to_string())));
+        assert!(to_value(&list).is_err());
+
+        assert_eq!(to_value(&HashMap::from([(1, 2)])), Err(KaramelErrorType::DictKeyNotValid(Rc::new(KaramelPrimative::Integer(1)))));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::buildin::Module;
 use crate::compiler::*;
 use crate::types::*;
+use std::mem;
 use std::rc::Rc;
 
 use std::ptr;
@@ -36,7 +37,10 @@ impl StaticStorage {
         self.parent_location
     }
     pub fn add_constant(&mut self, value: Rc<KaramelPrimative>) -> usize {
-        let constant_position = self.constants.iter().position(|x| *x.deref() == *value);
+        let constant_position = self
+            .constants
+            .iter()
+            .position(|x| Self::same_constant(&x.deref(), &value));
 
         match constant_position {
             Some(position) => position,
@@ -66,10 +70,15 @@ impl StaticStorage {
     pub fn get_constant_location(&self, value: Rc<KaramelPrimative>) -> Option<u8> {
         self.constants
             .iter()
-            .position(|x| *x.deref() == *value)
+            .position(|x| Self::same_constant(&x.deref(), &value))
             .map(|number| number as u8)
     }
 
+    /// '1' and '1.0' are equal but they could not share the same constant slot.
+    fn same_constant(left: &KaramelPrimative, right: &KaramelPrimative) -> bool {
+        mem::discriminant(left) == mem::discriminant(right) && *left == *right
+    }
+
     pub fn get_function_constant(&self, name: String, module: Rc<dyn Module>) -> Option<u8> {
         for (index, item) in self.constants.iter().enumerate() {
             if let KaramelPrimative::Function(reference, _) = &*item.deref() {
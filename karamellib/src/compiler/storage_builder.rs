@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use crate::compiler::ast::KaramelAstType;
+use crate::compiler::ast::{KaramelAstType, KaramelMatchPattern};
 use crate::compiler::context::KaramelCompilerContext;
 use crate::compiler::value::KaramelPrimative;
 use crate::error::KaramelErrorType;
@@ -80,14 +80,24 @@ impl StorageBuilder {
                 let module_path = params[0..(params.len() - 1)].to_vec();
 
                 let function_search = options.get_function(&name, &module_path, storage_index);
-                match function_search {
-                    Some(reference) => options
-                        .storages
-                        .get_mut(storage_index)
-                        .unwrap()
-                        .add_constant(Rc::new(KaramelPrimative::Function(reference, None))),
-                    None => return Err(KaramelErrorType::FunctionNotFound(name.to_string())),
+                let constant = match function_search {
+                    Some(reference) => {
+                        options.capabilities.check_function(&reference)?;
+                        Rc::new(KaramelPrimative::Function(reference, None))
+                    }
+                    None => match options.get_module_constant(&name, &module_path) {
+                        Some(constant) => {
+                            options.capabilities.check_module(&module_path)?;
+                            constant
+                        }
+                        None => return Err(KaramelErrorType::FunctionNotFound(name.to_string())),
+                    },
                 };
+                options
+                    .storages
+                    .get_mut(storage_index)
+                    .unwrap()
+                    .add_constant(constant);
             }
 
             KaramelAstType::Assignment { variable, operator: _, expression } => {
@@ -132,6 +142,7 @@ impl StorageBuilder {
                     KaramelAstType::Symbol(function_name) => {
                         let function_search = options.get_function(function_name.to_string(), module.get_path(), storage_index);
                         if let Some(reference) = function_search {
+                            options.capabilities.check_function(&reference)?;
                             options
                                 .storages
                                 .get_mut(storage_index)
@@ -148,11 +159,14 @@ impl StorageBuilder {
                     KaramelAstType::ModulePath(names) => {
                         let function_search = options.get_function(names[names.len() - 1].to_string(), &names[0..(names.len() - 1)].to_vec(), storage_index);
                         match function_search {
-                            Some(reference) => options
-                                .storages
-                                .get_mut(storage_index)
-                                .unwrap()
-                                .add_constant(Rc::new(KaramelPrimative::Function(reference, None))),
+                            Some(reference) => {
+                                options.capabilities.check_function(&reference)?;
+                                options
+                                    .storages
+                                    .get_mut(storage_index)
+                                    .unwrap()
+                                    .add_constant(Rc::new(KaramelPrimative::Function(reference, None)))
+                            }
                             None => return Err(KaramelErrorType::FunctionNotFound(names[names.len() - 1].to_string())),
                         };
                     }
@@ -187,13 +201,22 @@ impl StorageBuilder {
                     .add_constant(Rc::clone(primative));
             }
 
-            KaramelAstType::List(list) => {
+            KaramelAstType::List(list) | KaramelAstType::Tuple(list) => {
                 for array_item in list {
                     self.build(module.clone(), array_item, ast, options, storage_index)?;
                 }
                 return Ok(());
             }
 
+            KaramelAstType::NamedArgument { name, expression } => {
+                options
+                    .storages
+                    .get_mut(storage_index)
+                    .unwrap()
+                    .add_constant(Rc::new(KaramelPrimative::Text(Rc::new(name.to_string()))));
+                self.build(module.clone(), expression, ast, options, storage_index)?;
+            }
+
             KaramelAstType::Dict(dict) => {
                 for dict_item in dict {
                     options
@@ -229,6 +252,27 @@ impl StorageBuilder {
                 }
             }
 
+            KaramelAstType::Match { value, variable, cases } => {
+                self.build(module.clone(), value, ast, options, storage_index)?;
+                options
+                    .storages
+                    .get_mut(storage_index)
+                    .unwrap()
+                    .add_variable(variable);
+
+                for case in cases {
+                    for pattern in case.patterns.iter() {
+                        self.build_pattern(pattern, options, storage_index);
+                    }
+
+                    if let Some(guard) = &case.guard {
+                        self.build(module.clone(), guard, ast, options, storage_index)?;
+                    }
+
+                    self.build(module.clone(), &case.body, ast, options, storage_index)?;
+                }
+            }
+
             KaramelAstType::None => {
                 options
                     .storages
@@ -240,4 +284,54 @@ impl StorageBuilder {
         };
         Ok(())
     }
+
+    fn build_pattern(&self, pattern: &KaramelMatchPattern, options: &mut KaramelCompilerContext, storage_index: usize) {
+        match pattern {
+            KaramelMatchPattern::Wildcard => (),
+            KaramelMatchPattern::Literal(primative) => {
+                options
+                    .storages
+                    .get_mut(storage_index)
+                    .unwrap()
+                    .add_constant(primative.clone());
+            }
+            KaramelMatchPattern::Binding(name) => {
+                options
+                    .storages
+                    .get_mut(storage_index)
+                    .unwrap()
+                    .add_variable(name);
+            }
+            KaramelMatchPattern::List { items, rest } => {
+                for (index, item) in items.iter().enumerate() {
+                    options
+                        .storages
+                        .get_mut(storage_index)
+                        .unwrap()
+                        .add_constant(Rc::new(KaramelPrimative::Integer(index as i64)));
+                    self.build_pattern(item, options, storage_index);
+                }
+
+                if let Some(rest) = rest {
+                    if rest != "_" {
+                        options
+                            .storages
+                            .get_mut(storage_index)
+                            .unwrap()
+                            .add_variable(rest);
+                    }
+                }
+            }
+            KaramelMatchPattern::Dict(items) => {
+                for (key, item) in items.iter() {
+                    options
+                        .storages
+                        .get_mut(storage_index)
+                        .unwrap()
+                        .add_constant(key.clone());
+                    self.build_pattern(item, options, storage_index);
+                }
+            }
+        };
+    }
 }
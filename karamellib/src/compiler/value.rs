@@ -6,14 +6,25 @@ use std::mem::ManuallyDrop;
 use std::rc::Rc;
 use std::vec::Vec;
 
+use bigdecimal::BigDecimal;
+use indexmap::IndexSet;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use regex::Regex;
+
+use crate::buildin::date::{format_duration, DateParts, DEFAULT_DATE_FORMAT};
 use crate::compiler::function::FunctionReference;
+use crate::compiler::number::KaramelNumber;
 use crate::compiler::GetType;
 use crate::{buildin::Class, types::*};
 
-pub const EMPTY_OBJECT: VmObject = VmObject(QNAN | EMPTY_FLAG);
-pub const TRUE_OBJECT: VmObject = VmObject(QNAN | TRUE_FLAG);
-pub const FALSE_OBJECT: VmObject = VmObject(QNAN | FALSE_FLAG);
-pub static EMPTY_PRIMATIVE: KaramelPrimative = KaramelPrimative::Empty;
+pub const EMPTY_OBJECT: VmObject = VmObject::from_bits(QNAN | EMPTY_FLAG);
+pub const TRUE_OBJECT: VmObject = VmObject::from_bits(QNAN | TRUE_FLAG);
+pub const FALSE_OBJECT: VmObject = VmObject::from_bits(QNAN | FALSE_FLAG);
+pub const EMPTY_PRIMATIVE: KaramelPrimative = KaramelPrimative::Empty;
+
+/* Floating numbers bigger than 2^53 could not keep all integer digits */
+const MAX_EXACT_FLOAT_INTEGER: f64 = 9_007_199_254_740_992.0;
 
 #[repr(C)]
 #[derive(Clone, Default)]
@@ -23,38 +34,180 @@ pub enum KaramelPrimative {
     Number(f64),
     Bool(bool),
     List(RefCell<Vec<VmObject>>),
-    Dict(RefCell<HashMap<String, VmObject>>),
+    Dict(RefCell<HashMap<KaramelDictKey, VmObject>>),
     Text(Rc<String>),
     Function(Rc<FunctionReference>, Option<VmObject>),
     Class(Rc<dyn Class>),
+    Tuple(Vec<VmObject>),
+    Integer(i64),
+    BigInteger(BigInt),
+    Decimal(BigDecimal),
+    Fraction(BigRational),
+    Set(RefCell<IndexSet<KaramelDictKey>>),
+    /// Milliseconds since 1970-01-01 00:00:00, without time zone
+    Date(i64),
+    /// Milliseconds
+    Duration(i64),
+    /// Compiled regular expression
+    Pattern(Rc<Regex>),
+}
+
+/// Hashable dictionary key. Text and tuple values could be used as a dictionary key, tuple items could be any immutable value.
+/// Set items stored with the same key, so any immutable value could be added to a set.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum KaramelDictKey {
+    Empty,
+    Number(u64),
+    Integer(i64),
+    BigInteger(BigInt),
+    Fraction(BigRational),
+    Bool(bool),
+    Text(Rc<String>),
+    Tuple(Vec<KaramelDictKey>),
+    Date(i64),
+    Duration(i64),
+}
+
+impl KaramelDictKey {
+    pub fn new(primative: &KaramelPrimative) -> Option<KaramelDictKey> {
+        match primative {
+            KaramelPrimative::Text(_) | KaramelPrimative::Tuple(_) => KaramelDictKey::hashable(primative),
+            _ => None,
+        }
+    }
+
+    pub fn hashable(primative: &KaramelPrimative) -> Option<KaramelDictKey> {
+        match primative {
+            KaramelPrimative::Empty => Some(KaramelDictKey::Empty),
+            /* '-0.0' and '0.0' are the same key, '1.0' and '1' are the same key */
+            KaramelPrimative::Number(number) => match KaramelNumber::Float(*number).to_integer() {
+                Some(KaramelNumber::Integer(integer)) => Some(KaramelDictKey::Integer(integer)),
+                Some(KaramelNumber::BigInteger(integer)) => Some(KaramelDictKey::BigInteger(integer)),
+                _ => Some(KaramelDictKey::Number((number + 0.0).to_bits())),
+            },
+            KaramelPrimative::Integer(number) => Some(KaramelDictKey::Integer(*number)),
+            KaramelPrimative::BigInteger(number) => Some(KaramelDictKey::BigInteger(number.clone())),
+            /* Exact numbers share the key with the equal integer or floating number */
+            KaramelPrimative::Decimal(_) | KaramelPrimative::Fraction(_) => {
+                let number = KaramelNumber::from_primative(primative)?;
+                match (number.to_integer(), number.as_f64()) {
+                    (Some(integer), _) => KaramelDictKey::hashable(&integer.to_primative()),
+                    (None, float) if KaramelNumber::Float(float) == number => KaramelDictKey::hashable(&KaramelPrimative::Number(float)),
+                    _ => Some(KaramelDictKey::Fraction(number.to_rational())),
+                }
+            }
+            KaramelPrimative::Bool(value) => Some(KaramelDictKey::Bool(*value)),
+            KaramelPrimative::Text(text) => Some(KaramelDictKey::Text(text.clone())),
+            KaramelPrimative::Date(millis) => Some(KaramelDictKey::Date(*millis)),
+            KaramelPrimative::Duration(millis) => Some(KaramelDictKey::Duration(*millis)),
+            KaramelPrimative::Tuple(items) => items
+                .iter()
+                .map(|item| KaramelDictKey::hashable(&item.deref()))
+                .collect::<Option<Vec<KaramelDictKey>>>()
+                .map(KaramelDictKey::Tuple),
+            _ => None,
+        }
+    }
+
+    pub fn to_primative(&self) -> KaramelPrimative {
+        match self {
+            KaramelDictKey::Empty => KaramelPrimative::Empty,
+            KaramelDictKey::Number(number) => KaramelPrimative::Number(f64::from_bits(*number)),
+            KaramelDictKey::Integer(number) => KaramelPrimative::Integer(*number),
+            KaramelDictKey::BigInteger(number) => KaramelPrimative::BigInteger(number.clone()),
+            KaramelDictKey::Fraction(number) => KaramelPrimative::Fraction(number.clone()),
+            KaramelDictKey::Bool(value) => KaramelPrimative::Bool(*value),
+            KaramelDictKey::Text(text) => KaramelPrimative::Text(text.clone()),
+            KaramelDictKey::Date(millis) => KaramelPrimative::Date(*millis),
+            KaramelDictKey::Duration(millis) => KaramelPrimative::Duration(*millis),
+            KaramelDictKey::Tuple(items) => KaramelPrimative::Tuple(
+                items
+                    .iter()
+                    .map(|item| VmObject::native_convert(item.to_primative()))
+                    .collect(),
+            ),
+        }
+    }
 }
 
-unsafe impl Send for KaramelPrimative {}
-unsafe impl Sync for KaramelPrimative {}
+impl From<&str> for KaramelDictKey {
+    fn from(source: &str) -> Self {
+        KaramelDictKey::Text(Rc::new(source.to_string()))
+    }
+}
+
+impl From<String> for KaramelDictKey {
+    fn from(source: String) -> Self {
+        KaramelDictKey::Text(Rc::new(source))
+    }
+}
+
+impl fmt::Debug for KaramelDictKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KaramelDictKey::Text(text) => write!(f, "{:?}", text),
+            KaramelDictKey::Tuple(items) => {
+                write!(f, "(")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{:?}", item)?;
+                }
 
-unsafe impl Send for VmObject {}
-unsafe impl Sync for VmObject {}
+                match items.len() {
+                    1 => write!(f, ",)"),
+                    _ => write!(f, ")"),
+                }
+            }
+            _ => self.to_primative().format(f),
+        }
+    }
+}
 
 impl KaramelPrimative {
     pub fn format(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             KaramelPrimative::Empty => write!(f, "boş"),
             KaramelPrimative::Number(number) => {
-                if *number == (*number as u64) as f64 {
-                    write!(f, "{:?}", (*number as u64))
+                /* Floating numbers without fractional part printed like integer if they could be represented exactly */
+                if number.fract() == 0.0 && number.abs() <= MAX_EXACT_FLOAT_INTEGER {
+                    write!(f, "{}", *number as i64)
                 } else {
                     write!(f, "{:?}", number)
                 }
             }
+            KaramelPrimative::Integer(number) => write!(f, "{}", number),
+            KaramelPrimative::BigInteger(number) => write!(f, "{}", number),
+            KaramelPrimative::Decimal(number) => write!(f, "{}", number.to_plain_string()),
+            KaramelPrimative::Fraction(number) => write!(f, "{}", number),
             KaramelPrimative::Bool(b) => match b {
                 true => write!(f, "doğru"),
                 false => write!(f, "yanlış"),
             },
             KaramelPrimative::List(b) => write!(f, "{:?}", b.borrow()),
             KaramelPrimative::Dict(b) => write!(f, "{:?}", b.borrow()),
+            KaramelPrimative::Set(b) => write!(f, "{:?}", b.borrow()),
+            KaramelPrimative::Date(millis) => write!(f, "{}", DateParts::from_millis(*millis).format(DEFAULT_DATE_FORMAT)),
+            KaramelPrimative::Duration(millis) => write!(f, "{}", format_duration(*millis)),
+            KaramelPrimative::Pattern(pattern) => write!(f, "<Desen='{}'>", pattern.as_str()),
             KaramelPrimative::Text(b) => write!(f, "\"{}\"", b),
             KaramelPrimative::Function(func, _) => write!(f, "<Fonksiyon='{}'>", func.name),
             KaramelPrimative::Class(class) => write!(f, "<Sınıf='{}'>", class.get_type()),
+            KaramelPrimative::Tuple(items) => {
+                write!(f, "(")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{:?}", item)?;
+                }
+
+                match items.len() {
+                    1 => write!(f, ",)"),
+                    _ => write!(f, ")"),
+                }
+            }
         }
     }
 
@@ -62,12 +215,21 @@ impl KaramelPrimative {
         match self {
             KaramelPrimative::Text(value) => !value.is_empty(),
             KaramelPrimative::Number(value) => *value > 0.0,
+            KaramelPrimative::Integer(value) => *value > 0,
+            KaramelPrimative::BigInteger(value) => KaramelNumber::BigInteger(value.clone()).is_positive(),
+            KaramelPrimative::Decimal(value) => KaramelNumber::Decimal(value.clone()).is_positive(),
+            KaramelPrimative::Fraction(value) => KaramelNumber::Fraction(value.clone()).is_positive(),
             KaramelPrimative::Bool(value) => *value,
             KaramelPrimative::List(items) => !items.borrow().is_empty(),
             KaramelPrimative::Dict(items) => !items.borrow().is_empty(),
+            KaramelPrimative::Set(items) => !items.borrow().is_empty(),
+            KaramelPrimative::Date(_) => true,
+            KaramelPrimative::Duration(millis) => *millis != 0,
+            KaramelPrimative::Pattern(_) => true,
             KaramelPrimative::Empty => false,
             KaramelPrimative::Function(_, _) => true,
             KaramelPrimative::Class(_) => true,
+            KaramelPrimative::Tuple(items) => !items.is_empty(),
         }
     }
 
@@ -78,9 +240,14 @@ impl KaramelPrimative {
         }
     }
 
+    /// Float value of the any numeric type.
+    pub fn as_f64(&self) -> Option<f64> {
+        KaramelNumber::from_primative(self).map(|number| number.as_f64())
+    }
+
     pub fn discriminant(&self) -> usize {
         match self {
-            KaramelPrimative::Number(_) => 0,
+            KaramelPrimative::Number(_) | KaramelPrimative::Integer(_) | KaramelPrimative::BigInteger(_) | KaramelPrimative::Decimal(_) | KaramelPrimative::Fraction(_) => 0,
             KaramelPrimative::Text(_) => 1,
             KaramelPrimative::List(_) => 2,
             KaramelPrimative::Dict(_) => 3,
@@ -89,6 +256,11 @@ impl KaramelPrimative {
             KaramelPrimative::Bool(_) => 5,
             KaramelPrimative::Function(_, _) => 6,
             KaramelPrimative::Class(_) => 7,
+            KaramelPrimative::Tuple(_) => 8,
+            KaramelPrimative::Set(_) => 9,
+            KaramelPrimative::Date(_) => 10,
+            KaramelPrimative::Duration(_) => 11,
+            KaramelPrimative::Pattern(_) => 12,
         }
     }
 }
@@ -97,32 +269,45 @@ impl GetType for KaramelPrimative {
     fn get_type(&self) -> String {
         match self {
             KaramelPrimative::Text(_) => "yazı".to_string(),
-            KaramelPrimative::Number(_) => "sayı".to_string(),
+            KaramelPrimative::Number(_) | KaramelPrimative::Integer(_) | KaramelPrimative::BigInteger(_) => "sayı".to_string(),
+            KaramelPrimative::Decimal(_) => "ondalık".to_string(),
+            KaramelPrimative::Fraction(_) => "kesir".to_string(),
             KaramelPrimative::Bool(_) => "bool".to_string(),
             KaramelPrimative::List(_) => "liste".to_string(),
             KaramelPrimative::Dict(_) => "sözlük".to_string(),
             KaramelPrimative::Empty => "boş".to_string(),
             KaramelPrimative::Function(_, _) => "fonksiyon".to_string(),
             KaramelPrimative::Class(_) => "sınıf".to_string(),
+            KaramelPrimative::Tuple(_) => "demet".to_string(),
+            KaramelPrimative::Set(_) => "küme".to_string(),
+            KaramelPrimative::Date(_) => "tarih".to_string(),
+            KaramelPrimative::Duration(_) => "süre".to_string(),
+            KaramelPrimative::Pattern(_) => "desen".to_string(),
         }
     }
 }
 
 impl From<f64> for VmObject {
     fn from(number: f64) -> Self {
-        VmObject(number.to_bits())
+        VmObject::from_bits(number.to_bits())
     }
 }
 
 impl From<i64> for VmObject {
     fn from(number: i64) -> Self {
-        VmObject((number as f64).to_bits())
+        match (SMALL_INTEGER_MIN..=SMALL_INTEGER_MAX).contains(&number) {
+            true => VmObject::from_bits(INTEGER_FLAG | (number as u64 & INTEGER_MASK)),
+            false => VmObject::native_convert(KaramelPrimative::Integer(number)),
+        }
     }
 }
 
 impl From<usize> for VmObject {
     fn from(number: usize) -> Self {
-        VmObject((number as f64).to_bits())
+        match i64::try_from(number) {
+            Ok(number) => VmObject::from(number),
+            Err(_) => VmObject::native_convert(KaramelPrimative::BigInteger(BigInt::from(number))),
+        }
     }
 }
 
@@ -161,6 +346,16 @@ impl From<Rc<KaramelPrimative>> for VmObject {
 
 impl From<HashMap<String, VmObject>> for VmObject {
     fn from(source: HashMap<String, VmObject>) -> Self {
+        let dict = source
+            .into_iter()
+            .map(|(key, value)| (KaramelDictKey::from(key), value))
+            .collect::<HashMap<KaramelDictKey, VmObject>>();
+        VmObject::from(dict)
+    }
+}
+
+impl From<HashMap<KaramelDictKey, VmObject>> for VmObject {
+    fn from(source: HashMap<KaramelDictKey, VmObject>) -> Self {
         VmObject::convert(Rc::new(KaramelPrimative::Dict(RefCell::new(source))))
     }
 }
@@ -207,6 +402,9 @@ impl PartialEq for KaramelPrimative {
                     n == m
                 }
             }
+            (KaramelPrimative::Number(_) | KaramelPrimative::Integer(_) | KaramelPrimative::BigInteger(_) | KaramelPrimative::Decimal(_) | KaramelPrimative::Fraction(_), _) if self.as_f64().is_some() && other.as_f64().is_some() => {
+                KaramelNumber::from_primative(self) == KaramelNumber::from_primative(other)
+            }
             (KaramelPrimative::Text(lvalue), KaramelPrimative::Text(rvalue)) => lvalue == rvalue,
             (KaramelPrimative::List(l_value), KaramelPrimative::List(r_value)) => {
                 if (*l_value).borrow().len() != (*r_value).borrow().len() {
@@ -227,6 +425,13 @@ impl PartialEq for KaramelPrimative {
                 true
             }
             (KaramelPrimative::Class(l_value), KaramelPrimative::Class(r_value)) => l_value.get_type() == r_value.get_type(),
+            (KaramelPrimative::Tuple(l_value), KaramelPrimative::Tuple(r_value)) => {
+                l_value.len() == r_value.len()
+                    && l_value
+                        .iter()
+                        .zip(r_value.iter())
+                        .all(|(left, right)| left.deref() == right.deref())
+            }
             (KaramelPrimative::Dict(l_value), KaramelPrimative::Dict(r_value)) => {
                 if (*l_value).borrow().len() != (*r_value).borrow().len() {
                     return false;
@@ -244,6 +449,14 @@ impl PartialEq for KaramelPrimative {
                 }
                 true
             }
+            (KaramelPrimative::Date(l_value), KaramelPrimative::Date(r_value)) => l_value == r_value,
+            (KaramelPrimative::Duration(l_value), KaramelPrimative::Duration(r_value)) => l_value == r_value,
+            (KaramelPrimative::Pattern(l_value), KaramelPrimative::Pattern(r_value)) => l_value.as_str() == r_value.as_str(),
+            /* Item order is not important for sets */
+            (KaramelPrimative::Set(l_value), KaramelPrimative::Set(r_value)) => {
+                let (l_value, r_value) = (l_value.borrow(), r_value.borrow());
+                l_value.len() == r_value.len() && l_value.is_subset(&r_value)
+            }
             _ => false,
         }
     }
@@ -253,33 +466,36 @@ impl VmObject {
     #[inline]
     pub fn convert(primative: Rc<KaramelPrimative>) -> VmObject {
         match *primative {
-            KaramelPrimative::Empty => VmObject(QNAN | EMPTY_FLAG),
-            KaramelPrimative::Number(number) => VmObject(number.to_bits()),
+            KaramelPrimative::Empty => VmObject::from_bits(QNAN | EMPTY_FLAG),
+            KaramelPrimative::Number(number) => VmObject::from_bits(number.to_bits()),
+            KaramelPrimative::Integer(number) if (SMALL_INTEGER_MIN..=SMALL_INTEGER_MAX).contains(&number) => VmObject::from(number),
             KaramelPrimative::Bool(true) => TRUE_OBJECT,
             KaramelPrimative::Bool(false) => FALSE_OBJECT,
-            _ => VmObject(QNAN | POINTER_FLAG | (POINTER_MASK & (Rc::into_raw(primative)) as u64)),
+            _ => VmObject::from_bits(QNAN | POINTER_FLAG | (POINTER_MASK & (Rc::into_raw(primative)) as u64)),
         }
     }
 
     #[inline]
     pub fn native_convert<T: Borrow<KaramelPrimative>>(primative: T) -> VmObject {
         match primative.borrow() {
-            KaramelPrimative::Empty => VmObject(QNAN | EMPTY_FLAG),
-            KaramelPrimative::Number(number) => VmObject(number.to_bits()),
+            KaramelPrimative::Empty => VmObject::from_bits(QNAN | EMPTY_FLAG),
+            KaramelPrimative::Number(number) => VmObject::from_bits(number.to_bits()),
+            KaramelPrimative::Integer(number) if (SMALL_INTEGER_MIN..=SMALL_INTEGER_MAX).contains(number) => VmObject::from(*number),
             KaramelPrimative::Bool(true) => TRUE_OBJECT,
             KaramelPrimative::Bool(false) => FALSE_OBJECT,
-            _ => VmObject(QNAN | POINTER_FLAG | (POINTER_MASK & (Rc::into_raw(Rc::new(primative))) as u64)),
+            _ => VmObject::from_bits(QNAN | POINTER_FLAG | (POINTER_MASK & (Rc::into_raw(Rc::new(primative))) as u64)),
         }
     }
 
     #[inline]
     pub fn native_convert_by_ref(primative: Rc<KaramelPrimative>) -> VmObject {
         match &*primative {
-            KaramelPrimative::Empty => VmObject(QNAN | EMPTY_FLAG),
-            KaramelPrimative::Number(number) => VmObject(number.to_bits()),
+            KaramelPrimative::Empty => VmObject::from_bits(QNAN | EMPTY_FLAG),
+            KaramelPrimative::Number(number) => VmObject::from_bits(number.to_bits()),
+            KaramelPrimative::Integer(number) if (SMALL_INTEGER_MIN..=SMALL_INTEGER_MAX).contains(number) => VmObject::from(*number),
             KaramelPrimative::Bool(true) => TRUE_OBJECT,
             KaramelPrimative::Bool(false) => FALSE_OBJECT,
-            _ => VmObject(QNAN | POINTER_FLAG | (POINTER_MASK & (Rc::into_raw(primative)) as u64)),
+            _ => VmObject::from_bits(QNAN | POINTER_FLAG | (POINTER_MASK & (Rc::into_raw(primative)) as u64)),
         }
     }
 
@@ -287,6 +503,7 @@ impl VmObject {
     pub fn deref(&self) -> Rc<KaramelPrimative> {
         match self.0 {
             n if (n & QNAN) != QNAN => Rc::new(KaramelPrimative::Number(f64::from_bits(n))),
+            i if (i & (POINTER_FLAG | INTEGER_FLAG)) == INTEGER_FLAG => Rc::new(KaramelPrimative::Integer(self.small_integer())),
             e if e == (QNAN | EMPTY_FLAG) => Rc::new(KaramelPrimative::Empty),
             f if f == (QNAN | FALSE_FLAG) => Rc::new(KaramelPrimative::Bool(false)),
             t if t == (QNAN | TRUE_FLAG) => Rc::new(KaramelPrimative::Bool(true)),
@@ -303,6 +520,7 @@ impl VmObject {
     pub fn deref_clean(&self) -> KaramelPrimative {
         match self.0 {
             n if (n & QNAN) != QNAN => KaramelPrimative::Number(f64::from_bits(n)),
+            i if (i & (POINTER_FLAG | INTEGER_FLAG)) == INTEGER_FLAG => KaramelPrimative::Integer(self.small_integer()),
             e if e == (QNAN | EMPTY_FLAG) => KaramelPrimative::Empty,
             f if f == (QNAN | FALSE_FLAG) => KaramelPrimative::Bool(false),
             t if t == (QNAN | TRUE_FLAG) => KaramelPrimative::Bool(true),
@@ -315,6 +533,15 @@ impl VmObject {
                     KaramelPrimative::Dict(dict) => KaramelPrimative::Dict(dict.clone()),
                     KaramelPrimative::Function(func, base) => KaramelPrimative::Function(func.clone(), *base),
                     KaramelPrimative::Class(klass) => KaramelPrimative::Class(klass.clone()),
+                    KaramelPrimative::Tuple(items) => KaramelPrimative::Tuple(items.clone()),
+                    KaramelPrimative::Integer(number) => KaramelPrimative::Integer(*number),
+                    KaramelPrimative::BigInteger(number) => KaramelPrimative::BigInteger(number.clone()),
+                    KaramelPrimative::Decimal(number) => KaramelPrimative::Decimal(number.clone()),
+                    KaramelPrimative::Fraction(number) => KaramelPrimative::Fraction(number.clone()),
+                    KaramelPrimative::Set(items) => KaramelPrimative::Set(items.clone()),
+                    KaramelPrimative::Date(millis) => KaramelPrimative::Date(*millis),
+                    KaramelPrimative::Duration(millis) => KaramelPrimative::Duration(*millis),
+                    KaramelPrimative::Pattern(pattern) => KaramelPrimative::Pattern(pattern.clone()),
                     _ => KaramelPrimative::Empty,
                 }
             }
@@ -329,4 +556,21 @@ impl VmObject {
             false => None,
         }
     }
+
+    #[inline]
+    fn small_integer(&self) -> i64 {
+        /* Restore sign of the 48 bit integer */
+        (((self.0 & INTEGER_MASK) << 16) as i64) >> 16
+    }
+
+    /// Numeric view of the object, integers kept exact.
+    #[inline]
+    pub fn as_numeric(&self) -> Option<KaramelNumber> {
+        match self.0 {
+            n if (n & QNAN) != QNAN => Some(KaramelNumber::Float(f64::from_bits(n))),
+            i if (i & (POINTER_FLAG | INTEGER_FLAG)) == INTEGER_FLAG => Some(KaramelNumber::Integer(self.small_integer())),
+            p if (p & POINTER_FLAG) == POINTER_FLAG => KaramelNumber::from_primative(&self.deref()),
+            _ => None,
+        }
+    }
 }
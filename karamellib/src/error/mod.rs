@@ -232,6 +232,200 @@ pub enum KaramelErrorType {
     #[error("Öperatör geçerli değil")]
     #[strum(message = "153")]
     OperatorNotValid,
+
+    #[error("'{0:?}' içinde arama yapılamaz")]
+    #[strum(message = "154")]
+    MembershipNotSupported(Rc<KaramelPrimative>),
+
+    #[error("Eşleştirilecek değer bulunamadı")]
+    #[strum(message = "155")]
+    MatchValueNotFound,
+
+    #[error("Eşleştirme durumu bulunamadı")]
+    #[strum(message = "156")]
+    MatchCaseNotFound,
+
+    #[error("Eşleştirme deseni geçerli değil")]
+    #[strum(message = "157")]
+    InvalidMatchPattern,
+
+    #[error("Eşleştirme durumunun gövdesi eksik")]
+    #[strum(message = "158")]
+    MatchCaseBodyNotFound,
+
+    #[error("Parametrenin varsayılan değeri sabit bir değer olmalı")]
+    #[strum(message = "159")]
+    ArgumentDefaultValueNotValid,
+
+    #[error("'{0}' parametresinin varsayılan değeri olmalı")]
+    #[strum(message = "160")]
+    ArgumentDefaultValueRequired(String),
+
+    #[error("'*' ile tanımlanan parametre en sonda olmalı")]
+    #[strum(message = "161")]
+    VariadicArgumentMustBeLast,
+
+    #[error("'{0}' parametresi birden fazla tanımlanmış")]
+    #[strum(message = "162")]
+    ArgumentAlreadyDefined(String),
+
+    #[error("İsimli parametreden sonra sıralı parametre kullanılamaz")]
+    #[strum(message = "163")]
+    PositionalArgumentAfterNamedArgument,
+
+    #[error("'{0}' isimli parametresi birden fazla kullanılmış")]
+    #[strum(message = "164")]
+    NamedArgumentAlreadyUsed(String),
+
+    #[error("'{function}' fonksiyonunda '{argument}' isimli parametre bulunamadı")]
+    #[strum(message = "165")]
+    FunctionArgumentNotFound { function: String, argument: String },
+
+    #[error("'{function}' fonksiyonunun '{argument}' parametresine birden fazla değer verilmiş")]
+    #[strum(message = "166")]
+    FunctionArgumentAlreadyAssigned { function: String, argument: String },
+
+    #[error("'{function}' fonksiyonunun '{argument}' parametresi eksik")]
+    #[strum(message = "167")]
+    FunctionArgumentMissing { function: String, argument: String },
+
+    #[error("'{0}' fonksiyonu isimli parametre desteklemiyor")]
+    #[strum(message = "168")]
+    NamedArgumentsNotSupported(String),
+
+    #[error("Atama yapılacak hedef geçerli değil")]
+    #[strum(message = "169")]
+    InvalidAssignmentTarget,
+
+    #[error("'{0:?}' parçalanarak atanamaz")]
+    #[strum(message = "170")]
+    DestructuringNotSupported(Rc<KaramelPrimative>),
+
+    #[error("Parçalanarak atama için {expected} adet değer beklenirken {found} adet bulundu")]
+    #[strum(message = "171")]
+    DestructuringSizeNotMatching { expected: usize, found: usize },
+
+    #[error("'{0}' anahtarı sözlükte bulunamadı")]
+    #[strum(message = "172")]
+    DestructuringKeyNotFound(String),
+
+    #[error("Demet değiştirilemez")]
+    #[strum(message = "173")]
+    TupleNotMutable,
+
+    #[error("'{0:?}' sözlük anahtarı olarak kullanılamaz")]
+    #[strum(message = "174")]
+    DictKeyNotValid(Rc<KaramelPrimative>),
+
+    #[error("'{0:?}' bit işleminde kullanılamaz, tam sayı olması gerekiyor")]
+    #[strum(message = "175")]
+    IntegerExpected(Rc<KaramelPrimative>),
+
+    #[error("'{0}' geçerli bir kaydırma miktarı değil, 0 ile 1048576 arasında olması gerekiyor")]
+    #[strum(message = "176")]
+    ShiftAmountNotValid(i64),
+
+    #[error("Sıfıra bölme yapılamaz")]
+    #[strum(message = "177")]
+    DivisionByZero,
+
+    #[error("'{0:?}' kümeye eklenemez")]
+    #[strum(message = "178")]
+    SetItemNotValid(Rc<KaramelPrimative>),
+
+    #[error("Geçersiz tarih")]
+    #[strum(message = "179")]
+    DateNotValid,
+
+    #[error("'{0}' tarih olarak okunamadı")]
+    #[strum(message = "180")]
+    DateParseFailed(String),
+
+    #[error("Dosya sistemine erişim izni verilmedi")]
+    #[strum(message = "181")]
+    FileAccessNotAllowed,
+
+    #[error("'{filename}' yazılamadı. Hata: '{error}'")]
+    #[strum(message = "182")]
+    FileWriteError { filename: String, error: String },
+
+    #[error("JSON okunamadı ({line}. satır, {column}. sütun): {message}")]
+    #[strum(message = "183")]
+    JsonParseFailed { message: String, line: usize, column: usize },
+
+    #[error("'{0}' JSON olarak kodlanamaz")]
+    #[strum(message = "184")]
+    JsonEncodeFailed(String),
+
+    #[error("'{pattern}' deseni geçersiz. Hata: '{error}'")]
+    #[strum(message = "185")]
+    PatternNotValid { pattern: String, error: String },
+
+    #[error("'{function}' fonksiyonunun dönüş değeri {expected} değil")]
+    #[strum(message = "186")]
+    FunctionReturnTypeNotMatching { function: String, expected: String },
+
+    #[error("Fonksiyon çağırmadan önce program çalıştırılmalı")]
+    #[strum(message = "187")]
+    ScriptNotExecuted,
+
+    #[error("Program daha önce çalıştırıldı")]
+    #[strum(message = "188")]
+    ScriptAlreadyExecuted,
+
+    #[error("Fonksiyon çağırmak için çalışma ortamı bulunamadı")]
+    #[strum(message = "189")]
+    ContextNotAvailable,
+
+    #[error("'{0}' modülünü kullanma izni verilmedi")]
+    #[strum(message = "190")]
+    ModuleNotAllowed(String),
+
+    #[error("'{module}::{function}' fonksiyonunu kullanma izni verilmedi")]
+    #[strum(message = "191")]
+    FunctionNotAllowed { module: String, function: String },
+
+    #[error("Girdi okuma izni verilmedi")]
+    #[strum(message = "192")]
+    StdinNotAllowed,
+
+    #[error("'{0}' dosyasını yükleme izni verilmedi")]
+    #[strum(message = "193")]
+    LoadNotAllowed(String),
+
+    #[error("'{0}' tipindeki değerler dönüştürülemez")]
+    #[strum(message = "194")]
+    ValueNotSerializable(String),
+
+    #[error("Değer dönüştürülemedi: {0}")]
+    #[strum(message = "195")]
+    SerializationFailed(String),
+
+    #[error("İç içe fonksiyon çağrısı sınırı aşıldı")]
+    #[strum(message = "196")]
+    CallDepthExceeded,
+
+    #[error("Üs alma işleminin sonucu çok büyük, sonuç en fazla {0} bit olabilir")]
+    #[strum(message = "197")]
+    PowerResultTooLarge(u64),
+}
+
+#[derive(Clone, Debug, PartialEq, Error)]
+pub enum KaramelWarningType {
+    #[error("'eşleştir' tüm durumları kapsamıyor, '_' durumu eklenmeli")]
+    NonExhaustiveMatch,
+
+    #[error("'eşleştir' içerisinde ki {0}. durum hiçbir zaman çalıştırılmayacak")]
+    UnreachableMatchCase(usize),
+}
+
+impl KaramelErrorType {
+    /// Number of the error that documented at 'hata_kodlari.md'.
+    pub fn code(&self) -> i32 {
+        self.get_message()
+            .and_then(|code| code.parse().ok())
+            .unwrap_or_default()
+    }
 }
 
 impl From<KaramelErrorType> for KaramelError {
@@ -286,12 +480,12 @@ mod test {
         for error_enum in super::KaramelErrorType::iter() {
             let error_message = format!("{}", error_enum);
             if error_enum != KaramelErrorType::GeneralError("".to_string()) && error_message.is_empty() {
-                assert!(false, "'{:?}' hata mesaji yok", error_enum)
+                panic!("'{:?}' hata mesaji yok", error_enum)
             }
 
             match error_enum.get_message() {
                 Some(error_message) => assert!(!error_message.is_empty(), "'{:?}' sira numarasi bos olamaz", error_enum),
-                None => assert!(false, "{} sira numarasi yok", error_enum),
+                None => panic!("{} sira numarasi yok", error_enum),
             }
         }
     }
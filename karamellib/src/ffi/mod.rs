@@ -0,0 +1,573 @@
+//! C interface of the library, declarations are at 'include/karamel.h'.
+//!
+//! Handles are created and destroyed only by the functions of this module. Pointers that given to
+//! the functions should be either null or created by this module, texts should be null terminated UTF-8.
+#![allow(clippy::missing_safety_doc)]
+
+use std::collections::HashMap;
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use std::rc::Rc;
+
+use crate::buildin::builder::ModuleBuilder;
+use crate::compiler::convert::FromKaramel;
+use crate::compiler::function::FunctionParameter;
+use crate::compiler::KaramelPrimative;
+use crate::error::{KaramelError, KaramelErrorType};
+use crate::io::BufferIo;
+use crate::types::VmObject;
+use crate::vm::engine::Engine;
+use crate::vm::executer::ExecutionSource;
+
+/// Returned instead of an error code when a required pointer is null.
+pub const KARAMEL_INVALID_ARGUMENT: i32 = -1;
+
+/// Returned when the library panics, the engine should not be used after it.
+pub const KARAMEL_INTERNAL_ERROR: i32 = -2;
+
+/// C function that called by the script. Arguments are owned by Karamel and valid only during the call,
+/// returned value should be newly created, it is owned by Karamel after the call and null means 'boş'.
+pub type KaramelFunction = unsafe extern "C" fn(arguments: *const *const KaramelValue, count: usize, user_data: *mut c_void) -> *mut KaramelValue;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KaramelValueType {
+    Empty = 0,
+    Bool = 1,
+    Number = 2,
+    Text = 3,
+    Error = 4,
+    Other = 5,
+}
+
+/// Value that passed between C and Karamel.
+pub enum KaramelValue {
+    Value(VmObject),
+    Error(String),
+}
+
+/// Engine with its output streams and the functions that registered before the execution.
+pub struct KaramelEngine {
+    engine: Engine,
+    io: Rc<BufferIo>,
+    modules: HashMap<String, ModuleBuilder>,
+    error: Option<KaramelErrorType>,
+    stdout: CString,
+    stderr: CString,
+    error_message: CString,
+}
+
+impl KaramelEngine {
+    fn set_error(&mut self, error: KaramelErrorType) -> i32 {
+        let code = error.code();
+        self.error = Some(error);
+        code
+    }
+
+    fn set_result<T>(&mut self, result: Result<T, KaramelError>) -> Result<T, i32> {
+        result.map_err(|error| self.set_error(error.error_type))
+    }
+
+    /// Functions are registered to the engine before the first execution.
+    fn add_modules(&mut self) {
+        for (_, builder) in self.modules.drain() {
+            self.engine.add_module(builder.build());
+        }
+    }
+
+    unsafe fn write_result(&mut self, response: Result<VmObject, KaramelError>, result: *mut *mut KaramelValue) -> i32 {
+        match self.set_result(response) {
+            Ok(value) => {
+                if !result.is_null() {
+                    *result = new_value(value);
+                }
+                0
+            }
+            Err(code) => code,
+        }
+    }
+}
+
+/// Panics should not unwind into the C code, fallback value is returned instead.
+fn guard<T>(fallback: T, function: impl FnOnce() -> T) -> T {
+    catch_unwind(AssertUnwindSafe(function)).unwrap_or(fallback)
+}
+
+fn to_c_string(text: String) -> CString {
+    CString::new(text.replace('\0', "")).unwrap_or_default()
+}
+
+unsafe fn from_c_string(text: *const c_char) -> Option<String> {
+    match text.is_null() {
+        true => None,
+        false => Some(CStr::from_ptr(text).to_string_lossy().to_string()),
+    }
+}
+
+fn new_value(value: VmObject) -> *mut KaramelValue {
+    Box::into_raw(Box::new(KaramelValue::Value(value)))
+}
+
+unsafe fn take_value(value: *mut KaramelValue, arguments: &[*const KaramelValue]) -> Result<VmObject, KaramelErrorType> {
+    if value.is_null() {
+        return Ok(VmObject::native_convert(KaramelPrimative::Empty));
+    }
+
+    /* Arguments are not allocated separately, returning one of them is not allowed but it should not be freed */
+    if arguments.contains(&(value as *const KaramelValue)) {
+        return Ok(value_object(value).unwrap_or_else(|| VmObject::native_convert(KaramelPrimative::Empty)));
+    }
+
+    match *Box::from_raw(value) {
+        KaramelValue::Value(value) => Ok(value),
+        KaramelValue::Error(message) => Err(KaramelErrorType::GeneralError(message)),
+    }
+}
+
+unsafe fn value_object(value: *const KaramelValue) -> Option<VmObject> {
+    match value.as_ref() {
+        Some(KaramelValue::Value(value)) => Some(*value),
+        _ => None,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn karamel_engine_new() -> *mut KaramelEngine {
+    guard(ptr::null_mut(), || unsafe { karamel_engine_with_input(ptr::null()) })
+}
+
+/// Every line of the input is read by one 'gç::satıroku' call.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_engine_with_input(input: *const c_char) -> *mut KaramelEngine {
+    guard(ptr::null_mut(), || {
+        let input = from_c_string(input).unwrap_or_default();
+        let io = Rc::new(BufferIo::with_input(&input));
+        let mut engine = Engine::new();
+        engine.set_io(io.clone());
+
+        Box::into_raw(Box::new(KaramelEngine {
+            engine,
+            io,
+            modules: HashMap::new(),
+            error: None,
+            stdout: CString::default(),
+            stderr: CString::default(),
+            error_message: CString::default(),
+        }))
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn karamel_engine_free(engine: *mut KaramelEngine) {
+    guard((), || {
+        if !engine.is_null() {
+            drop(Box::from_raw(engine));
+        }
+    })
+}
+
+/// Adds the C function as 'modül::isim', should be called before the execution.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_engine_register_function(engine: *mut KaramelEngine, module: *const c_char, name: *const c_char, function: Option<KaramelFunction>, user_data: *mut c_void) -> i32 {
+    guard(KARAMEL_INTERNAL_ERROR, || {
+        let (engine, module, name, function) = match (engine.as_mut(), from_c_string(module), from_c_string(name), function) {
+            (Some(engine), Some(module), Some(name), Some(function)) => (engine, module, name, function),
+            _ => return KARAMEL_INVALID_ARGUMENT,
+        };
+
+        if !engine.engine.context().main_module.is_null() {
+            return engine.set_error(KaramelErrorType::ScriptAlreadyExecuted);
+        }
+
+        let builder = engine
+            .modules
+            .remove(&module)
+            .unwrap_or_else(|| ModuleBuilder::new(module.to_string()));
+
+        let builder = builder.closure(name, move |parameter: FunctionParameter| {
+            let arguments = parameter
+                .iter()
+                .map(|argument| KaramelValue::Value(*argument))
+                .collect::<Vec<_>>();
+            let pointers = arguments
+                .iter()
+                .map(|argument| argument as *const KaramelValue)
+                .collect::<Vec<_>>();
+
+            take_value(function(pointers.as_ptr(), pointers.len(), user_data), &pointers)
+        });
+        engine.modules.insert(module, builder);
+        0
+    })
+}
+
+/// Executes the script, returns 0 or the error code.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_engine_execute(engine: *mut KaramelEngine, source: *const c_char) -> i32 {
+    guard(KARAMEL_INTERNAL_ERROR, || {
+        let (engine, source) = match (engine.as_mut(), from_c_string(source)) {
+            (Some(engine), Some(source)) => (engine, source),
+            _ => return KARAMEL_INVALID_ARGUMENT,
+        };
+
+        engine.add_modules();
+        engine.error = None;
+        let result = engine.engine.execute(ExecutionSource::Code(source));
+        engine
+            .set_result(result)
+            .map(|_| 0)
+            .unwrap_or_else(|code| code)
+    })
+}
+
+/// Calls the function of the executed script, the result is written to 'result' if it is not null.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_engine_call(engine: *mut KaramelEngine, name: *const c_char, arguments: *const *const KaramelValue, count: usize, result: *mut *mut KaramelValue) -> i32 {
+    guard(KARAMEL_INTERNAL_ERROR, || {
+        let (engine, name) = match (engine.as_mut(), from_c_string(name)) {
+            (Some(engine), Some(name)) => (engine, name),
+            _ => return KARAMEL_INVALID_ARGUMENT,
+        };
+
+        let arguments = match (arguments.is_null(), count) {
+            (_, 0) => Vec::new(),
+            (true, _) => return KARAMEL_INVALID_ARGUMENT,
+            (false, _) => match std::slice::from_raw_parts(arguments, count)
+                .iter()
+                .map(|argument| value_object(*argument))
+                .collect::<Option<Vec<_>>>()
+            {
+                Some(arguments) => arguments,
+                None => return KARAMEL_INVALID_ARGUMENT,
+            },
+        };
+
+        engine.error = None;
+        let response = engine.engine.call::<_, VmObject>(&name, arguments);
+        engine.write_result(response, result)
+    })
+}
+
+/// Runs the code with the variables and functions of the previous executions. Value of the last expression is
+/// written to 'result' if it is not null, 'boş' if the code ends with a statement.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_engine_eval(engine: *mut KaramelEngine, source: *const c_char, result: *mut *mut KaramelValue) -> i32 {
+    guard(KARAMEL_INTERNAL_ERROR, || {
+        let (engine, source) = match (engine.as_mut(), from_c_string(source)) {
+            (Some(engine), Some(source)) => (engine, source),
+            _ => return KARAMEL_INVALID_ARGUMENT,
+        };
+
+        engine.add_modules();
+        engine.error = None;
+        let response = engine.engine.eval(&source);
+        engine.write_result(response, result)
+    })
+}
+
+/// Output of the script. Text is owned by the engine and valid until the next call of this function.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_engine_stdout(engine: *mut KaramelEngine) -> *const c_char {
+    guard(ptr::null(), || match engine.as_mut() {
+        Some(engine) => {
+            engine.stdout = to_c_string(engine.io.stdout());
+            engine.stdout.as_ptr()
+        }
+        None => ptr::null(),
+    })
+}
+
+/// Error output of the script. Text is owned by the engine and valid until the next call of this function.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_engine_stderr(engine: *mut KaramelEngine) -> *const c_char {
+    guard(ptr::null(), || match engine.as_mut() {
+        Some(engine) => {
+            engine.stderr = to_c_string(engine.io.stderr());
+            engine.stderr.as_ptr()
+        }
+        None => ptr::null(),
+    })
+}
+
+/// Code of the last error, 0 if the last execution or call was successful.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_engine_error_code(engine: *const KaramelEngine) -> i32 {
+    guard(KARAMEL_INTERNAL_ERROR, || match engine.as_ref() {
+        Some(engine) => engine.error.as_ref().map_or(0, |error| error.code()),
+        None => KARAMEL_INVALID_ARGUMENT,
+    })
+}
+
+/// Message of the last error, empty if there is no error. Text is owned by the engine and valid until the next call of this function.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_engine_error_message(engine: *mut KaramelEngine) -> *const c_char {
+    guard(ptr::null(), || match engine.as_mut() {
+        Some(engine) => {
+            engine.error_message = to_c_string(
+                engine
+                    .error
+                    .as_ref()
+                    .map_or_else(String::new, |error| error.to_string()),
+            );
+            engine.error_message.as_ptr()
+        }
+        None => ptr::null(),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn karamel_value_empty() -> *mut KaramelValue {
+    guard(ptr::null_mut(), || new_value(VmObject::native_convert(KaramelPrimative::Empty)))
+}
+
+#[no_mangle]
+pub extern "C" fn karamel_value_bool(value: bool) -> *mut KaramelValue {
+    guard(ptr::null_mut(), || new_value(VmObject::from(value)))
+}
+
+#[no_mangle]
+pub extern "C" fn karamel_value_int(value: i64) -> *mut KaramelValue {
+    guard(ptr::null_mut(), || new_value(VmObject::from(value)))
+}
+
+#[no_mangle]
+pub extern "C" fn karamel_value_double(value: f64) -> *mut KaramelValue {
+    guard(ptr::null_mut(), || new_value(VmObject::from(value)))
+}
+
+/// Copies the text, null is converted to 'boş'.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_value_string(value: *const c_char) -> *mut KaramelValue {
+    guard(ptr::null_mut(), || match from_c_string(value) {
+        Some(value) => new_value(VmObject::from(value)),
+        None => karamel_value_empty(),
+    })
+}
+
+/// Returned from a C function to stop the script with the given message.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_value_error(message: *const c_char) -> *mut KaramelValue {
+    guard(ptr::null_mut(), || Box::into_raw(Box::new(KaramelValue::Error(from_c_string(message).unwrap_or_default()))))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn karamel_value_type(value: *const KaramelValue) -> KaramelValueType {
+    guard(KaramelValueType::Other, || match value.as_ref() {
+        Some(KaramelValue::Value(value)) => match &*value.deref() {
+            KaramelPrimative::Empty => KaramelValueType::Empty,
+            KaramelPrimative::Bool(_) => KaramelValueType::Bool,
+            KaramelPrimative::Number(_) | KaramelPrimative::Integer(_) | KaramelPrimative::BigInteger(_) | KaramelPrimative::Decimal(_) | KaramelPrimative::Fraction(_) => KaramelValueType::Number,
+            KaramelPrimative::Text(_) => KaramelValueType::Text,
+            _ => KaramelValueType::Other,
+        },
+        Some(KaramelValue::Error(_)) => KaramelValueType::Error,
+        None => KaramelValueType::Empty,
+    })
+}
+
+unsafe fn write_value<T: FromKaramel>(value: *const KaramelValue, output: *mut T) -> bool {
+    match (value_object(value).and_then(|value| T::from_karamel(&value)), output.is_null()) {
+        (Some(value), false) => {
+            *output = value;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Writes the integer to 'output', returns false if the value is not an integer.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_value_as_int(value: *const KaramelValue, output: *mut i64) -> bool {
+    guard(false, || write_value(value, output))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn karamel_value_as_double(value: *const KaramelValue, output: *mut f64) -> bool {
+    guard(false, || write_value(value, output))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn karamel_value_as_bool(value: *const KaramelValue, output: *mut bool) -> bool {
+    guard(false, || write_value(value, output))
+}
+
+/// Text of the value, other values are formatted like 'gç::yaz'. Returned text should be freed with 'karamel_string_free'.
+#[no_mangle]
+pub unsafe extern "C" fn karamel_value_to_string(value: *const KaramelValue) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        let text = match value.as_ref() {
+            Some(KaramelValue::Value(value)) => String::from_karamel(value).unwrap_or_else(|| value.deref().to_string()),
+            Some(KaramelValue::Error(message)) => message.to_string(),
+            None => return ptr::null_mut(),
+        };
+        to_c_string(text).into_raw()
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn karamel_value_free(value: *mut KaramelValue) {
+    guard((), || {
+        if !value.is_null() {
+            drop(Box::from_raw(value));
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn karamel_string_free(text: *mut c_char) {
+    guard((), || {
+        if !text.is_null() {
+            drop(CString::from_raw(text));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: *const c_char) -> String {
+        unsafe { CStr::from_ptr(value) }
+            .to_string_lossy()
+            .to_string()
+    }
+
+    unsafe extern "C" fn multiply(arguments: *const *const KaramelValue, count: usize, user_data: *mut c_void) -> *mut KaramelValue {
+        let mut value = 0;
+        if count != 1 || !karamel_value_as_int(*arguments, &mut value) {
+            let message = CString::new("sayı bekleniyor").unwrap();
+            return karamel_value_error(message.as_ptr());
+        }
+
+        *(user_data as *mut i64) += 1;
+        karamel_value_int(value * 3)
+    }
+
+    #[test]
+    fn test_execute_and_call() {
+        unsafe {
+            let mut calls = 0_i64;
+            let engine = karamel_engine_with_input(CString::new("Karamel").unwrap().as_ptr());
+            let (module, name) = (CString::new("c").unwrap(), CString::new("üçle").unwrap());
+            assert_eq!(karamel_engine_register_function(engine, module.as_ptr(), name.as_ptr(), Some(multiply), &mut calls as *mut i64 as *mut c_void), 0);
+
+            let source = CString::new("fonk topla(a, b): döndür c::üçle(a) + b\ngç::yaz(gç::satıroku())").unwrap();
+            assert_eq!(karamel_engine_execute(engine, source.as_ptr()), 0);
+            assert_eq!(text(karamel_engine_stdout(engine)), "\"Karamel\"");
+
+            let arguments = [karamel_value_int(2), karamel_value_double(0.5)];
+            let mut result = ptr::null_mut();
+            let function = CString::new("topla").unwrap();
+            assert_eq!(karamel_engine_call(engine, function.as_ptr(), arguments.as_ptr() as *const *const KaramelValue, 2, &mut result), 0);
+
+            let mut number = 0.0;
+            assert_eq!(karamel_value_type(result), KaramelValueType::Number);
+            assert!(karamel_value_as_double(result, &mut number));
+            assert_eq!(number, 6.5);
+            assert_eq!(calls, 1);
+
+            arguments
+                .iter()
+                .for_each(|argument| karamel_value_free(*argument));
+            karamel_value_free(result);
+            karamel_engine_free(engine);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        unsafe {
+            let engine = karamel_engine_new();
+            let (module, name) = (CString::new("c").unwrap(), CString::new("üçle").unwrap());
+            karamel_engine_register_function(engine, module.as_ptr(), name.as_ptr(), Some(multiply), ptr::null_mut());
+
+            let source = CString::new("c::üçle('a')").unwrap();
+            let code = karamel_engine_execute(engine, source.as_ptr());
+            assert_eq!(code, KaramelErrorType::GeneralError(String::new()).code());
+            assert_eq!(karamel_engine_error_code(engine), code);
+            assert_eq!(text(karamel_engine_error_message(engine)), "sayı bekleniyor");
+            assert!(text(karamel_engine_stderr(engine)).contains("sayı bekleniyor"));
+
+            let function = CString::new("yok").unwrap();
+            let code = karamel_engine_call(engine, function.as_ptr(), ptr::null(), 0, ptr::null_mut());
+            assert_eq!(code, KaramelErrorType::FunctionNotFound(String::new()).code());
+            assert_eq!(karamel_engine_register_function(engine, module.as_ptr(), name.as_ptr(), Some(multiply), ptr::null_mut()), KaramelErrorType::ScriptAlreadyExecuted.code());
+            assert_eq!(karamel_engine_execute(ptr::null_mut(), source.as_ptr()), KARAMEL_INVALID_ARGUMENT);
+            karamel_engine_free(engine);
+        }
+    }
+
+    unsafe extern "C" fn first(arguments: *const *const KaramelValue, _: usize, _: *mut c_void) -> *mut KaramelValue {
+        *arguments as *mut KaramelValue
+    }
+
+    #[test]
+    fn test_eval() {
+        unsafe {
+            let engine = karamel_engine_new();
+            let (module, name) = (CString::new("c").unwrap(), CString::new("ilk").unwrap());
+            karamel_engine_register_function(engine, module.as_ptr(), name.as_ptr(), Some(first), ptr::null_mut());
+
+            let mut result = ptr::null_mut();
+            let source = CString::new("a = c::ilk(20)").unwrap();
+            assert_eq!(karamel_engine_eval(engine, source.as_ptr(), &mut result), 0);
+            assert_eq!(karamel_value_type(result), KaramelValueType::Empty);
+            karamel_value_free(result);
+
+            let (source, mut number) = (CString::new("a + 1").unwrap(), 0);
+            assert_eq!(karamel_engine_eval(engine, source.as_ptr(), &mut result), 0);
+            assert!(karamel_value_as_int(result, &mut number));
+            assert_eq!(number, 21);
+            karamel_value_free(result);
+
+            /* Promoted numbers are still numbers, they are converted by the getters */
+            let mut double = 0.0;
+            for (code, expected) in [("2 ** 70", 2_f64.powi(70)), ("sayı::ondalık('0.75')", 0.75), ("sayı::kesir(1, 4)", 0.25)] {
+                let source = CString::new(code).unwrap();
+                assert_eq!(karamel_engine_eval(engine, source.as_ptr(), &mut result), 0);
+                assert_eq!(karamel_value_type(result), KaramelValueType::Number);
+                assert!(karamel_value_as_double(result, &mut double));
+                assert_eq!(double, expected);
+                karamel_value_free(result);
+            }
+
+            let source = CString::new("a +").unwrap();
+            assert_ne!(karamel_engine_eval(engine, source.as_ptr(), ptr::null_mut()), 0);
+            assert_eq!(karamel_engine_eval(engine, ptr::null(), ptr::null_mut()), KARAMEL_INVALID_ARGUMENT);
+            karamel_engine_free(engine);
+        }
+    }
+
+    #[test]
+    fn test_panic_guard() {
+        let code = guard(KARAMEL_INTERNAL_ERROR, || -> i32 { panic!("hata") });
+        assert_eq!(code, KARAMEL_INTERNAL_ERROR);
+        assert_eq!(guard(KARAMEL_INTERNAL_ERROR, || 0), 0);
+    }
+
+    #[test]
+    fn test_values() {
+        unsafe {
+            let value = karamel_value_string(CString::new("merhaba").unwrap().as_ptr());
+            assert_eq!(karamel_value_type(value), KaramelValueType::Text);
+
+            let (mut number, mut flag) = (0_i64, false);
+            assert!(!karamel_value_as_int(value, &mut number));
+            assert!(!karamel_value_as_bool(value, &mut flag));
+
+            let converted = karamel_value_to_string(value);
+            assert_eq!(text(converted), "merhaba");
+            karamel_string_free(converted);
+            karamel_value_free(value);
+
+            let value = karamel_value_bool(true);
+            assert!(karamel_value_as_bool(value, &mut flag) && flag);
+            karamel_value_free(value);
+
+            let value = karamel_value_empty();
+            assert_eq!(karamel_value_type(value), KaramelValueType::Empty);
+            karamel_value_free(value);
+        }
+    }
+}
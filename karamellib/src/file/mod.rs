@@ -11,8 +11,13 @@ pub fn read_file<T: Borrow<str>>(file_name: T) -> Result<String, KaramelErrorTyp
     match File::open(file_name.borrow()) {
         Ok(mut file) => {
             let mut contents = String::new();
-            file.read_to_string(&mut contents).unwrap();
-            Ok(contents)
+            match file.read_to_string(&mut contents) {
+                Ok(_) => Ok(contents),
+                Err(error) => Err(KaramelErrorType::FileReadError {
+                    filename: file_name.borrow().to_owned(),
+                    error: error.to_string(),
+                }),
+            }
         }
         Err(error) => Err(KaramelErrorType::FileReadError {
             filename: file_name.borrow().to_owned(),
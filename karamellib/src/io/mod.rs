@@ -0,0 +1,188 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Write};
+
+use log::Level;
+
+/// Input and output streams of the script. Output should be written as soon as it is produced.
+pub trait KaramelIo {
+    fn write_stdout(&self, data: &str);
+    fn write_stderr(&self, data: &str);
+
+    /// Next line of the input without the line ending, 'None' if the input is finished.
+    fn read_line(&self) -> Option<String>;
+
+    /// Messages of the 'log' macros, kept apart from the error output of the script.
+    fn write_log(&self, _level: Level, _message: &str) {}
+}
+
+/// Standard input and output of the process.
+pub struct ConsoleIo;
+
+impl KaramelIo for ConsoleIo {
+    fn write_stdout(&self, data: &str) {
+        let mut stdout = io::stdout();
+        let _ = stdout.write_all(data.as_bytes());
+        let _ = stdout.flush();
+    }
+
+    fn write_stderr(&self, data: &str) {
+        let _ = io::stderr().write_all(data.as_bytes());
+    }
+
+    fn read_line(&self) -> Option<String> {
+        let mut line = String::new();
+        match io::stdin().read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line.trim_end_matches(['\r', '\n']).to_string()),
+        }
+    }
+
+    fn write_log(&self, level: Level, message: &str) {
+        #[cfg(all(not(target_arch = "wasm32"), not(test)))]
+        eprintln!("[{}] {}", level, message);
+
+        #[cfg(any(target_arch = "wasm32", test))]
+        let _ = (level, message);
+    }
+}
+
+/// Keeps the output in memory and reads the input from the given text. Used by the tests and the web.
+#[derive(Default)]
+pub struct BufferIo {
+    stdout: RefCell<String>,
+    stderr: RefCell<String>,
+    log: RefCell<String>,
+    stdin: RefCell<VecDeque<String>>,
+}
+
+impl BufferIo {
+    pub fn new() -> BufferIo {
+        BufferIo::default()
+    }
+
+    /// Every line of the input is returned by one 'read_line' call.
+    pub fn with_input(input: &str) -> BufferIo {
+        BufferIo {
+            stdin: RefCell::new(input.lines().map(|line| line.to_string()).collect()),
+            ..BufferIo::default()
+        }
+    }
+
+    pub fn stdout(&self) -> String {
+        self.stdout.borrow().to_string()
+    }
+
+    pub fn stderr(&self) -> String {
+        self.stderr.borrow().to_string()
+    }
+
+    pub fn log(&self) -> String {
+        self.log.borrow().to_string()
+    }
+}
+
+impl KaramelIo for BufferIo {
+    fn write_stdout(&self, data: &str) {
+        self.stdout.borrow_mut().push_str(data);
+    }
+
+    fn write_stderr(&self, data: &str) {
+        self.stderr.borrow_mut().push_str(data);
+    }
+
+    fn read_line(&self) -> Option<String> {
+        self.stdin.borrow_mut().pop_front()
+    }
+
+    fn write_log(&self, level: Level, message: &str) {
+        self.log
+            .borrow_mut()
+            .push_str(&format!("[{}] {}\n", level, message));
+    }
+}
+
+/// Writes to and reads from the streams of the host application, like files or sockets.
+pub struct StreamIo {
+    stdout: RefCell<Box<dyn Write>>,
+    stderr: RefCell<Box<dyn Write>>,
+    stdin: RefCell<Box<dyn BufRead>>,
+}
+
+impl StreamIo {
+    pub fn new(stdout: Box<dyn Write>, stderr: Box<dyn Write>, stdin: Box<dyn BufRead>) -> StreamIo {
+        StreamIo {
+            stdout: RefCell::new(stdout),
+            stderr: RefCell::new(stderr),
+            stdin: RefCell::new(stdin),
+        }
+    }
+}
+
+impl KaramelIo for StreamIo {
+    fn write_stdout(&self, data: &str) {
+        let mut stdout = self.stdout.borrow_mut();
+        let _ = stdout.write_all(data.as_bytes());
+        let _ = stdout.flush();
+    }
+
+    fn write_stderr(&self, data: &str) {
+        let mut stderr = self.stderr.borrow_mut();
+        let _ = stderr.write_all(data.as_bytes());
+        let _ = stderr.flush();
+    }
+
+    fn read_line(&self) -> Option<String> {
+        let mut line = String::new();
+        match self.stdin.borrow_mut().read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line.trim_end_matches(['\r', '\n']).to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_buffer_io() {
+        let io = BufferIo::with_input("ilk\r\nikinci");
+        assert_eq!(io.read_line(), Some("ilk".to_string()));
+        assert_eq!(io.read_line(), Some("ikinci".to_string()));
+        assert_eq!(io.read_line(), None);
+
+        io.write_stdout("merhaba");
+        io.write_stderr("hata");
+        io.write_log(Level::Info, "bilgi");
+        assert_eq!((io.stdout(), io.stderr(), io.log()), ("merhaba".to_string(), "hata".to_string(), "[INFO] bilgi\n".to_string()));
+    }
+
+    #[test]
+    fn test_stream_io() {
+        let (stdout, stderr) = (SharedBuffer::default(), SharedBuffer::default());
+        let io = StreamIo::new(Box::new(stdout.clone()), Box::new(stderr.clone()), Box::new(io::Cursor::new("satır\n")));
+
+        io.write_stdout("çıktı");
+        io.write_stderr("hata");
+        assert_eq!(io.read_line(), Some("satır".to_string()));
+        assert_eq!(io.read_line(), None);
+        assert_eq!(String::from_utf8(stdout.0.borrow().clone()).unwrap(), "çıktı");
+        assert_eq!(String::from_utf8(stderr.0.borrow().clone()).unwrap(), "hata");
+    }
+}
@@ -6,7 +6,9 @@ pub mod buildin;
 pub mod compiler;
 pub mod constants;
 pub mod error;
+pub mod ffi;
 pub mod file;
+pub mod io;
 pub mod logger;
 pub mod parser;
 pub mod syntax;
@@ -1,6 +1,10 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use log::*;
 
 use crate::compiler::KaramelCompilerContext;
+use crate::io::{ConsoleIo, KaramelIo};
 
 pub struct ConsoleLogger;
 pub struct DummyLogger;
@@ -8,6 +12,47 @@ pub struct DummyLogger;
 pub static CONSOLE_LOGGER: ConsoleLogger = ConsoleLogger;
 pub static DUMMY_LOGGER: DummyLogger = DummyLogger;
 
+type LogTarget = (Rc<dyn KaramelIo>, LevelFilter);
+
+thread_local! {
+    /// Streams and log level of the running script, log messages are written to them
+    static LOG_IO: RefCell<Option<LogTarget>> = const { RefCell::new(None) };
+}
+
+/// Default log level of the scripts.
+pub fn default_log_level() -> LevelFilter {
+    match cfg!(debug_assertions) {
+        true => LevelFilter::Debug,
+        false => LevelFilter::Info,
+    }
+}
+
+/// Installs the console logger once for the process. Every script filters the messages with its own log level.
+pub fn init_logger() {
+    if log::set_logger(&CONSOLE_LOGGER).is_ok() {
+        log::set_max_level(LevelFilter::Trace);
+    }
+}
+
+/// Sends the log messages of the current thread to the given streams until it is dropped.
+pub struct LogRedirect {
+    previous: Option<LogTarget>,
+}
+
+impl LogRedirect {
+    pub fn new(io: Rc<dyn KaramelIo>, level: LevelFilter) -> LogRedirect {
+        LogRedirect {
+            previous: LOG_IO.with(|log_io| log_io.replace(Some((io, level)))),
+        }
+    }
+}
+
+impl Drop for LogRedirect {
+    fn drop(&mut self) {
+        LOG_IO.with(|log_io| *log_io.borrow_mut() = self.previous.take());
+    }
+}
+
 impl Log for DummyLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
         metadata.level() <= Level::Debug
@@ -18,13 +63,20 @@ impl Log for DummyLogger {
 
 impl Log for ConsoleLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= Level::Debug
+        let level = LOG_IO.with(|log_io| match &*log_io.borrow() {
+            Some((_, level)) => *level,
+            None => default_log_level(),
+        });
+        metadata.level() <= level
     }
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
-            #[cfg(all(not(target_arch = "wasm32"), not(test)))]
-            println!("[{}] {}", record.level(), record.args());
+            let message = record.args().to_string();
+            LOG_IO.with(|log_io| match &*log_io.borrow() {
+                Some((io, _)) => io.write_log(record.level(), &message),
+                None => ConsoleIo.write_log(record.level(), &message),
+            });
         }
     }
 
@@ -32,17 +84,10 @@ impl Log for ConsoleLogger {
 }
 
 pub fn write_stdout(context: &KaramelCompilerContext, data: String) {
-    if let Some(out) = &context.stdout {
-        if let Ok(mut out_mut) = out.try_borrow_mut() {
-            out_mut.push_str(&data[..])
-        }
-    };
+    context.io.write_stdout(&data);
 }
 
+/// Error messages are written line by line.
 pub fn write_stderr(context: &KaramelCompilerContext, data: String) {
-    if let Some(out) = &context.stderr {
-        if let Ok(mut out_mut) = out.try_borrow_mut() {
-            out_mut.push_str(&data[..])
-        }
-    };
+    context.io.write_stderr(&format!("{}\n", data));
 }
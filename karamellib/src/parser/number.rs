@@ -1,5 +1,7 @@
 use crate::error::KaramelErrorType;
 use crate::types::*;
+use num_bigint::BigInt;
+use std::rc::Rc;
 
 pub struct NumberParser;
 
@@ -9,23 +11,42 @@ impl NumberParser {
         tokinizer.get_char()
     }
 
-    fn get_digits(&self, tokinizer: &mut Tokinizer) -> (u8, u64) {
-        let mut number: u64 = 0;
-        let mut num_count: u8 = 0;
+    fn get_digits(&self, tokinizer: &mut Tokinizer) -> (usize, String) {
+        let mut digits = String::new();
         let mut ch: char = tokinizer.get_char();
 
         while !tokinizer.is_end() && (ch.is_ascii_digit() || ch == '_') {
             if ch != '_' {
-                num_count += 1;
-
-                number *= u64::pow(10, 1);
-                number += ch as u64 - '0' as u64;
+                digits.push(ch);
             }
 
             ch = self.increase(tokinizer);
         }
 
-        (num_count, number)
+        (digits.len(), digits)
+    }
+
+    /// Long numbers could not fit into i64, they are stored as big integer.
+    fn integer_token(&self, digits: &str, radix: u32) -> KaramelTokenType {
+        match i64::from_str_radix(digits, radix) {
+            Ok(number) => KaramelTokenType::Integer(number),
+            Err(_) => match BigInt::parse_bytes(digits.as_bytes(), radix) {
+                Some(number) => KaramelTokenType::BigInteger(Rc::new(number)),
+                None => KaramelTokenType::Integer(0),
+            },
+        }
+    }
+
+    fn get_radix_digits(&self, tokinizer: &mut Tokinizer, radix: u32) -> String {
+        let mut digits = String::new();
+        let mut ch: char = tokinizer.get_char();
+
+        while !tokinizer.is_end() && ch.is_digit(radix) {
+            digits.push(ch);
+            ch = self.increase(tokinizer);
+        }
+
+        digits
     }
 
     fn detect_number_system(&self, tokinizer: &mut Tokinizer) -> KaramelNumberSystem {
@@ -53,57 +74,18 @@ impl NumberParser {
     }
 
     fn parse_hex(&self, tokinizer: &mut Tokinizer) -> KaramelTokenType {
-        let mut number: u64 = 0;
-        let mut ch: char = tokinizer.get_char();
-
-        while !tokinizer.is_end() && ch.is_ascii_hexdigit() {
-            number <<= 4;
-
-            let tmp_ch = ch.to_digit(16);
-            if tmp_ch.is_some() {
-                number += tmp_ch.unwrap() as u64;
-            }
-
-            ch = self.increase(tokinizer);
-        }
-
-        KaramelTokenType::Integer(number as i64)
+        let digits = self.get_radix_digits(tokinizer, 16);
+        self.integer_token(&digits, 16)
     }
 
     fn parse_octal(&self, tokinizer: &mut Tokinizer) -> KaramelTokenType {
-        let mut number: u64 = 0;
-        let mut ch: char = tokinizer.get_char();
-
-        while !tokinizer.is_end() && ('0'..='7').contains(&ch) {
-            number <<= 3;
-
-            let tmp_ch = ch.to_digit(8);
-            if tmp_ch.is_some() {
-                number += tmp_ch.unwrap() as u64;
-            }
-
-            ch = self.increase(tokinizer);
-        }
-
-        KaramelTokenType::Integer(number as i64)
+        let digits = self.get_radix_digits(tokinizer, 8);
+        self.integer_token(&digits, 8)
     }
 
     fn parse_binary(&self, tokinizer: &mut Tokinizer) -> KaramelTokenType {
-        let mut number: u64 = 0;
-        let mut ch: char = tokinizer.get_char();
-
-        while !tokinizer.is_end() && ('0'..='1').contains(&ch) {
-            number <<= 1;
-
-            let tmp_ch = ch.to_digit(2);
-            if tmp_ch.is_some() {
-                number += tmp_ch.unwrap() as u64;
-            }
-
-            ch = self.increase(tokinizer);
-        }
-
-        KaramelTokenType::Integer(number as i64)
+        let digits = self.get_radix_digits(tokinizer, 2);
+        self.integer_token(&digits, 2)
     }
 
     fn parse_decimal(&self, tokinizer: &mut Tokinizer) -> KaramelTokenType {
@@ -148,19 +130,26 @@ impl NumberParser {
                 let e_after = digits;
                 self.increase(tokinizer);
 
-                let num = before_comma as f64 + (after_comma as f64 * f64::powi(10.0, -(dot_place as i32)));
+                let num = self.to_double(&before_comma, &after_comma, dot_place);
+                let e_after = e_after.parse::<i32>().unwrap_or(0);
 
                 return match is_minus {
-                    true => KaramelTokenType::Double(num / f64::powi(10.0, e_after as i32)),
-                    false => KaramelTokenType::Double(num * f64::powi(10.0, e_after as i32)),
+                    true => KaramelTokenType::Double(num / f64::powi(10.0, e_after)),
+                    false => KaramelTokenType::Double(num * f64::powi(10.0, e_after)),
                 };
             }
 
-            let num = before_comma as f64 + (after_comma as f64 * f64::powi(10.0, -(dot_place as i32)));
+            let num = self.to_double(&before_comma, &after_comma, dot_place);
             return KaramelTokenType::Double(num);
         }
 
-        KaramelTokenType::Integer(before_comma as i64)
+        self.integer_token(&before_comma, 10)
+    }
+
+    fn to_double(&self, before_comma: &str, after_comma: &str, dot_place: usize) -> f64 {
+        let before_comma = before_comma.parse::<f64>().unwrap_or(0.0);
+        let after_comma = after_comma.parse::<f64>().unwrap_or(0.0);
+        before_comma + (after_comma * f64::powi(10.0, -(dot_place as i32)))
     }
 }
 
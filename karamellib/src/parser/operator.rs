@@ -28,6 +28,9 @@ impl TokenParser for OperatorParser {
             ('>', '=') => KaramelOperatorType::GreaterEqualThan,
             ('*', '=') => KaramelOperatorType::AssignMultiplication,
             ('*', '/') => KaramelOperatorType::CommentMultilineEnd,
+            ('*', '*') => KaramelOperatorType::Power,
+            ('<', '<') => KaramelOperatorType::LeftShift,
+            ('>', '>') => KaramelOperatorType::RightShift,
             ('=', '=') => KaramelOperatorType::Equal,
             _ => KaramelOperatorType::None,
         };
@@ -55,6 +58,10 @@ impl TokenParser for OperatorParser {
                 ';' => KaramelOperatorType::Semicolon,
                 '.' => KaramelOperatorType::Dot,
                 '!' => KaramelOperatorType::Not,
+                '&' => KaramelOperatorType::BitwiseAnd,
+                '|' => KaramelOperatorType::BitwiseOr,
+                '^' => KaramelOperatorType::BitwiseXor,
+                '~' => KaramelOperatorType::BitwiseNot,
                 _ => KaramelOperatorType::None,
             };
         }
@@ -1,7 +1,9 @@
 use std::rc::Rc;
 
 use crate::compiler::ast::KaramelAstType;
+use crate::error::KaramelErrorType;
 use crate::syntax::expression::ExpressionParser;
+use crate::syntax::primative::PrimativeParser;
 use crate::syntax::{SyntaxFlag, SyntaxParser, SyntaxParserTrait};
 use crate::types::*;
 
@@ -16,9 +18,17 @@ impl SyntaxParserTrait for AssignmentParser {
 
         let variable = ExpressionParser::parse(parser)?;
 
+        /* Multiple assignment is not allowed in expressions, 'döngü i = 0, i < 10, ++i' uses comma as seperator */
+        let in_expression = parser.flags.get().contains(SyntaxFlag::IN_EXPRESSION);
+        let variable = match (in_expression, &variable) {
+            (false, KaramelAstType::Symbol(_)) | (false, KaramelAstType::Indexer { .. }) | (false, KaramelAstType::List(_)) | (false, KaramelAstType::Dict(_)) => PrimativeParser::parse_tuple(parser, variable)?,
+            _ => variable,
+        };
+
         match variable {
             KaramelAstType::Symbol(_) => (),
             KaramelAstType::Indexer { body: _, indexer: _ } => (),
+            KaramelAstType::Tuple(_) | KaramelAstType::List(_) | KaramelAstType::Dict(_) if !in_expression => (),
             _ => {
                 parser.set_index(index_backup);
                 return Ok(KaramelAstType::None);
@@ -36,17 +46,25 @@ impl SyntaxParserTrait for AssignmentParser {
         ]) {
             parser.cleanup_whitespaces();
 
-            let expression = with_flag(SyntaxFlag::IN_ASSIGNMENT, parser, || ExpressionParser::parse(parser));
-            match expression {
-                Ok(KaramelAstType::None) => return expression,
-                Ok(_) => (),
-                Err(_) => return expression,
-            };
+            let is_destructuring = !matches!(variable, KaramelAstType::Symbol(_) | KaramelAstType::Indexer { .. });
+            if is_destructuring && !Self::is_valid_target(&variable) {
+                return Err(KaramelErrorType::InvalidAssignmentTarget);
+            }
+
+            let expression = with_flag(SyntaxFlag::IN_ASSIGNMENT, parser, || match ExpressionParser::parse(parser)? {
+                KaramelAstType::None => Ok(KaramelAstType::None),
+                expression if !in_expression => PrimativeParser::parse_tuple(parser, expression),
+                expression => Ok(expression),
+            })?;
+
+            if expression == KaramelAstType::None {
+                return Ok(KaramelAstType::None);
+            }
 
             let assignment_ast = KaramelAstType::Assignment {
                 variable: Rc::new(variable),
                 operator,
-                expression: Rc::new(expression.unwrap()),
+                expression: Rc::new(expression),
             };
 
             return Ok(assignment_ast);
@@ -55,3 +73,14 @@ impl SyntaxParserTrait for AssignmentParser {
         Ok(KaramelAstType::None)
     }
 }
+
+impl AssignmentParser {
+    fn is_valid_target(target: &KaramelAstType) -> bool {
+        match target {
+            KaramelAstType::Symbol(_) => true,
+            KaramelAstType::Tuple(items) | KaramelAstType::List(items) => items.iter().all(|item| Self::is_valid_target(item)),
+            KaramelAstType::Dict(items) => items.iter().all(|item| Self::is_valid_target(&item.value)),
+            _ => false,
+        }
+    }
+}
@@ -12,6 +12,35 @@ use super::util::with_flag;
 pub struct ModuloParser;
 pub struct MultiplyDivideParser;
 pub struct AddSubtractParser;
+pub struct PowerParser;
+pub struct ShiftParser;
+pub struct BitwiseAndParser;
+pub struct BitwiseXorParser;
+pub struct BitwiseOrParser;
+
+impl SyntaxParserTrait for BitwiseOrParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        parse_binary::<BitwiseXorParser>(parser, &[KaramelOperatorType::BitwiseOr])
+    }
+}
+
+impl SyntaxParserTrait for BitwiseXorParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        parse_binary::<BitwiseAndParser>(parser, &[KaramelOperatorType::BitwiseXor])
+    }
+}
+
+impl SyntaxParserTrait for BitwiseAndParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        parse_binary::<ShiftParser>(parser, &[KaramelOperatorType::BitwiseAnd])
+    }
+}
+
+impl SyntaxParserTrait for ShiftParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        parse_binary::<AddSubtractParser>(parser, &[KaramelOperatorType::LeftShift, KaramelOperatorType::RightShift])
+    }
+}
 
 impl SyntaxParserTrait for ModuloParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
@@ -21,7 +50,39 @@ impl SyntaxParserTrait for ModuloParser {
 
 impl SyntaxParserTrait for MultiplyDivideParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
-        parse_binary::<UnaryParser>(parser, &[KaramelOperatorType::Multiplication, KaramelOperatorType::Division])
+        parse_binary::<PowerParser>(parser, &[KaramelOperatorType::Multiplication, KaramelOperatorType::Division, KaramelOperatorType::FloorDivision])
+    }
+}
+
+impl SyntaxParserTrait for PowerParser {
+    /* Power is right associative, 2 ** 3 ** 2 equals to 2 ** (3 ** 2) */
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        let left_expr = UnaryParser::parse(parser)?;
+        if left_expr == KaramelAstType::None {
+            return Ok(left_expr);
+        };
+
+        let index_backup = parser.get_index();
+        parser.cleanup_whitespaces();
+
+        if let Some(operator) = parser.match_operator(&[KaramelOperatorType::Power]) {
+            update_functions_for_temp_return(&left_expr);
+            parser.cleanup_whitespaces();
+
+            let right_expr = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || PowerParser::parse(parser))?;
+            if right_expr == KaramelAstType::None {
+                return Err(KaramelErrorType::RightSideOfExpressionNotFound);
+            }
+
+            return Ok(KaramelAstType::Binary {
+                left: Rc::new(left_expr),
+                operator,
+                right: Rc::new(right_expr),
+            });
+        }
+
+        parser.set_index(index_backup);
+        Ok(left_expr)
     }
 }
 
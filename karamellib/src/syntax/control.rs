@@ -2,7 +2,7 @@ use std::rc::Rc;
 
 use crate::compiler::ast::KaramelAstType;
 use crate::error::KaramelErrorType;
-use crate::syntax::binary::AddSubtractParser;
+use crate::syntax::binary::BitwiseOrParser;
 use crate::syntax::util::update_functions_for_temp_return;
 use crate::syntax::{SyntaxFlag, SyntaxParser, SyntaxParserTrait};
 use crate::types::*;
@@ -40,8 +40,8 @@ impl SyntaxParserTrait for ControlParser {
 
 pub fn special_control(parser: &SyntaxParser) -> AstResult {
     let mut functions_updated_for_temp = false;
-    let mut left_expr = AddSubtractParser::parse(parser)?;
-    let operators = [KaramelOperatorType::GreaterEqualThan, KaramelOperatorType::GreaterThan, KaramelOperatorType::LessEqualThan, KaramelOperatorType::LessThan];
+    let mut left_expr = BitwiseOrParser::parse(parser)?;
+    let operators = [KaramelOperatorType::GreaterEqualThan, KaramelOperatorType::GreaterThan, KaramelOperatorType::LessEqualThan, KaramelOperatorType::LessThan, KaramelOperatorType::In];
     if left_expr == KaramelAstType::None {
         return Ok(left_expr);
     };
@@ -49,14 +49,21 @@ pub fn special_control(parser: &SyntaxParser) -> AstResult {
     loop {
         let index_backup = parser.get_index();
         parser.cleanup_whitespaces();
-        if let Some(operator) = parser.match_operator(&operators) {
+        if let Some(mut operator) = parser.match_operator(&operators) {
             if !functions_updated_for_temp {
                 update_functions_for_temp_return(&left_expr);
                 functions_updated_for_temp = true;
             }
 
             parser.cleanup_whitespaces();
-            let right_expr = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || AddSubtractParser::parse(parser));
+
+            /* 'içinde değil' */
+            if operator == KaramelOperatorType::In && parser.match_operator(&[KaramelOperatorType::Not]).is_some() {
+                operator = KaramelOperatorType::NotIn;
+                parser.cleanup_whitespaces();
+            }
+
+            let right_expr = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || BitwiseOrParser::parse(parser));
             match right_expr {
                 Ok(KaramelAstType::None) => return Err(KaramelErrorType::RightSideOfExpressionNotFound),
                 Ok(_) => (),
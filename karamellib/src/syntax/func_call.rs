@@ -60,7 +60,7 @@ impl ExtensionSyntaxParser for FuncCallParser {
             .match_operator(&[KaramelOperatorType::LeftParentheses])
             .is_some()
         {
-            let mut arguments = Vec::new();
+            let mut arguments: Vec<Rc<KaramelAstType>> = Vec::new();
 
             let inner_parser_flags = parser.flags.get();
             parser.flags.set(parser_flags | SyntaxFlag::IN_FUNCTION_ARG);
@@ -70,7 +70,13 @@ impl ExtensionSyntaxParser for FuncCallParser {
             while continue_to_parse {
                 parser.cleanup_whitespaces();
 
-                let param_expression = ExpressionParser::parse(parser)?;
+                let param_expression = match FuncCallParser::parse_argument_name(parser) {
+                    Some(name) => match ExpressionParser::parse(parser)? {
+                        KaramelAstType::None => return Err(KaramelErrorType::InvalidExpression),
+                        expression => KaramelAstType::NamedArgument { name, expression: Rc::new(expression) },
+                    },
+                    None => ExpressionParser::parse(parser)?,
+                };
 
                 parser.cleanup_whitespaces();
 
@@ -87,8 +93,21 @@ impl ExtensionSyntaxParser for FuncCallParser {
                     }
                 }
 
+                let has_named_argument = arguments
+                    .iter()
+                    .any(|argument| matches!(&**argument, KaramelAstType::NamedArgument { .. }));
+
                 match param_expression {
                     KaramelAstType::None => (),
+                    KaramelAstType::NamedArgument { name, expression: _ }
+                        if arguments
+                            .iter()
+                            .any(|argument| matches!(&**argument, KaramelAstType::NamedArgument { name: used_name, .. } if used_name == &name)) =>
+                    {
+                        return Err(KaramelErrorType::NamedArgumentAlreadyUsed(name.to_string()))
+                    }
+                    KaramelAstType::NamedArgument { .. } => arguments.push(Rc::new(param_expression)),
+                    _ if has_named_argument => return Err(KaramelErrorType::PositionalArgumentAfterNamedArgument),
                     data => arguments.push(Rc::new(data)),
                 };
             }
@@ -127,3 +146,24 @@ impl ExtensionSyntaxParser for FuncCallParser {
         Ok(KaramelAstType::None)
     }
 }
+
+impl FuncCallParser {
+    /* Parse 'isim=' part of the named argument */
+    fn parse_argument_name(parser: &SyntaxParser) -> Option<String> {
+        let index_backup = parser.get_index();
+
+        if let Ok(KaramelAstType::Symbol(name)) = PrimativeParser::parse_symbol(parser) {
+            parser.cleanup_whitespaces();
+            if parser
+                .match_operator(&[KaramelOperatorType::Assign])
+                .is_some()
+            {
+                parser.cleanup_whitespaces();
+                return Some(name);
+            }
+        }
+
+        parser.set_index(index_backup);
+        None
+    }
+}
@@ -1,7 +1,12 @@
 use crate::compiler::ast::KaramelAstType;
+use crate::compiler::function::FunctionArgument;
+use crate::compiler::number::KaramelNumber;
+use crate::compiler::value::KaramelPrimative;
 use crate::error::KaramelErrorType;
 use crate::syntax::block::{MultiLineBlockParser, SingleLineBlockParser};
+use crate::syntax::expression::ExpressionParser;
 use crate::syntax::primative::PrimativeParser;
+use crate::syntax::util::with_flag;
 use crate::syntax::{SyntaxFlag, SyntaxParser, SyntaxParserTrait};
 use crate::types::*;
 use std::rc::Rc;
@@ -41,12 +46,38 @@ impl SyntaxParserTrait for FunctionDefinationParser {
                         break;
                     }
 
-                    let argument = PrimativeParser::parse_symbol(parser)?;
-                    match argument {
-                        KaramelAstType::Symbol(text) => arguments.push(text),
+                    if arguments
+                        .last()
+                        .is_some_and(|argument: &FunctionArgument| argument.variadic)
+                    {
+                        return Err(KaramelErrorType::VariadicArgumentMustBeLast);
+                    }
+
+                    /* '*geri_kalan' collects all remaining positional arguments */
+                    let variadic = parser
+                        .match_operator(&[KaramelOperatorType::Multiplication])
+                        .is_some();
+
+                    let name = match PrimativeParser::parse_symbol(parser)? {
+                        KaramelAstType::Symbol(text) => text,
                         _ => return Err(KaramelErrorType::ArgumentMustBeText),
                     };
 
+                    if arguments.iter().any(|argument| argument.name == name) {
+                        return Err(KaramelErrorType::ArgumentAlreadyDefined(name));
+                    }
+
+                    parser.cleanup_whitespaces();
+                    let argument = match parser.match_operator(&[KaramelOperatorType::Assign]) {
+                        Some(_) if variadic => return Err(KaramelErrorType::ArgumentDefaultValueNotValid),
+                        Some(_) => FunctionArgument::with_default(&name, Self::parse_default_value(parser)?),
+                        None if variadic => FunctionArgument::variadic(&name),
+                        None if arguments.iter().any(|argument| argument.default.is_some()) => return Err(KaramelErrorType::ArgumentDefaultValueRequired(name)),
+                        None => FunctionArgument::new(&name),
+                    };
+
+                    arguments.push(argument);
+
                     parser.cleanup_whitespaces();
                     if parser
                         .match_operator(&[KaramelOperatorType::Comma])
@@ -118,3 +149,31 @@ impl SyntaxParserTrait for FunctionDefinationParser {
         Ok(KaramelAstType::None)
     }
 }
+
+impl FunctionDefinationParser {
+    /* Default values are stored at function signature, so only constant values are accepted */
+    fn parse_default_value(parser: &SyntaxParser) -> Result<Rc<KaramelPrimative>, KaramelErrorType> {
+        parser.cleanup_whitespaces();
+        let value = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))?;
+
+        match value {
+            KaramelAstType::Primative(primative) => Ok(primative),
+            KaramelAstType::PrefixUnary {
+                operator: KaramelOperatorType::Subtraction,
+                expression,
+                assign_to_temp: _,
+            } => match &*expression {
+                KaramelAstType::Primative(primative) => match &**primative {
+                    KaramelPrimative::Number(number) => Ok(Rc::new(KaramelPrimative::Number(-number))),
+                    KaramelPrimative::Integer(_) | KaramelPrimative::BigInteger(_) => match KaramelNumber::from_primative(primative) {
+                        Some(number) => Ok(Rc::new(KaramelNumber::Integer(0).sub(&number).to_primative())),
+                        None => Err(KaramelErrorType::ArgumentDefaultValueNotValid),
+                    },
+                    _ => Err(KaramelErrorType::ArgumentDefaultValueNotValid),
+                },
+                _ => Err(KaramelErrorType::ArgumentDefaultValueNotValid),
+            },
+            _ => Err(KaramelErrorType::ArgumentDefaultValueNotValid),
+        }
+    }
+}
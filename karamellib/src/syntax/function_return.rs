@@ -3,6 +3,7 @@ use std::rc::Rc;
 use crate::compiler::ast::KaramelAstType;
 use crate::error::KaramelErrorType;
 use crate::syntax::expression::ExpressionParser;
+use crate::syntax::primative::PrimativeParser;
 use crate::syntax::{SyntaxFlag, SyntaxParser, SyntaxParserTrait};
 use crate::types::*;
 
@@ -24,7 +25,12 @@ impl SyntaxParserTrait for FunctionReturnParser {
             let parser_flags = parser.flags.get();
             parser.flags.set(parser_flags | SyntaxFlag::IN_RETURN);
 
+            /* Multiple values returned as tuple */
             let ast = ExpressionParser::parse(parser)?;
+            let ast = match ast {
+                KaramelAstType::None => ast,
+                _ => PrimativeParser::parse_tuple(parser, ast)?,
+            };
             let return_ast = KaramelAstType::Return(Rc::new(ast));
             parser.flags.set(parser_flags);
 
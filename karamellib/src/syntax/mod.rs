@@ -11,6 +11,7 @@ pub mod load_module;
 pub mod loop_item;
 pub mod loops;
 pub mod newline;
+pub mod pattern_match;
 pub mod primative;
 pub mod statement;
 pub mod unary;
@@ -112,17 +113,14 @@ impl SyntaxParser {
     }
 
     pub fn is_same_indentation(&self, indentation: usize) -> bool {
-        if self.indentation_check().is_err() {
-            return false;
-        } else if indentation != self.get_indentation() {
-            return false;
-        } else if self.peek_token().is_none() {
-            return false;
-        } else if self.peek_token().unwrap().start != indentation as u32 {
+        if self.indentation_check().is_err() || indentation != self.get_indentation() {
             return false;
         }
 
-        true
+        match self.peek_token() {
+            Some(token) => token.start == indentation as u32,
+            None => false,
+        }
     }
 
     pub fn peek_token(&self) -> Option<&Token> {
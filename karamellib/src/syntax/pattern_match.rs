@@ -0,0 +1,275 @@
+use std::rc::Rc;
+
+use crate::compiler::ast::{KaramelAstType, KaramelMatchCase, KaramelMatchPattern};
+use crate::compiler::value::KaramelPrimative;
+use crate::error::KaramelErrorType;
+use crate::syntax::block::{MultiLineBlockParser, SingleLineBlockParser};
+use crate::syntax::expression::ExpressionParser;
+use crate::syntax::primative::PrimativeParser;
+use crate::syntax::util::with_flag;
+use crate::syntax::{SyntaxFlag, SyntaxParser, SyntaxParserTrait};
+use crate::types::*;
+
+pub struct PatternMatchParser;
+
+impl SyntaxParserTrait for PatternMatchParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+        parser.indentation_check()?;
+
+        let indentation = parser.get_indentation();
+        if !parser.match_keyword(KaramelKeywordType::Match) {
+            parser.set_index(index_backup);
+            return Ok(KaramelAstType::None);
+        }
+
+        /* Matched value stored at hidden variable, so every case could read it again */
+        let variable = format!("__eşleştir_{}", parser.get_index());
+
+        parser.cleanup_whitespaces();
+        let value = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))?;
+        if value == KaramelAstType::None {
+            return Err(KaramelErrorType::MatchValueNotFound);
+        }
+
+        parser.cleanup_whitespaces();
+        if parser
+            .match_operator(&[KaramelOperatorType::ColonMark])
+            .is_none()
+        {
+            return Err(KaramelErrorType::ColonMarkMissing);
+        }
+
+        parser.cleanup_whitespaces();
+        if !parser.get_newline().0 {
+            return Err(KaramelErrorType::MatchCaseNotFound);
+        }
+
+        parser.in_indication()?;
+        let case_indentation = parser.get_indentation();
+        let mut cases = Vec::new();
+
+        loop {
+            parser.indentation_check()?;
+            cases.push(Rc::new(Self::parse_case(parser)?));
+            parser.set_indentation(case_indentation);
+
+            parser.cleanup();
+            if !parser.is_same_indentation(case_indentation) {
+                break;
+            }
+        }
+
+        parser.set_indentation(indentation);
+        Ok(KaramelAstType::Match { value: Rc::new(value), variable, cases })
+    }
+}
+
+/// Guard word is not a keyword, it could be used as a name out of the match cases.
+const GUARD_WORDS: [&str; 2] = ["eğer", "eger"];
+
+impl PatternMatchParser {
+    fn match_guard(parser: &SyntaxParser) -> bool {
+        let is_guard = matches!(parser.peek_token(), Some(Token { token_type: KaramelTokenType::Symbol(symbol), .. }) if GUARD_WORDS.contains(&symbol.as_str()));
+        if is_guard {
+            parser.consume_token();
+        }
+        is_guard
+    }
+
+    fn parse_case(parser: &SyntaxParser) -> Result<KaramelMatchCase, KaramelErrorType> {
+        let mut patterns = Vec::new();
+
+        /* Multiple alternatives could be separated with comma */
+        loop {
+            parser.cleanup_whitespaces();
+            patterns.push(Rc::new(Self::parse_pattern(parser)?));
+
+            parser.cleanup_whitespaces();
+            if parser
+                .match_operator(&[KaramelOperatorType::Comma])
+                .is_none()
+            {
+                break;
+            }
+        }
+
+        let guard = match Self::match_guard(parser) {
+            true => {
+                parser.cleanup_whitespaces();
+                let guard = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))?;
+                if guard == KaramelAstType::None {
+                    return Err(KaramelErrorType::InvalidExpression);
+                }
+
+                parser.cleanup_whitespaces();
+                Some(Rc::new(guard))
+            }
+            false => None,
+        };
+
+        if parser
+            .match_operator(&[KaramelOperatorType::ColonMark])
+            .is_none()
+        {
+            return Err(KaramelErrorType::ColonMarkMissing);
+        }
+
+        parser.cleanup_whitespaces();
+        let body = match parser.get_newline() {
+            (true, _) => {
+                parser.in_indication()?;
+                MultiLineBlockParser::parse(parser)
+            }
+            (false, _) => SingleLineBlockParser::parse(parser),
+        }?;
+
+        if body == KaramelAstType::None {
+            return Err(KaramelErrorType::MatchCaseBodyNotFound);
+        }
+
+        Ok(KaramelMatchCase::new(patterns, guard, Rc::new(body)))
+    }
+
+    fn parse_pattern(parser: &SyntaxParser) -> Result<KaramelMatchPattern, KaramelErrorType> {
+        let token_type = match parser.peek_token() {
+            Some(token) => token.token_type.clone(),
+            None => return Err(KaramelErrorType::InvalidMatchPattern),
+        };
+
+        match token_type {
+            KaramelTokenType::Operator(KaramelOperatorType::SquareBracketStart) => Self::parse_list_pattern(parser),
+            KaramelTokenType::Operator(KaramelOperatorType::CurveBracketStart) => Self::parse_dict_pattern(parser),
+            KaramelTokenType::Symbol(symbol) => {
+                parser.consume_token();
+                match symbol.as_str() {
+                    "_" => Ok(KaramelMatchPattern::Wildcard),
+                    _ => Ok(KaramelMatchPattern::Binding(symbol.to_string())),
+                }
+            }
+            KaramelTokenType::Operator(KaramelOperatorType::Subtraction) => {
+                parser.consume_token();
+                match parser.peek_token().map(|token| token.token_type.clone()) {
+                    Some(KaramelTokenType::Integer(integer)) => {
+                        parser.consume_token();
+                        Ok(KaramelMatchPattern::Literal(Rc::new(KaramelPrimative::Integer(-integer))))
+                    }
+                    Some(KaramelTokenType::BigInteger(integer)) => {
+                        parser.consume_token();
+                        Ok(KaramelMatchPattern::Literal(Rc::new(KaramelPrimative::BigInteger(-(*integer).clone()))))
+                    }
+                    Some(KaramelTokenType::Double(double)) => {
+                        parser.consume_token();
+                        Ok(KaramelMatchPattern::Literal(Rc::new(KaramelPrimative::Number(-double))))
+                    }
+                    _ => Err(KaramelErrorType::InvalidMatchPattern),
+                }
+            }
+            _ => match PrimativeParser::parse_basic_primatives(parser)? {
+                KaramelAstType::Primative(primative) => Ok(KaramelMatchPattern::Literal(primative)),
+                _ => Err(KaramelErrorType::InvalidMatchPattern),
+            },
+        }
+    }
+
+    fn parse_list_pattern(parser: &SyntaxParser) -> Result<KaramelMatchPattern, KaramelErrorType> {
+        parser.consume_token();
+
+        let mut items = Vec::new();
+        let mut rest = None;
+
+        loop {
+            parser.cleanup_whitespaces();
+            if parser.check_operator(&KaramelOperatorType::SquareBracketEnd) {
+                break;
+            }
+
+            /* '...geri' collects remaining items and it must be the last item */
+            if parser.match_operator(&[KaramelOperatorType::Dot]).is_some() {
+                for _ in 0..2 {
+                    if parser.match_operator(&[KaramelOperatorType::Dot]).is_none() {
+                        return Err(KaramelErrorType::InvalidMatchPattern);
+                    }
+                }
+
+                rest = match parser.peek_token().map(|token| token.token_type.clone()) {
+                    Some(KaramelTokenType::Symbol(symbol)) => Some(symbol.to_string()),
+                    _ => return Err(KaramelErrorType::InvalidMatchPattern),
+                };
+
+                parser.consume_token();
+                parser.cleanup_whitespaces();
+                break;
+            }
+
+            items.push(Rc::new(Self::parse_pattern(parser)?));
+
+            parser.cleanup_whitespaces();
+            if parser
+                .match_operator(&[KaramelOperatorType::Comma])
+                .is_none()
+            {
+                break;
+            }
+        }
+
+        if parser
+            .match_operator(&[KaramelOperatorType::SquareBracketEnd])
+            .is_none()
+        {
+            return Err(KaramelErrorType::ArrayNotClosed);
+        }
+
+        Ok(KaramelMatchPattern::List { items, rest })
+    }
+
+    fn parse_dict_pattern(parser: &SyntaxParser) -> Result<KaramelMatchPattern, KaramelErrorType> {
+        parser.consume_token();
+
+        let mut items = Vec::new();
+
+        loop {
+            parser.cleanup();
+            if parser.check_operator(&KaramelOperatorType::CurveBracketEnd) {
+                break;
+            }
+
+            let key = match PrimativeParser::parse_basic_primatives(parser)? {
+                KaramelAstType::Primative(primative) => match &*primative {
+                    KaramelPrimative::Text(_) => primative.clone(),
+                    _ => return Err(KaramelErrorType::DictionaryKeyNotValid),
+                },
+                _ => return Err(KaramelErrorType::DictionaryKeyNotValid),
+            };
+
+            parser.cleanup_whitespaces();
+            if parser
+                .match_operator(&[KaramelOperatorType::ColonMark])
+                .is_none()
+            {
+                return Err(KaramelErrorType::ColonMarkMissing);
+            }
+
+            parser.cleanup_whitespaces();
+            items.push((key, Rc::new(Self::parse_pattern(parser)?)));
+
+            parser.cleanup();
+            if parser
+                .match_operator(&[KaramelOperatorType::Comma])
+                .is_none()
+            {
+                break;
+            }
+        }
+
+        parser.cleanup();
+        if parser
+            .match_operator(&[KaramelOperatorType::CurveBracketEnd])
+            .is_none()
+        {
+            return Err(KaramelErrorType::DictNotClosed);
+        }
+
+        Ok(KaramelMatchPattern::Dict(items))
+    }
+}
@@ -21,7 +21,8 @@ impl PrimativeParser {
         }
 
         let result = match &token.unwrap().token_type {
-            KaramelTokenType::Integer(int) => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(*int as f64)))),
+            KaramelTokenType::Integer(int) => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Integer(*int)))),
+            KaramelTokenType::BigInteger(int) => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::BigInteger((**int).clone())))),
             KaramelTokenType::Double(double) => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(*double)))),
             KaramelTokenType::Text(text) => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::clone(text))))),
             KaramelTokenType::Keyword(keyword) => match keyword {
@@ -108,7 +109,11 @@ impl PrimativeParser {
 
                 parser.cleanup();
 
-                let key_ast = Self::parse_basic_primatives(parser);
+                let mut key_ast = Self::parse_basic_primatives(parser);
+                if let Ok(KaramelAstType::None) = key_ast {
+                    key_ast = Self::parse_parenthesis(parser);
+                }
+
                 if is_ast_empty(&key_ast) {
                     return err_or_message(key_ast, KaramelErrorType::DictionaryKeyNotValid);
                 }
@@ -121,6 +126,10 @@ impl PrimativeParser {
                             return Err(KaramelErrorType::DictionaryKeyNotValid);
                         }
                     },
+                    Ok(KaramelAstType::Tuple(items)) => match Self::constant_tuple(&items) {
+                        Some(tuple) => Rc::new(tuple),
+                        None => return Err(KaramelErrorType::DictionaryKeyNotValid),
+                    },
                     _ => return Err(KaramelErrorType::DictionaryKeyNotValid),
                 };
 
@@ -227,17 +236,43 @@ impl PrimativeParser {
         Ok(KaramelAstType::None)
     }
 
+    /// Tuple keys of the dictionary are constant, items should be constant values or tuples.
+    fn constant_tuple(items: &[Rc<KaramelAstType>]) -> Option<KaramelPrimative> {
+        items
+            .iter()
+            .map(|item| match &**item {
+                KaramelAstType::Primative(primative) => Some(VmObject::convert(primative.clone())),
+                KaramelAstType::Tuple(items) => Self::constant_tuple(items).map(VmObject::native_convert),
+                _ => None,
+            })
+            .collect::<Option<Vec<VmObject>>>()
+            .map(KaramelPrimative::Tuple)
+    }
+
     pub fn parse_parenthesis(parser: &SyntaxParser) -> AstResult {
         let index_backup = parser.get_index();
         if parser
             .match_operator(&[KaramelOperatorType::LeftParentheses])
             .is_some()
         {
+            parser.cleanup_whitespaces();
+
+            /* Empty tuple */
+            if parser
+                .match_operator(&[KaramelOperatorType::RightParentheses])
+                .is_some()
+            {
+                return Ok(KaramelAstType::Tuple(Vec::new()));
+            }
+
             let ast = ExpressionParser::parse(parser);
             if is_ast_empty(&ast) {
                 return err_or_message(ast, KaramelErrorType::InvalidExpression);
             }
 
+            let ast = Self::parse_tuple(parser, ast.unwrap())?;
+
+            parser.cleanup_whitespaces();
             if parser
                 .match_operator(&[KaramelOperatorType::RightParentheses])
                 .is_none()
@@ -245,12 +280,49 @@ impl PrimativeParser {
                 return Err(KaramelErrorType::ParenthesesNotClosed);
             }
 
-            return Ok(ast.unwrap());
+            return Ok(ast);
         }
 
         parser.set_index(index_backup);
         Ok(KaramelAstType::None)
     }
+
+    /// Collects comma separated expressions after the first one into a tuple.
+    /// If there is no comma, first expression returned as is.
+    pub fn parse_tuple(parser: &SyntaxParser, first: KaramelAstType) -> AstResult {
+        let mut items = vec![Rc::new(first)];
+        let mut is_tuple = false;
+
+        loop {
+            let index_backup = parser.get_index();
+            parser.cleanup_whitespaces();
+            if parser
+                .match_operator(&[KaramelOperatorType::Comma])
+                .is_none()
+            {
+                parser.set_index(index_backup);
+                break;
+            }
+
+            /* Trailing comma is allowed, (1,) is a tuple with one item */
+            is_tuple = true;
+            let index_backup = parser.get_index();
+            parser.cleanup_whitespaces();
+
+            let ast = ExpressionParser::parse(parser)?;
+            if ast == KaramelAstType::None {
+                parser.set_index(index_backup);
+                break;
+            }
+
+            items.push(Rc::new(ast));
+        }
+
+        match is_tuple {
+            true => Ok(KaramelAstType::Tuple(items)),
+            false => Ok((*items[0]).clone()),
+        }
+    }
 }
 
 impl SyntaxParserTrait for PrimativeParser {
@@ -1,4 +1,5 @@
 use crate::compiler::ast::KaramelAstType;
+use crate::compiler::number::KaramelNumber;
 use crate::compiler::value::KaramelPrimative;
 use crate::error::KaramelErrorType;
 use crate::syntax::expression::ExpressionParser;
@@ -6,6 +7,7 @@ use crate::syntax::func_call::FuncCallParser;
 use crate::syntax::primative::PrimativeParser;
 use crate::syntax::util::is_ast_empty;
 use crate::syntax::util::map_parser;
+use crate::syntax::util::with_flag;
 use crate::syntax::SyntaxFlag;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait};
 use crate::types::*;
@@ -28,7 +30,7 @@ impl SyntaxParserTrait for UnaryParser {
         {
             parser.cleanup_whitespaces();
 
-            let indexer_ast = ExpressionParser::parse(parser)?;
+            let indexer_ast = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))?;
             parser.cleanup_whitespaces();
 
             if parser
@@ -70,7 +72,8 @@ impl UnaryParser {
         {
             parser.cleanup_whitespaces();
 
-            let indexer_ast = ExpressionParser::parse(parser);
+            /* Indexer value always used, function calls must keep their return value */
+            let indexer_ast = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser));
             parser.cleanup_whitespaces();
 
             if parser
@@ -89,7 +92,14 @@ impl UnaryParser {
     fn parse_prefix_unary(parser: &SyntaxParser) -> AstResult {
         let index_backup = parser.get_index();
 
-        if let Some(operator) = parser.match_operator(&[KaramelOperatorType::Addition, KaramelOperatorType::Subtraction, KaramelOperatorType::Increment, KaramelOperatorType::Deccrement, KaramelOperatorType::Not]) {
+        if let Some(operator) = parser.match_operator(&[
+            KaramelOperatorType::Addition,
+            KaramelOperatorType::Subtraction,
+            KaramelOperatorType::Increment,
+            KaramelOperatorType::Deccrement,
+            KaramelOperatorType::Not,
+            KaramelOperatorType::BitwiseNot,
+        ]) {
             parser.cleanup_whitespaces();
 
             let mut unary_ast = KaramelAstType::None;
@@ -105,8 +115,15 @@ impl UnaryParser {
                     };
 
                     parser.consume_token();
-                    match token.token_type {
-                        KaramelTokenType::Integer(integer) => return Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(integer as f64 * opt)))),
+                    match &token.token_type {
+                        KaramelTokenType::Integer(integer) => {
+                            let number = KaramelNumber::Integer(*integer).mul(&KaramelNumber::Integer(opt as i64));
+                            return Ok(KaramelAstType::Primative(Rc::new(number.to_primative())));
+                        }
+                        KaramelTokenType::BigInteger(integer) => {
+                            let number = KaramelNumber::BigInteger((**integer).clone()).mul(&KaramelNumber::Integer(opt as i64));
+                            return Ok(KaramelAstType::Primative(Rc::new(number.to_primative())));
+                        }
                         KaramelTokenType::Double(double) => return Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(double * opt)))),
                         _ => {
                             parser.set_index(index_backup);
@@ -123,7 +140,7 @@ impl UnaryParser {
                     }
                 }
 
-                KaramelOperatorType::Not => {
+                KaramelOperatorType::Not | KaramelOperatorType::BitwiseNot => {
                     let expression = UnaryParser::parse(parser);
                     unary_ast = match expression {
                         Ok(KaramelAstType::None) => {
@@ -1,8 +1,10 @@
 use crate::error::KaramelErrorType;
 use crate::{compiler::ast::KaramelAstType, error::KaramelError};
+use num_bigint::BigInt;
 use std::borrow::Borrow;
 use std::hash::Hash;
 use std::iter::Peekable;
+use std::marker::PhantomData;
 use std::mem::discriminant;
 use std::rc::Rc;
 use std::result::Result;
@@ -17,6 +19,8 @@ pub const TAG_NULL: u64 = 0;
 pub const TAG_FALSE: u64 = 1;
 pub const TAG_TRUE: u64 = 2;
 
+pub const TAG_INTEGER: u64 = 0x0001_0000_0000_0000;
+
 pub const QNAN: u64 = 0x7ffc_0000_0000_0000;
 pub const POINTER_FLAG: u64 = 0x8000_0000_0000_0000;
 pub const POINTER_MASK: u64 = 0x0000_FFFF_FFFF_FFFF;
@@ -24,9 +28,22 @@ pub const FALSE_FLAG: u64 = QNAN | TAG_FALSE;
 pub const TRUE_FLAG: u64 = QNAN | TAG_TRUE;
 pub const EMPTY_FLAG: u64 = QNAN | TAG_NULL;
 
+/* Integers that fit into 48 bit stored inside of the object, others stored as pointer */
+pub const INTEGER_FLAG: u64 = QNAN | TAG_INTEGER;
+pub const INTEGER_MASK: u64 = 0x0000_FFFF_FFFF_FFFF;
+pub const SMALL_INTEGER_MIN: i64 = -(1 << 47);
+pub const SMALL_INTEGER_MAX: i64 = (1 << 47) - 1;
+
+/// Pointers of the objects are 'Rc', so values could not be sent to an other thread.
 #[derive(PartialEq, Hash, Clone, Copy)]
 #[repr(transparent)]
-pub struct VmObject(pub u64);
+pub struct VmObject(pub u64, PhantomData<Rc<()>>);
+
+impl VmObject {
+    pub const fn from_bits(bits: u64) -> VmObject {
+        VmObject(bits, PhantomData)
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum KaramelKeywordType {
@@ -55,6 +72,9 @@ pub enum KaramelKeywordType {
     Continue,
     While,
     Load,
+    In,
+    Match,
+    FloorDivision,
 }
 
 impl KaramelKeywordType {
@@ -63,11 +83,13 @@ impl KaramelKeywordType {
             KaramelKeywordType::And => KaramelOperatorType::And,
             KaramelKeywordType::Or => KaramelOperatorType::Or,
             KaramelKeywordType::Modulo => KaramelOperatorType::Modulo,
+            KaramelKeywordType::FloorDivision => KaramelOperatorType::FloorDivision,
             KaramelKeywordType::Not => KaramelOperatorType::Not,
             KaramelKeywordType::Equal => KaramelOperatorType::Equal,
             KaramelKeywordType::NotEqual => KaramelOperatorType::NotEqual,
             KaramelKeywordType::GreaterThan => KaramelOperatorType::GreaterThan,
             KaramelKeywordType::GreaterEqualThan => KaramelOperatorType::GreaterEqualThan,
+            KaramelKeywordType::In => KaramelOperatorType::In,
             _ => KaramelOperatorType::None,
         }
     }
@@ -87,6 +109,8 @@ pub static KEYWORDS: &[(&str, KaramelKeywordType)] = &[
     ("veya", KaramelKeywordType::Or),
     ("boş", KaramelKeywordType::Empty),
     ("mod", KaramelKeywordType::Modulo),
+    ("tambölüm", KaramelKeywordType::FloorDivision),
+    ("tambolum", KaramelKeywordType::FloorDivision),
     ("değil", KaramelKeywordType::Not),
     ("degil", KaramelKeywordType::Not),
     ("fonk", KaramelKeywordType::Fn),
@@ -99,6 +123,10 @@ pub static KEYWORDS: &[(&str, KaramelKeywordType)] = &[
     ("dongu", KaramelKeywordType::While),
     ("yükle", KaramelKeywordType::Load),
     ("yukle", KaramelKeywordType::Load),
+    ("içinde", KaramelKeywordType::In),
+    ("icinde", KaramelKeywordType::In),
+    ("eşleştir", KaramelKeywordType::Match),
+    ("eslestir", KaramelKeywordType::Match),
 ];
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -108,6 +136,7 @@ pub enum KaramelOperatorType {
     Subtraction,
     Multiplication,
     Division,
+    FloorDivision,
     Modulo,
     Increment,
     Deccrement,
@@ -125,6 +154,8 @@ pub enum KaramelOperatorType {
     LessThan,
     GreaterEqualThan,
     LessEqualThan,
+    In,
+    NotIn,
     QuestionMark,
     ColonMark,
     LeftParentheses,
@@ -139,6 +170,13 @@ pub enum KaramelOperatorType {
     CommentMultilineEnd,
     CurveBracketStart,
     CurveBracketEnd,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseNot,
+    LeftShift,
+    RightShift,
+    Power,
 }
 
 impl KaramelOperatorType {
@@ -151,6 +189,7 @@ impl KaramelOperatorType {
 #[derive(Clone, Debug, PartialEq)]
 pub enum KaramelTokenType {
     Integer(i64),
+    BigInteger(Rc<BigInt>),
     Double(f64),
     Symbol(Rc<String>),
     Operator(KaramelOperatorType),
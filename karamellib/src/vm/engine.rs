@@ -0,0 +1,270 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use crate::buildin::Module;
+use crate::compiler::ast::KaramelAstType;
+use crate::compiler::capabilities::Capabilities;
+use crate::compiler::context::KaramelCompilerContext;
+use crate::compiler::convert::{FromKaramel, IntoKaramelArguments};
+use crate::compiler::function::FunctionReference;
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::compiler::{InterpreterCompiler, KaramelPrimative};
+use crate::error::{generate_error_message, KaramelError, KaramelErrorType};
+use crate::file::read_module_or_script;
+use crate::io::KaramelIo;
+use crate::logger::{write_stderr, LogRedirect};
+use crate::parser::Parser;
+use crate::syntax::util::update_functions_for_temp_return;
+use crate::syntax::SyntaxParser;
+use crate::types::VmObject;
+use crate::vm::executer::{get_execution_path, ExecutionSource};
+use crate::vm::interpreter::{call_function, run_vm};
+use log::LevelFilter;
+
+/// Keeps the compiled script, so the functions of the script could be called from Rust after the execution.
+///
+/// ```
+/// use karamellib::vm::engine::Engine;
+/// use karamellib::vm::executer::ExecutionSource;
+///
+/// let mut engine = Engine::new();
+/// engine.execute(ExecutionSource::Code("fonk topla(a, b): döndür a + b".to_string())).unwrap();
+///
+/// let result: i64 = engine.call("topla", (2, 3)).unwrap();
+/// assert_eq!(result, 5);
+/// ```
+pub struct Engine {
+    context: Box<KaramelCompilerContext>,
+    executed: bool,
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Engine {
+    pub fn new() -> Engine {
+        Engine {
+            context: Box::new(KaramelCompilerContext::new()),
+            executed: false,
+        }
+    }
+
+    /// Context of the script. Output, random seed and clock should be changed before the execution.
+    pub fn context(&self) -> &KaramelCompilerContext {
+        &self.context
+    }
+
+    pub fn context_mut(&mut self) -> &mut KaramelCompilerContext {
+        &mut self.context
+    }
+
+    /// Input and output streams of the script, standard streams of the process are used by default.
+    pub fn set_io(&mut self, io: Rc<dyn KaramelIo>) {
+        self.context.io = io;
+    }
+
+    /// Modules, functions and files that the script could use, should be changed before the execution.
+    pub fn set_capabilities(&mut self, capabilities: Capabilities) {
+        self.context.capabilities = capabilities;
+    }
+
+    /// Log messages of this engine above the level are dropped. Logger of the process should be installed with 'logger::init_logger'.
+    pub fn set_log_level(&mut self, level: LevelFilter) {
+        self.context.log_level = level;
+    }
+
+    pub fn add_module(&mut self, module: Rc<dyn Module>) {
+        self.context.add_module(module);
+    }
+
+    /// Compiles and executes the script. Values that left at the stack are returned.
+    pub fn execute(&mut self, source: ExecutionSource) -> Result<Vec<VmObject>, KaramelError> {
+        if !self.context.main_module.is_null() {
+            return Err(KaramelErrorType::ScriptAlreadyExecuted.into());
+        }
+
+        let _log_redirect = LogRedirect::new(self.context.io.clone(), self.context.log_level);
+        self.context.execution_path = get_execution_path(&source);
+        let data = match source {
+            ExecutionSource::Code(code) => code,
+            ExecutionSource::File(filename) => read_module_or_script(filename, &self.context).map_err(|error| self.report(error.into(), None))?,
+        };
+
+        let mut parser = Parser::new(&data);
+        parser
+            .parse()
+            .map_err(|error| self.report(error, Some(&data)))?;
+
+        let syntax = SyntaxParser::new(parser.tokens().to_vec());
+        let ast = syntax
+            .parse()
+            .map_err(|error| self.report(error, Some(&data)))?;
+
+        InterpreterCompiler {}
+            .compile(ast, &mut self.context)
+            .map_err(|error| self.report(error.into(), None))?;
+
+        let memory = unsafe { run_vm(&mut self.context, false, false) }.map_err(|error| self.report(error.into(), None))?;
+        self.executed = true;
+        Ok(memory)
+    }
+
+    /// Compiles and runs the code with the variables, functions and modules of the previous executions.
+    /// Value of the last expression is returned, 'boş' if the code ends with a statement.
+    pub fn eval(&mut self, code: &str) -> Result<VmObject, KaramelError> {
+        if self.context.main_module.is_null() {
+            self.execute(ExecutionSource::Code(String::new()))?;
+        }
+
+        let _log_redirect = LogRedirect::new(self.context.io.clone(), self.context.log_level);
+        let mut parser = Parser::new(code);
+        parser
+            .parse()
+            .map_err(|error| self.report(error, Some(code)))?;
+
+        let syntax = SyntaxParser::new(parser.tokens().to_vec());
+        let ast = syntax
+            .parse()
+            .map_err(|error| self.report(error, Some(code)))?;
+
+        /* Result of the last function call should be kept at the stack */
+        let last_ast = match &*ast {
+            KaramelAstType::Block(items) => items.last().cloned(),
+            _ => Some(ast.clone()),
+        };
+        let has_value = last_ast.as_deref().is_some_and(is_expression);
+        if let Some(last_ast) = &last_ast {
+            update_functions_for_temp_return(last_ast);
+        }
+
+        let variable_size = self.context.storages[0].variables.len();
+        let location = InterpreterCompiler {}
+            .compile_incremental(ast, &mut self.context)
+            .map_err(|error| self.report(error.into(), None))?;
+
+        let context = &mut *self.context;
+        unsafe {
+            /* New variables should not see the values of the previous executions */
+            for index in variable_size..context.storages[0].variables.len() {
+                context.stack[index] = EMPTY_OBJECT;
+            }
+
+            context.scope_index = 0;
+            context.current_scope = context.scopes_ptr;
+            context.opcodes_ptr = context.opcodes_top_ptr.add(location);
+        }
+
+        unsafe { run_vm(context, false, false) }.map_err(|error| self.report(error.into(), None))?;
+        self.executed = true;
+
+        /* Value of the last expression is at the top of the stack */
+        let context = &self.context;
+        let stack_size = unsafe { get_memory_index!(context) } as usize;
+        Ok(match has_value && stack_size > context.storages[0].variables.len() {
+            true => context.stack[stack_size - 1],
+            false => EMPTY_OBJECT,
+        })
+    }
+
+    /// Function of the script, functions of the other modules could be found with 'modül::isim'.
+    pub fn get_function(&self, name: &str) -> Option<Rc<FunctionReference>> {
+        let mut path = name
+            .split("::")
+            .map(|part| part.to_string())
+            .collect::<Vec<_>>();
+        let name = path.pop()?;
+
+        match path.is_empty() {
+            true if self.context.main_module.is_null() => None,
+            true => unsafe {
+                (*self.context.main_module)
+                    .functions
+                    .borrow()
+                    .get(&name)
+                    .cloned()
+            },
+            false => self.context.get_function(name, &path, 0),
+        }
+    }
+
+    /// Calls the function with the given name, arguments and the result are converted with the 'IntoKaramel' and 'FromKaramel'.
+    pub fn call<A: IntoKaramelArguments, R: FromKaramel>(&mut self, name: &str, arguments: A) -> Result<R, KaramelError> {
+        let function = self
+            .get_function(name)
+            .ok_or_else(|| KaramelErrorType::FunctionNotFound(name.to_string()))?;
+
+        let result = self.call_reference(&function, arguments.into_arguments())?;
+        R::from_karamel(&result).ok_or_else(|| {
+            KaramelErrorType::FunctionReturnTypeNotMatching {
+                function: function.name.to_string(),
+                expected: R::type_name(),
+            }
+            .into()
+        })
+    }
+
+    /// Calls the function with the arguments as is. Could be called many times, every call starts with a clean stack.
+    pub fn call_reference(&mut self, function: &Rc<FunctionReference>, arguments: Vec<VmObject>) -> Result<VmObject, KaramelErrorType> {
+        if !self.executed {
+            return Err(KaramelErrorType::ScriptNotExecuted);
+        }
+
+        let _log_redirect = LogRedirect::new(self.context.io.clone(), self.context.log_level);
+        let context = &mut *self.context;
+        unsafe {
+            context.scope_index = 0;
+            context.current_scope = context.scopes_ptr;
+            context.stack_ptr = context
+                .stack
+                .as_mut_ptr()
+                .add(context.storages[0].variables.len());
+
+            let result = call_function(context, VmObject::native_convert(KaramelPrimative::Function(function.clone(), None)), &arguments);
+            if let Err(error) = &result {
+                write_stderr(context, format!("Program hata ile sonlandırıldı: {}", error));
+            }
+            result
+        }
+    }
+
+    /// Stores the value of the host application, native functions could reach it with 'FunctionParameter::user_data'.
+    pub fn set_user_data<T: Any>(&mut self, value: T) {
+        self.context.set_user_data(value);
+    }
+
+    pub fn user_data<T: Any>(&self) -> Option<&T> {
+        self.context.get_user_data::<T>()
+    }
+
+    fn report(&self, error: KaramelError, data: Option<&str>) -> KaramelError {
+        let message = match data {
+            Some(data) => generate_error_message(data, &error),
+            None => format!("Program hata ile sonlandırıldı: {}", error.error_type),
+        };
+
+        write_stderr(&self.context, message);
+        error
+    }
+}
+
+/// Statements like assignments, loops and function definitions do not leave a value.
+fn is_expression(ast: &KaramelAstType) -> bool {
+    matches!(
+        ast,
+        KaramelAstType::FuncCall { .. }
+            | KaramelAstType::AccessorFuncCall { .. }
+            | KaramelAstType::Primative(_)
+            | KaramelAstType::Binary { .. }
+            | KaramelAstType::Control { .. }
+            | KaramelAstType::PrefixUnary { .. }
+            | KaramelAstType::Symbol(_)
+            | KaramelAstType::ModulePath(_)
+            | KaramelAstType::List(_)
+            | KaramelAstType::Dict(_)
+            | KaramelAstType::Tuple(_)
+            | KaramelAstType::Indexer { .. }
+    )
+}
@@ -1,11 +1,17 @@
 use std::borrow::Borrow;
 use std::cell::RefCell;
+use std::rc::Rc;
 
+use crate::buildin::date::Clock;
+use crate::buildin::random::RandomGenerator;
+use crate::buildin::Module;
+use crate::compiler::capabilities::Capabilities;
 use crate::compiler::context::{ExecutionPathInfo, KaramelCompilerContext};
 use crate::compiler::*;
-use crate::error::generate_error_message;
+use crate::error::{generate_error_message, KaramelWarningType};
 use crate::file::read_module_or_script;
-use crate::logger::{write_stderr, CONSOLE_LOGGER};
+use crate::io::{BufferIo, KaramelIo};
+use crate::logger::{write_stderr, LogRedirect};
 use crate::parser::*;
 use crate::syntax::SyntaxParser;
 use crate::{types::Token, vm::interpreter::run_vm};
@@ -24,6 +30,40 @@ pub struct ExecutionParameters {
     pub return_output: bool,
     pub dump_opcode: bool,
     pub dump_memory: bool,
+
+    /// Seed of the 'rastgele' module, same seed generates the same numbers at every execution
+    pub random_seed: Option<u64>,
+
+    /// Time source of the 'tarih' module, system clock used if it is not given
+    pub clock: Option<Rc<dyn Clock>>,
+
+    /// Modules, functions and files that the script could use. File system is not allowed by default
+    pub capabilities: Capabilities,
+
+    /// Native modules of the application, they could be used like the build-in modules
+    pub modules: Vec<Rc<dyn Module>>,
+
+    /// Input and output streams of the script. Output is collected into the status with 'return_output' if it is not given,
+    /// otherwise standard streams of the process are used
+    pub io: Option<Rc<dyn KaramelIo>>,
+}
+
+/// Runs an empty code with default capabilities; every output and dump option is disabled.
+impl Default for ExecutionParameters {
+    fn default() -> Self {
+        ExecutionParameters {
+            source: ExecutionSource::Code(String::new()),
+            return_opcode: false,
+            return_output: false,
+            dump_opcode: false,
+            dump_memory: false,
+            random_seed: None,
+            clock: None,
+            capabilities: Capabilities::default(),
+            modules: Vec::new(),
+            io: None,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -36,6 +76,14 @@ pub struct ExecutionStatus {
     pub opcodes: Option<Vec<Token>>,
     pub memory_dump: Option<String>,
     pub opcode_dump: Option<String>,
+    pub warnings: Vec<KaramelWarningType>,
+}
+
+fn collect_output(status: &mut ExecutionStatus, buffer: &Option<Rc<BufferIo>>) {
+    if let Some(buffer) = buffer {
+        status.stdout = Some(RefCell::new(buffer.stdout()));
+        status.stderr = Some(RefCell::new(buffer.stderr()));
+    }
 }
 
 pub fn get_execution_path<T: Borrow<ExecutionSource>>(source: T) -> ExecutionPathInfo {
@@ -56,32 +104,45 @@ pub fn get_execution_path<T: Borrow<ExecutionSource>>(source: T) -> ExecutionPat
 
 pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
     let mut status = ExecutionStatus::default();
-    if log::set_logger(&CONSOLE_LOGGER).is_ok() {
-        if cfg!(debug_assertions) {
-            log::set_max_level(log::LevelFilter::Debug)
-        } else {
-            log::set_max_level(log::LevelFilter::Info)
-        }
-    };
-
     let mut context: KaramelCompilerContext = KaramelCompilerContext::new();
     context.execution_path = get_execution_path(&parameters.source);
     log::debug!("Execution path: {}", context.execution_path.path);
 
-    if parameters.return_output {
-        context.stdout = Some(RefCell::new(String::new()));
-        context.stderr = Some(RefCell::new(String::new()));
+    if let Some(seed) = parameters.random_seed {
+        context.random = RefCell::new(RandomGenerator::new(seed));
+    }
+
+    if let Some(clock) = parameters.clock {
+        context.clock = clock;
     }
 
+    context.capabilities = parameters.capabilities;
+    for module in parameters.modules {
+        context.add_module(module);
+    }
+
+    /* Output is kept in memory only if the caller did not give its own streams */
+    let buffer = match (parameters.io, parameters.return_output) {
+        (Some(io), _) => {
+            context.io = io;
+            None
+        }
+        (None, true) => {
+            let buffer = Rc::new(BufferIo::new());
+            context.io = buffer.clone();
+            Some(buffer)
+        }
+        (None, false) => None,
+    };
+    let _log_redirect = LogRedirect::new(context.io.clone(), context.log_level);
+
     let data = match parameters.source {
         ExecutionSource::Code(code) => code,
         ExecutionSource::File(filename) => match read_module_or_script(filename, &context) {
             Ok(content) => content,
             Err(error) => {
                 write_stderr(&context, format!("Program hata ile sonlandırıldı: {}", error));
-                log::error!("Program hata ile sonlandırıldı: {}", error);
-                status.stdout = context.stdout;
-                status.stderr = context.stderr;
+                collect_output(&mut status, &buffer);
 
                 status.executed = false;
                 return status;
@@ -92,9 +153,7 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
     let mut parser = Parser::new(&data);
     if let Err(error) = parser.parse() {
         write_stderr(&context, generate_error_message(&data, &error));
-        log::error!("{}", generate_error_message(&data, &error));
-        status.stdout = context.stdout;
-        status.stderr = context.stderr;
+        collect_output(&mut status, &buffer);
 
         return status;
     };
@@ -104,9 +163,7 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
         Ok(ast) => ast,
         Err(error) => {
             write_stderr(&context, generate_error_message(&data, &error));
-            log::error!("{}", generate_error_message(&data, &error));
-            status.stdout = context.stdout;
-            status.stderr = context.stderr;
+            collect_output(&mut status, &buffer);
 
             return status;
         }
@@ -114,12 +171,17 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
 
     let opcode_compiler = InterpreterCompiler {};
     let execution_status = match opcode_compiler.compile(ast.clone(), &mut context) {
-        Ok(_) => unsafe { run_vm(&mut context, parameters.dump_opcode, parameters.dump_memory) },
+        Ok(_) => {
+            for warning in context.warnings.iter() {
+                log::warn!("{}", warning);
+            }
+
+            status.warnings = context.warnings.to_vec();
+            unsafe { run_vm(&mut context, parameters.dump_opcode, parameters.dump_memory) }
+        }
         Err(message) => {
             write_stderr(&context, format!("Program hata ile sonlandırıldı: {}", message));
-            log::error!("Program hata ile sonlandırıldı: {}", message);
-            status.stdout = context.stdout;
-            status.stderr = context.stderr;
+            collect_output(&mut status, &buffer);
 
             return status;
         }
@@ -133,9 +195,7 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
         }
         Err(error) => {
             write_stderr(&context, format!("Program hata ile sonlandırıldı: {}", error));
-            log::error!("Program hata ile sonlandırıldı: {}", error);
-            status.stdout = context.stdout;
-            status.stderr = context.stderr;
+            collect_output(&mut status, &buffer);
 
             return status;
         }
@@ -146,8 +206,7 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
         status.opcodes = Some(parser.tokens());
     }
 
-    status.stdout = context.stdout;
-    status.stderr = context.stderr;
+    collect_output(&mut status, &buffer);
     status.memory_dump = context.memory_dump;
     status.opcode_dump = context.opcode_dump;
 
@@ -1,10 +1,13 @@
 use crate::buildin::ClassProperty;
 use crate::compiler::context::KaramelCompilerContext;
+use crate::compiler::function::NativeCallResult;
+use crate::compiler::generator::call::CallFlag;
+use crate::compiler::number::{KaramelNumber, MAX_SHIFT_AMOUNT};
 use crate::compiler::scope::Scope;
 use crate::compiler::*;
 use crate::error::KaramelErrorType;
 use crate::types::VmObject;
-use crate::{dec_memory_index, get_memory_index, inc_memory_index, karamel_dbg, pop};
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::mem;
 use std::ptr;
@@ -38,454 +41,821 @@ pub unsafe fn dump_opcode<W: Write>(index: usize, context: &mut KaramelCompilerC
     }
 }
 
+/// Date and duration arithmetic result, overflowed values are empty.
+fn time_object(value: Option<i64>, builder: fn(i64) -> KaramelPrimative) -> VmObject {
+    match value {
+        Some(value) => VmObject::native_convert(builder(value)),
+        None => EMPTY_OBJECT,
+    }
+}
+
+/// Executes the opcodes from the current opcode position until the 'Halt' opcode.
+///
 /// # Safety
-pub unsafe fn run_vm(context: &mut KaramelCompilerContext, dump_code: bool, dump_memory: bool) -> Result<Vec<VmObject>, KaramelErrorType> {
-    #[cfg(any(feature = "liveOpcodeView", feature = "dumpOpcodes"))]
+/// Context should be prepared by the 'run_vm', stack and scope pointers are used as is.
+pub unsafe fn run_opcodes(context: &mut KaramelCompilerContext) -> Result<(), KaramelErrorType> {
+    #[cfg(feature = "liveOpcodeView")]
     let mut log_update = LogUpdate::new(stdout()).unwrap();
 
-    #[cfg(feature = "dumpMemory")]
-    {
-        context.storages[0].dump();
-    }
+    loop {
+        let opcode = mem::transmute::<u8, VmOpCode>(*context.opcodes_ptr);
+        #[cfg(feature = "liveOpcodeView")]
+        {
+            dump_opcode(context.opcode_index, context, &mut log_update);
+        }
 
-    if dump_code {
-        let generated = context.opcode_generator.dump(&context.opcodes);
-        context.opcode_dump = Some(generated);
-        //log_update.render(&generated[..]);
-    }
+        match karamel_dbg_any!(opcode) {
+            VmOpCode::Subraction => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
 
-    // Save top stack for main storage
-    let top_stack = context.stack.as_mut_ptr();
+                karamel_print_level2!("Subraction: {:?} - {:?}", left, right);
 
-    // Move stack pointer to forward. First slots are reserved for variable memories.
-    context.stack_ptr = top_stack.add(context.storages[0].variables.len());
-    context.storages_ptr = context.storages.as_mut_ptr();
-    {
-        context.scopes[context.scope_index] = Scope {
-            location: ptr::null_mut(),
-            call_return_assign_to_temp: false,
-            top_stack,
-            constant_ptr: context.storages[0].constants.as_ptr(),
-        };
+                *context.stack_ptr = match (left.as_numeric(), right.as_numeric()) {
+                    (Some(l_value), Some(r_value)) => VmObject::from(karamel_dbg!(l_value).sub(&karamel_dbg!(r_value))),
+                    _ => match (&left.deref_clean(), &right.deref_clean()) {
+                        (KaramelPrimative::Date(l_value), KaramelPrimative::Date(r_value)) => time_object(l_value.checked_sub(*r_value), KaramelPrimative::Duration),
+                        (KaramelPrimative::Date(l_value), KaramelPrimative::Duration(r_value)) => time_object(l_value.checked_sub(*r_value), KaramelPrimative::Date),
+                        (KaramelPrimative::Duration(l_value), KaramelPrimative::Duration(r_value)) => time_object(l_value.checked_sub(*r_value), KaramelPrimative::Duration),
+                        _ => EMPTY_OBJECT,
+                    },
+                };
+                inc_memory_index!(context, 1);
+                dump_data!(context, "result");
+            }
+
+            VmOpCode::Addition => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("Addition: {:?} + {:?}", left, right);
 
-        loop {
-            let opcode = mem::transmute::<u8, VmOpCode>(*context.opcodes_ptr);
-            #[cfg(feature = "liveOpcodeView")]
-            {
-                dump_opcode(context.opcode_index, context, &mut log_update);
+                *context.stack_ptr = match (left.as_numeric(), right.as_numeric()) {
+                    (Some(l_value), Some(r_value)) => VmObject::from(karamel_dbg!(l_value).add(&karamel_dbg!(r_value))),
+                    _ => match (&left.deref_clean(), &right.deref_clean()) {
+                        (KaramelPrimative::Text(l_value), KaramelPrimative::Text(r_value)) => VmObject::from(Rc::new((**l_value).to_owned() + &**r_value)),
+                        (KaramelPrimative::Date(date), KaramelPrimative::Duration(duration)) | (KaramelPrimative::Duration(duration), KaramelPrimative::Date(date)) => time_object(date.checked_add(*duration), KaramelPrimative::Date),
+                        (KaramelPrimative::Duration(l_value), KaramelPrimative::Duration(r_value)) => time_object(l_value.checked_add(*r_value), KaramelPrimative::Duration),
+                        _ => EMPTY_OBJECT,
+                    },
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
             }
 
-            match karamel_dbg_any!(opcode) {
-                VmOpCode::Subraction => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
+            VmOpCode::Load => {
+                let tmp = *context.opcodes_ptr.offset(1) as usize;
+                let scope = &mut *context.current_scope;
+                *context.stack_ptr = karamel_dbg!(*scope.top_stack.add(tmp));
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                karamel_print_level2!("Load: [{:?}]: {:?}", tmp, *context.stack_ptr);
+                dump_data!(context, "loaded");
+                inc_memory_index!(context, 1);
+            }
 
-                    karamel_print_level2!("Subraction: {:?} - {:?}", left, right);
+            VmOpCode::Constant => {
+                let tmp = *context.opcodes_ptr.offset(1) as usize;
+                let scope = &mut *context.current_scope;
+                *context.stack_ptr = karamel_dbg!(*scope.constant_ptr.add(tmp));
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                karamel_print_level2!("Constant: [{:?}]: {:?}", tmp, *context.stack_ptr);
+                dump_data!(context, "constant loaded");
+                inc_memory_index!(context, 1);
+            }
 
-                    *context.stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value), Some(r_value)) => VmObject::from(karamel_dbg!(l_value) - karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT,
-                    };
-                    inc_memory_index!(context, 1);
-                    dump_data!(context, "result");
-                }
+            VmOpCode::Store => {
+                let tmp = *context.opcodes_ptr.offset(1) as usize;
+                dec_memory_index!(context, 1);
+                *(*context.current_scope).top_stack.add(tmp) = karamel_dbg!(*context.stack_ptr);
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                karamel_print_level2!("Store: [{:?}]: {:?}", tmp, *context.stack_ptr);
+            }
 
-                VmOpCode::Addition => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
-                    karamel_print_level2!("Addition: {:?} + {:?}", left, right);
+            VmOpCode::CopyToStore => {
+                let tmp = *context.opcodes_ptr.offset(1) as usize;
+                *(*context.current_scope).top_stack.add(tmp) = karamel_dbg!(*context.stack_ptr.sub(1));
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                karamel_print_level2!("CopyToStore: [{:?}]: {:?}", tmp, *context.stack_ptr);
+            }
 
-                    *context.stack_ptr = match (&left.deref_clean(), &right.deref_clean()) {
-                        (KaramelPrimative::Number(l_value), KaramelPrimative::Number(r_value)) => VmObject::from(karamel_dbg!(l_value) + karamel_dbg!(r_value)),
-                        (KaramelPrimative::Text(l_value), KaramelPrimative::Text(r_value)) => VmObject::from(Rc::new((**l_value).to_owned() + &**r_value)),
+            VmOpCode::FastStore => {
+                let destination = *context.opcodes_ptr.offset(1) as usize;
+                let source = *context.opcodes_ptr.offset(2) as usize;
+                *(*context.current_scope).top_stack.add(destination) = karamel_dbg!(*(*context.current_scope).constant_ptr.add(source));
+                context.opcodes_ptr = context.opcodes_ptr.offset(2);
+                karamel_print_level2!(
+                    "FastStore: {:?}: {:?} => {:?}",
+                    *(*context.current_scope)
+                        .top_stack
+                        .offset(destination as isize),
+                    source,
+                    destination
+                );
+            }
+
+            VmOpCode::Not => {
+                *context.stack_ptr.sub(1) = VmObject::from(!(*context.stack_ptr.sub(1)).deref_clean().is_true());
+                dump_data!(context, "result");
+                karamel_print_level2!("Not: {:?}", *context.stack_ptr.sub(1));
+            }
+
+            VmOpCode::Dublicate => {
+                *context.stack_ptr = karamel_dbg!(*context.stack_ptr.sub(1));
+                karamel_print_level2!("Dublicate: {:?}", *context.stack_ptr);
+                inc_memory_index!(context, 1);
+            }
+
+            VmOpCode::DublicatePair => {
+                *context.stack_ptr = *context.stack_ptr.sub(2);
+                *context.stack_ptr.add(1) = *context.stack_ptr.sub(1);
+                karamel_print_level2!("DublicatePair: {:?} {:?}", *context.stack_ptr, *context.stack_ptr.add(1));
+                inc_memory_index!(context, 2);
+            }
+
+            VmOpCode::And => {
+                let left = pop!(context, "left");
+                let right = pop!(context, "right");
+                karamel_print_level2!("And: {:?} && {:?}", left, right);
+
+                *context.stack_ptr = VmObject::from(karamel_dbg!(left.is_true()) && karamel_dbg!(right.is_true()));
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
+
+            VmOpCode::Or => {
+                let left = pop!(context, "left");
+                let right = pop!(context, "right");
+                karamel_print_level2!("Or: {:?} || {:?}", left, right);
+
+                *context.stack_ptr = VmObject::from(karamel_dbg!(left.is_true()) || karamel_dbg!(right.is_true()));
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
+
+            VmOpCode::Multiply => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("Multiply: {:?} * {:?}", left, right);
+
+                *context.stack_ptr = match (left.as_numeric(), right.as_numeric()) {
+                    (Some(l_value), Some(r_value)) => VmObject::from(l_value.mul(&r_value)),
+                    (None, Some(r_value)) => match &*left.deref() {
+                        KaramelPrimative::Text(l_value) => VmObject::from((*l_value).repeat(r_value.as_f64() as usize)),
                         _ => EMPTY_OBJECT,
-                    };
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                }
+                    },
+                    _ => EMPTY_OBJECT,
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
 
-                VmOpCode::Load => {
-                    let tmp = *context.opcodes_ptr.offset(1) as usize;
-                    let scope = &mut *context.current_scope;
-                    *context.stack_ptr = karamel_dbg!(*scope.top_stack.add(tmp));
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                    karamel_print_level2!("Load: [{:?}]: {:?}", tmp, *context.stack_ptr);
-                    dump_data!(context, "loaded");
-                    inc_memory_index!(context, 1);
-                }
+            VmOpCode::Division => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("Division: {:?} / {:?}", left, right);
 
-                VmOpCode::Constant => {
-                    let tmp = *context.opcodes_ptr.offset(1) as usize;
-                    let scope = &mut *context.current_scope;
-                    *context.stack_ptr = karamel_dbg!(*scope.constant_ptr.add(tmp));
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                    karamel_print_level2!("Constant: [{:?}]: {:?}", tmp, *context.stack_ptr);
-                    dump_data!(context, "constant loaded");
-                    inc_memory_index!(context, 1);
-                }
+                let calculation = match (left.as_numeric(), right.as_numeric()) {
+                    (Some(l_value), Some(r_value)) => l_value.div(&r_value)?,
+                    _ => KaramelNumber::Float(f64::NAN),
+                };
 
-                VmOpCode::Store => {
-                    let tmp = *context.opcodes_ptr.offset(1) as usize;
-                    dec_memory_index!(context, 1);
-                    *(*context.current_scope).top_stack.add(tmp) = karamel_dbg!(*context.stack_ptr);
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                    karamel_print_level2!("Store: [{:?}]: {:?}", tmp, *context.stack_ptr);
-                }
+                *context.stack_ptr = if calculation.as_f64().is_nan() {
+                    EMPTY_OBJECT
+                } else {
+                    VmObject::from(calculation)
+                };
 
-                VmOpCode::CopyToStore => {
-                    let tmp = *context.opcodes_ptr.offset(1) as usize;
-                    *(*context.current_scope).top_stack.add(tmp) = karamel_dbg!(*context.stack_ptr.sub(1));
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                    karamel_print_level2!("CopyToStore: [{:?}]: {:?}", tmp, *context.stack_ptr);
-                }
+                inc_memory_index!(context, 1);
+            }
 
-                VmOpCode::FastStore => {
-                    let destination = *context.opcodes_ptr.offset(1) as usize;
-                    let source = *context.opcodes_ptr.offset(2) as usize;
-                    *(*context.current_scope).top_stack.add(destination) = karamel_dbg!(*(*context.current_scope).constant_ptr.add(source));
-                    context.opcodes_ptr = context.opcodes_ptr.offset(2);
-                    karamel_print_level2!(
-                        "FastStore: {:?}: {:?} => {:?}",
-                        *(*context.current_scope)
-                            .top_stack
-                            .offset(destination as isize),
-                        source,
-                        destination
-                    );
-                }
+            VmOpCode::Module => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("Module: {:?} / {:?}", left, right);
+
+                *context.stack_ptr = match (left.as_numeric(), right.as_numeric()) {
+                    (Some(l_value), Some(r_value)) => VmObject::from(karamel_dbg!(l_value).rem(&karamel_dbg!(r_value))?),
+                    _ => EMPTY_OBJECT,
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
 
-                VmOpCode::Not => {
-                    *context.stack_ptr.sub(1) = VmObject::from(!(*context.stack_ptr.sub(1)).deref_clean().is_true());
-                    dump_data!(context, "result");
-                    karamel_print_level2!("Not: {:?}", *context.stack_ptr.sub(1));
-                }
+            VmOpCode::FloorDivision => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("FloorDivision: {:?} tambölüm {:?}", left, right);
+
+                *context.stack_ptr = match (left.as_numeric(), right.as_numeric()) {
+                    (Some(l_value), Some(r_value)) => VmObject::from(karamel_dbg!(l_value).floor_div(&karamel_dbg!(r_value))?),
+                    _ => EMPTY_OBJECT,
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
 
-                VmOpCode::Dublicate => {
-                    *context.stack_ptr = karamel_dbg!(*context.stack_ptr.sub(1));
-                    karamel_print_level2!("Dublicate: {:?}", *context.stack_ptr);
-                    inc_memory_index!(context, 1);
-                }
+            VmOpCode::Power => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("Power: {:?} ** {:?}", left, right);
+
+                *context.stack_ptr = match (left.as_numeric(), right.as_numeric()) {
+                    (Some(l_value), Some(r_value)) => VmObject::from(karamel_dbg!(l_value).pow(&karamel_dbg!(r_value))?),
+                    _ => EMPTY_OBJECT,
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
 
-                VmOpCode::And => {
-                    let left = pop!(context, "left");
-                    let right = pop!(context, "right");
-                    karamel_print_level2!("And: {:?} && {:?}", left, right);
+            VmOpCode::BitwiseAnd | VmOpCode::BitwiseOr | VmOpCode::BitwiseXor | VmOpCode::LeftShift | VmOpCode::RightShift => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("{:?}: {:?} {:?}", opcode, left, right);
+
+                let l_value = left
+                    .as_numeric()
+                    .and_then(|number| number.to_integer())
+                    .ok_or_else(|| KaramelErrorType::IntegerExpected(left.deref()))?;
+                let r_value = right
+                    .as_numeric()
+                    .and_then(|number| number.to_integer())
+                    .ok_or_else(|| KaramelErrorType::IntegerExpected(right.deref()))?;
+
+                let result = match opcode {
+                    VmOpCode::BitwiseAnd => l_value.bitwise(&r_value, |l, r| l & r, |l, r| l & r),
+                    VmOpCode::BitwiseOr => l_value.bitwise(&r_value, |l, r| l | r, |l, r| l | r),
+                    VmOpCode::BitwiseXor => l_value.bitwise(&r_value, |l, r| l ^ r, |l, r| l ^ r),
+                    _ => {
+                        let amount = match r_value {
+                            KaramelNumber::Integer(amount) if (0..=MAX_SHIFT_AMOUNT).contains(&amount) => amount as usize,
+                            KaramelNumber::Integer(amount) => return Err(KaramelErrorType::ShiftAmountNotValid(amount)),
+                            _ => return Err(KaramelErrorType::ShiftAmountNotValid(i64::MAX)),
+                        };
+
+                        match opcode {
+                            VmOpCode::LeftShift => l_value.shift_left(amount),
+                            _ => l_value.shift_right(amount),
+                        }
+                    }
+                };
 
-                    *context.stack_ptr = VmObject::from(karamel_dbg!(left.is_true()) && karamel_dbg!(right.is_true()));
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                }
+                *context.stack_ptr = VmObject::from(result);
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
 
-                VmOpCode::Or => {
-                    let left = pop!(context, "left");
-                    let right = pop!(context, "right");
-                    karamel_print_level2!("Or: {:?} || {:?}", left, right);
+            VmOpCode::BitwiseNot => {
+                let value = *context.stack_ptr.sub(1);
+                karamel_print_level2!("BitwiseNot: {:?}", value);
 
-                    *context.stack_ptr = VmObject::from(karamel_dbg!(left.is_true()) || karamel_dbg!(right.is_true()));
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                }
+                let integer = value
+                    .as_numeric()
+                    .and_then(|number| number.to_integer())
+                    .ok_or_else(|| KaramelErrorType::IntegerExpected(value.deref()))?;
+                *context.stack_ptr.sub(1) = VmObject::from(integer.not());
+                dump_data!(context, "result");
+            }
+
+            VmOpCode::Equal => {
+                let right = pop!(context, "right");
+                let left = pop!(context, "left");
+                karamel_print_level2!("Equal: {:?} == {:?}", left, right);
 
-                VmOpCode::Multiply => {
-                    let right = pop!(context, "right");
-                    let left = pop!(context, "left");
-                    karamel_print_level2!("Multiply: {:?} * {:?}", left, right);
+                *context.stack_ptr = VmObject::from(karamel_dbg!(left) == karamel_dbg!(right));
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
 
-                    *context.stack_ptr = match (&*left, &*right) {
-                        (KaramelPrimative::Number(l_value), KaramelPrimative::Number(r_value)) => VmObject::from(*l_value * *r_value),
-                        (KaramelPrimative::Text(l_value), KaramelPrimative::Number(r_value)) => VmObject::from((*l_value).repeat((*r_value) as usize)),
+            VmOpCode::NotEqual => {
+                let right = pop!(context, "right");
+                let left = pop!(context, "left");
+                karamel_print_level2!("NotEqual: {:?} != {:?}", left, right);
+
+                *context.stack_ptr = VmObject::from(karamel_dbg!(left) != karamel_dbg!(right));
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
+
+            VmOpCode::GreaterThan => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("GreaterThan: {:?} > {:?}", left, right);
+
+                *context.stack_ptr = match (left.as_numeric(), right.as_numeric()) {
+                    (Some(l_value), Some(r_value)) => VmObject::from(
+                        karamel_dbg!(l_value)
+                            .compare(&karamel_dbg!(r_value))
+                            .is_some_and(|ordering| ordering == Ordering::Greater),
+                    ),
+                    _ => match (&left.deref_clean(), &right.deref_clean()) {
+                        (KaramelPrimative::Date(l_value), KaramelPrimative::Date(r_value)) | (KaramelPrimative::Duration(l_value), KaramelPrimative::Duration(r_value)) => VmObject::from(l_value.cmp(r_value) == Ordering::Greater),
                         _ => EMPTY_OBJECT,
-                    };
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                }
+                    },
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
 
-                VmOpCode::Division => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
-                    karamel_print_level2!("Division: {:?} / {:?}", left, right);
+            VmOpCode::GreaterEqualThan => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("GreaterEqualThan {:?} >= {:?}", left, right);
+
+                *context.stack_ptr = match (left.as_numeric(), right.as_numeric()) {
+                    (Some(l_value), Some(r_value)) => VmObject::from(
+                        karamel_dbg!(l_value)
+                            .compare(&karamel_dbg!(r_value))
+                            .is_some_and(|ordering| ordering != Ordering::Less),
+                    ),
+                    _ => match (&left.deref_clean(), &right.deref_clean()) {
+                        (KaramelPrimative::Date(l_value), KaramelPrimative::Date(r_value)) | (KaramelPrimative::Duration(l_value), KaramelPrimative::Duration(r_value)) => VmObject::from(l_value.cmp(r_value) != Ordering::Less),
+                        _ => EMPTY_OBJECT,
+                    },
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
 
-                    let calculation = match (left.as_number(), right.as_number()) {
-                        (Some(l_value), Some(r_value)) => l_value / r_value,
-                        _ => f64::NAN,
-                    };
+            VmOpCode::In => {
+                let collection = pop!(context, "collection");
+                let item = pop!(context, "item");
+                karamel_print_level2!("In: {:?} in {:?}", item, collection);
+
+                let status = match (&*item, &*collection) {
+                    (_, KaramelPrimative::List(list)) => list
+                        .borrow()
+                        .iter()
+                        .any(|list_item| *list_item.deref() == *item),
+                    (_, KaramelPrimative::Tuple(items)) => items.iter().any(|tuple_item| *tuple_item.deref() == *item),
+                    (_, KaramelPrimative::Dict(dict)) => match KaramelDictKey::new(&item) {
+                        Some(key) => dict.borrow().contains_key(&key),
+                        None => false,
+                    },
+                    (_, KaramelPrimative::Set(items)) => match KaramelDictKey::hashable(&item) {
+                        Some(key) => items.borrow().contains(&key),
+                        None => false,
+                    },
+                    (KaramelPrimative::Text(search), KaramelPrimative::Text(text)) => text.contains(search.as_str()),
+                    (_, KaramelPrimative::Text(_)) => false,
+                    _ => return Err(KaramelErrorType::MembershipNotSupported(collection.clone())),
+                };
+
+                *context.stack_ptr = VmObject::from(karamel_dbg!(status));
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
 
-                    *context.stack_ptr = if calculation.is_nan() {
-                        EMPTY_OBJECT
-                    } else {
-                        VmObject::from(calculation)
-                    };
+            VmOpCode::MatchList => {
+                let size = *context.opcodes_ptr.offset(1) as usize;
+                let has_rest = *context.opcodes_ptr.offset(2) != 0;
+                let value = pop!(context, "value");
+                karamel_print_level2!("MatchList: {:?} {:?} {:?}", value, size, has_rest);
+
+                let status = match &*value {
+                    KaramelPrimative::List(list) if has_rest => list.borrow().len() >= size,
+                    KaramelPrimative::List(list) => list.borrow().len() == size,
+                    KaramelPrimative::Tuple(items) if has_rest => items.len() >= size,
+                    KaramelPrimative::Tuple(items) => items.len() == size,
+                    _ => false,
+                };
+
+                *context.stack_ptr = VmObject::from(karamel_dbg!(status));
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+                context.opcodes_ptr = context.opcodes_ptr.offset(2);
+            }
 
-                    inc_memory_index!(context, 1);
-                }
+            VmOpCode::MatchDict => {
+                let value = pop!(context, "value");
+                karamel_print_level2!("MatchDict: {:?}", value);
 
-                VmOpCode::Module => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
-                    karamel_print_level2!("Module: {:?} / {:?}", left, right);
+                *context.stack_ptr = VmObject::from(karamel_dbg!(matches!(&*value, KaramelPrimative::Dict(_))));
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
 
-                    *context.stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value), Some(r_value)) => VmObject::from(karamel_dbg!(l_value) % karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT,
-                    };
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                }
+            VmOpCode::MatchItem => {
+                let key = pop!(context, "key");
+                let collection = pop!(context, "collection");
+                karamel_print_level2!("MatchItem: {:?} {:?}", collection, key);
+
+                *context.stack_ptr = match (&*collection, &*key) {
+                    (KaramelPrimative::List(list), index) if index.as_f64().is_some() => list
+                        .borrow()
+                        .get(index.as_f64().unwrap_or_default() as usize)
+                        .copied()
+                        .unwrap_or(EMPTY_OBJECT),
+                    (KaramelPrimative::Tuple(items), index) if index.as_f64().is_some() => items
+                        .get(index.as_f64().unwrap_or_default() as usize)
+                        .copied()
+                        .unwrap_or(EMPTY_OBJECT),
+                    (KaramelPrimative::Dict(dict), KaramelPrimative::Text(key)) => dict
+                        .borrow()
+                        .get(&KaramelDictKey::Text(key.clone()))
+                        .copied()
+                        .unwrap_or(EMPTY_OBJECT),
+                    _ => EMPTY_OBJECT,
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            }
 
-                VmOpCode::Equal => {
-                    let right = pop!(context, "right");
-                    let left = pop!(context, "left");
-                    karamel_print_level2!("Equal: {:?} == {:?}", left, right);
+            VmOpCode::ListTail => {
+                let start = *context.opcodes_ptr.offset(1) as usize;
+                let value = pop!(context, "value");
+                karamel_print_level2!("ListTail: {:?} {:?}", value, start);
+
+                *context.stack_ptr = match &*value {
+                    KaramelPrimative::List(list) => VmObject::from(
+                        list.borrow()
+                            .iter()
+                            .skip(start)
+                            .copied()
+                            .collect::<Vec<VmObject>>(),
+                    ),
+                    _ => EMPTY_OBJECT,
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            }
 
-                    *context.stack_ptr = VmObject::from(karamel_dbg!(left) == karamel_dbg!(right));
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
+            VmOpCode::Unpack => {
+                let size = *context.opcodes_ptr.offset(1) as usize;
+                let value = pop!(context, "value");
+                karamel_print_level2!("Unpack: {:?} {:?}", value, size);
+
+                let items = match &*value {
+                    KaramelPrimative::List(list) => list.borrow().clone(),
+                    KaramelPrimative::Tuple(items) => items.clone(),
+                    KaramelPrimative::Set(items) => items
+                        .borrow()
+                        .iter()
+                        .map(|item| VmObject::native_convert(item.to_primative()))
+                        .collect(),
+                    _ => return Err(KaramelErrorType::DestructuringNotSupported(value.clone())),
+                };
+
+                if items.len() != size {
+                    return Err(KaramelErrorType::DestructuringSizeNotMatching { expected: size, found: items.len() });
                 }
 
-                VmOpCode::NotEqual => {
-                    let right = pop!(context, "right");
-                    let left = pop!(context, "left");
-                    karamel_print_level2!("NotEqual: {:?} != {:?}", left, right);
-
-                    *context.stack_ptr = VmObject::from(karamel_dbg!(left) != karamel_dbg!(right));
-                    dump_data!(context, "result");
+                for item in items.iter().rev() {
+                    *context.stack_ptr = *item;
                     inc_memory_index!(context, 1);
                 }
 
-                VmOpCode::GreaterThan => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
-                    karamel_print_level2!("GreaterThan: {:?} > {:?}", left, right);
+                dump_data!(context, "result");
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            }
 
-                    *context.stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value), Some(r_value)) => VmObject::from(karamel_dbg!(l_value) > karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT,
-                    };
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
+            VmOpCode::UnpackDict => {
+                let size = *context.opcodes_ptr.offset(1) as usize;
+                let mut keys = Vec::with_capacity(size);
+                for _ in 0..size {
+                    keys.push(pop!(context, "key"));
                 }
 
-                VmOpCode::GreaterEqualThan => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
-                    karamel_print_level2!("GreaterEqualThan {:?} >= {:?}", left, right);
+                let value = pop!(context, "value");
+                karamel_print_level2!("UnpackDict: {:?} {:?}", value, keys);
 
-                    *context.stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value), Some(r_value)) => VmObject::from(karamel_dbg!(l_value) >= karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT,
+                let dict = match &*value {
+                    KaramelPrimative::Dict(dict) => dict,
+                    _ => return Err(KaramelErrorType::DestructuringNotSupported(value.clone())),
+                };
+
+                /* Keys are popped in reverse order, so the last key's value pushed first */
+                for key in keys.iter() {
+                    let item = KaramelDictKey::new(key).and_then(|key| dict.borrow().get(&key).copied());
+                    *context.stack_ptr = match item {
+                        Some(item) => item,
+                        None => return Err(KaramelErrorType::DestructuringKeyNotFound(key.get_text())),
                     };
-                    dump_data!(context, "result");
                     inc_memory_index!(context, 1);
                 }
 
-                VmOpCode::Call => {
-                    let func_location = *context.opcodes_ptr.offset(1) as usize;
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-
-                    let value = (*(*context.current_scope).constant_ptr.add(func_location)).deref();
+                dump_data!(context, "result");
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            }
 
-                    karamel_print_level2!("Call: {:?}", value);
-                    if let KaramelPrimative::Function(reference, _) = karamel_dbg!(&*value) {
-                        reference.execute(context, None)?;
-                    } else {
-                        return Err(KaramelErrorType::NotCallable(value.clone()));
-                    }
-                }
+            VmOpCode::Call => {
+                let func_location = *context.opcodes_ptr.offset(1) as usize;
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
 
-                VmOpCode::CallStack => {
-                    let function = pop_raw!(context, "function");
-                    let value = function.deref();
-                    karamel_print_level2!("CallStack {:?}", value);
+                let value = (*(*context.current_scope).constant_ptr.add(func_location)).deref();
 
-                    match &*value {
-                        KaramelPrimative::Function(reference, base) => reference.execute(context, *base)?,
-                        _ => {
-                            log::debug!("{:?} not callable", &*function.deref());
-                            return Err(KaramelErrorType::NotCallable(value.clone()));
-                        }
-                    };
+                karamel_print_level2!("Call: {:?}", value);
+                if let KaramelPrimative::Function(reference, _) = karamel_dbg!(&*value) {
+                    reference.execute(context, None)?;
+                } else {
+                    return Err(KaramelErrorType::NotCallable(value.clone()));
                 }
+            }
 
-                VmOpCode::Return => {
-                    let return_value = *context.stack_ptr.sub(1);
-                    context.opcodes_ptr = (*context.current_scope).location;
-                    let call_return_assign_to_temp = (*context.current_scope).call_return_assign_to_temp;
-                    context.scope_index -= 1;
-
-                    context.stack_ptr = (*context.current_scope).top_stack;
-                    context.current_scope = context.scopes_ptr.add(context.scope_index);
-
-                    if call_return_assign_to_temp {
-                        *context.stack_ptr = return_value;
-                        karamel_print_level2!("Return [{:?}] {:?}", get_memory_index!(context), *context.stack_ptr);
-                        inc_memory_index!(context, 1);
-                    } else {
-                        karamel_print_level2!("Return");
+            VmOpCode::CallStack => {
+                let function = pop_raw!(context, "function");
+                let value = function.deref();
+                karamel_print_level2!("CallStack {:?}", value);
+
+                match &*value {
+                    KaramelPrimative::Function(reference, base) => reference.execute(context, *base)?,
+                    _ => {
+                        log::debug!("{:?} not callable", &*function.deref());
+                        return Err(KaramelErrorType::NotCallable(value.clone()));
                     }
-                }
+                };
+            }
 
-                VmOpCode::Increment => {
-                    karamel_print_level2!("Increment");
-                    *context.stack_ptr.sub(1) = match (*context.stack_ptr.sub(1)).as_number() {
-                        Some(value) => VmObject::from(karamel_dbg!(value + 1_f64)),
-                        _ => EMPTY_OBJECT,
-                    };
-                }
+            VmOpCode::Return => {
+                let return_value = *context.stack_ptr.sub(1);
+                context.opcodes_ptr = (*context.current_scope).location;
+                let call_return_assign_to_temp = (*context.current_scope).call_return_assign_to_temp;
+                context.scope_index -= 1;
 
-                VmOpCode::Decrement => {
-                    karamel_print_level2!("Increment");
-                    *context.stack_ptr.sub(1) = match (*context.stack_ptr.sub(1)).as_number() {
-                        Some(value) => VmObject::from(value - 1_f64),
-                        _ => EMPTY_OBJECT,
-                    };
+                context.stack_ptr = (*context.current_scope).top_stack;
+                context.current_scope = context.scopes_ptr.add(context.scope_index);
+
+                if call_return_assign_to_temp {
+                    *context.stack_ptr = return_value;
+                    karamel_print_level2!("Return [{:?}] {:?}", get_memory_index!(context), *context.stack_ptr);
+                    inc_memory_index!(context, 1);
+                } else {
+                    karamel_print_level2!("Return");
                 }
+            }
 
-                VmOpCode::Init => {
-                    let init_type = *context.opcodes_ptr.offset(1) as usize;
-                    let total_item = *context.opcodes_ptr.offset(2) as usize;
-                    karamel_print_level2!("Init: {:?} {:?}", init_type, total_item);
+            VmOpCode::Increment => {
+                karamel_print_level2!("Increment");
+                *context.stack_ptr.sub(1) = match (*context.stack_ptr.sub(1)).as_numeric() {
+                    Some(value) => VmObject::from(karamel_dbg!(value.add(&KaramelNumber::Integer(1)))),
+                    _ => EMPTY_OBJECT,
+                };
+            }
+
+            VmOpCode::Decrement => {
+                karamel_print_level2!("Increment");
+                *context.stack_ptr.sub(1) = match (*context.stack_ptr.sub(1)).as_numeric() {
+                    Some(value) => VmObject::from(value.sub(&KaramelNumber::Integer(1))),
+                    _ => EMPTY_OBJECT,
+                };
+            }
 
-                    *context.stack_ptr = match init_type {
-                        // Dict
-                        0 => {
-                            let mut dict = HashMap::new();
+            VmOpCode::Init => {
+                let init_type = *context.opcodes_ptr.offset(1) as usize;
+                let total_item = *context.opcodes_ptr.offset(2) as usize;
+                karamel_print_level2!("Init: {:?} {:?}", init_type, total_item);
 
-                            for _ in 0..total_item {
-                                let value = pop_raw!(context, "value");
-                                let key = pop!(context, "key");
+                *context.stack_ptr = match init_type {
+                    // Dict
+                    0 => {
+                        let mut dict = HashMap::new();
 
-                                dict.insert(key.get_text(), value);
-                            }
+                        for _ in 0..total_item {
+                            let value = pop_raw!(context, "value");
+                            let key = pop!(context, "key");
 
-                            VmObject::from(dict)
+                            match KaramelDictKey::new(&key) {
+                                Some(key) => dict.insert(key, value),
+                                None => return Err(KaramelErrorType::DictKeyNotValid(key)),
+                            };
                         }
 
-                        // List
-                        1 => {
-                            let mut list = Vec::with_capacity(total_item);
+                        VmObject::from(dict)
+                    }
 
-                            for _i in 0..total_item {
-                                list.push(pop_raw!(context, _i));
-                            }
+                    // List
+                    1 => {
+                        let mut list = Vec::with_capacity(total_item);
 
-                            VmObject::from(list)
+                        for _i in 0..total_item {
+                            list.push(pop_raw!(context, _i));
                         }
-                        _ => return Err(KaramelErrorType::GeneralError("Geçersiz yükleme tipi".to_string())),
-                    };
 
-                    inc_memory_index!(context, 1);
-                    context.opcodes_ptr = context.opcodes_ptr.offset(2);
-                }
+                        VmObject::from(list)
+                    }
 
-                VmOpCode::Compare => {
-                    let condition = pop_raw!(context, "condition");
-                    karamel_print_level2!("Compare: {:?}", condition);
+                    // Tuple
+                    2 => {
+                        let mut items = Vec::with_capacity(total_item);
 
-                    let status = match &condition.deref_clean() {
-                        KaramelPrimative::Empty => false,
-                        KaramelPrimative::Bool(l_value) => *l_value,
-                        KaramelPrimative::Number(l_value) => *l_value > 0.0,
-                        KaramelPrimative::Text(l_value) => !(*l_value).is_empty(),
-                        _ => false,
-                    };
+                        for _i in 0..total_item {
+                            items.push(pop_raw!(context, _i));
+                        }
 
-                    if status {
-                        context.opcodes_ptr = context.opcodes_ptr.offset(2);
-                    } else {
-                        let location = ((*context.opcodes_ptr.offset(2) as u16 * 256) + *context.opcodes_ptr.offset(1) as u16) as usize;
-                        context.opcodes_ptr = context.opcodes_ptr.add(location);
-                        continue;
+                        VmObject::native_convert(KaramelPrimative::Tuple(items))
                     }
-                }
+                    _ => return Err(KaramelErrorType::GeneralError("Geçersiz yükleme tipi".to_string())),
+                };
+
+                inc_memory_index!(context, 1);
+                context.opcodes_ptr = context.opcodes_ptr.offset(2);
+            }
 
-                VmOpCode::Jump => {
+            VmOpCode::Compare => {
+                let condition = pop_raw!(context, "condition");
+                karamel_print_level2!("Compare: {:?}", condition);
+
+                let status = match &condition.deref_clean() {
+                    KaramelPrimative::Empty => false,
+                    KaramelPrimative::Bool(l_value) => *l_value,
+                    KaramelPrimative::Number(_) | KaramelPrimative::Integer(_) | KaramelPrimative::BigInteger(_) => condition
+                        .as_numeric()
+                        .is_some_and(|number| number.is_positive()),
+                    KaramelPrimative::Text(l_value) => !(*l_value).is_empty(),
+                    _ => false,
+                };
+
+                if status {
+                    context.opcodes_ptr = context.opcodes_ptr.offset(2);
+                } else {
                     let location = ((*context.opcodes_ptr.offset(2) as u16 * 256) + *context.opcodes_ptr.offset(1) as u16) as usize;
-                    karamel_print_level2!("Jump: {:?}", location);
-                    context.opcodes_ptr = context.opcodes.as_mut_ptr().add(location);
+                    context.opcodes_ptr = context.opcodes_ptr.add(location);
                     continue;
                 }
+            }
 
-                VmOpCode::SetItem => {
-                    let assign_item = pop_raw!(context, "assign_item");
-                    let indexer = pop!(context, "indexer");
-                    let raw_object = pop_raw!(context, "raw_object");
-                    let object = raw_object.deref();
-                    karamel_print_level2!("GetItem: object={:?}, indexer={:?}, item={:?}", object, indexer, assign_item);
-
-                    // todo: change all those codes with setter implementation
-                    match &*object {
-                        KaramelPrimative::List(value) => {
-                            let indexer_value = match &*indexer {
-                                KaramelPrimative::Number(number) => *number as usize,
-                                _ => return Err(KaramelErrorType::IndexerMustBeNumber(indexer.clone())),
-                            };
-
-                            value.borrow_mut()[indexer_value] = assign_item;
-                        }
-                        KaramelPrimative::Dict(value) => {
-                            let indexer_value = match &*indexer {
-                                KaramelPrimative::Text(text) => text,
-                                _ => return Err(KaramelErrorType::IndexerMustBeString(indexer.clone())),
-                            };
-
-                            value
-                                .borrow_mut()
-                                .insert(indexer_value.to_string(), assign_item);
-                        }
-                        KaramelPrimative::Text(_) => {
-                            let indexer_value = match &*indexer {
-                                KaramelPrimative::Number(number) => *number,
-                                _ => return Err(KaramelErrorType::IndexerMustBeNumber(indexer.clone())),
-                            };
+            VmOpCode::Jump => {
+                let location = ((*context.opcodes_ptr.offset(2) as u16 * 256) + *context.opcodes_ptr.offset(1) as u16) as usize;
+                karamel_print_level2!("Jump: {:?}", location);
+                context.opcodes_ptr = context.opcodes.as_mut_ptr().add(location);
+                continue;
+            }
 
-                            match context.get_class(&object).get_setter() {
-                                Some(function) => function(raw_object, indexer_value, assign_item)?,
-                                _ => EMPTY_OBJECT,
-                            };
-                        }
+            VmOpCode::SetItem => {
+                let assign_item = pop_raw!(context, "assign_item");
+                let indexer = pop!(context, "indexer");
+                let raw_object = pop_raw!(context, "raw_object");
+                let object = raw_object.deref();
+                karamel_print_level2!("GetItem: object={:?}, indexer={:?}, item={:?}", object, indexer, assign_item);
+
+                // todo: change all those codes with setter implementation
+                match &*object {
+                    KaramelPrimative::List(value) => {
+                        let indexer_value = match indexer.as_f64() {
+                            Some(number) => number as usize,
+                            _ => return Err(KaramelErrorType::IndexerMustBeNumber(indexer.clone())),
+                        };
+
+                        value.borrow_mut()[indexer_value] = assign_item;
+                    }
+                    KaramelPrimative::Dict(value) => {
+                        let indexer_value = match (KaramelDictKey::new(&indexer), &*indexer) {
+                            (Some(key), _) => key,
+                            (None, KaramelPrimative::Tuple(_)) => return Err(KaramelErrorType::DictKeyNotValid(indexer.clone())),
+                            (None, _) => return Err(KaramelErrorType::IndexerMustBeString(indexer.clone())),
+                        };
+
+                        value.borrow_mut().insert(indexer_value, assign_item);
+                    }
+                    KaramelPrimative::Tuple(_) => return Err(KaramelErrorType::TupleNotMutable),
+                    KaramelPrimative::Text(_) => {
+                        let indexer_value = match indexer.as_f64() {
+                            Some(number) => number,
+                            _ => return Err(KaramelErrorType::IndexerMustBeNumber(indexer.clone())),
+                        };
+
+                        match context.get_class(&object).get_setter() {
+                            Some(function) => function(raw_object, indexer_value, assign_item)?,
+                            _ => EMPTY_OBJECT,
+                        };
+                    }
 
-                        _ => (),
-                    };
-                }
+                    _ => (),
+                };
+            }
 
-                VmOpCode::GetItem => {
-                    let indexer = pop!(context, "indexer");
-                    let raw_object = pop_raw!(context, "raw_object");
-                    let object = &*raw_object.deref();
-                    karamel_print_level2!("GetItem: object={:?}, indexer={:?}", object, indexer);
-
-                    *context.stack_ptr = match &*indexer {
-                        KaramelPrimative::Text(text) => {
-                            match context
-                                .get_class(object)
-                                .get_element(Some(raw_object), text.clone())
-                            {
-                                Some(element) => match element {
-                                    ClassProperty::Function(function) => VmObject::from(Rc::new(KaramelPrimative::Function(function.clone(), Some(raw_object)))),
-                                    ClassProperty::Field(field) => VmObject::from(field.clone()),
-                                },
-                                _ => EMPTY_OBJECT,
-                            }
-                        }
-                        KaramelPrimative::Number(index) => match context.get_class(object).get_getter() {
-                            Some(function) => function(raw_object, *index)?,
+            VmOpCode::GetItem => {
+                let indexer = pop!(context, "indexer");
+                let raw_object = pop_raw!(context, "raw_object");
+                let object = &*raw_object.deref();
+                karamel_print_level2!("GetItem: object={:?}, indexer={:?}", object, indexer);
+
+                *context.stack_ptr = match &*indexer {
+                    KaramelPrimative::Text(text) => {
+                        match context
+                            .get_class(object)
+                            .get_element(Some(raw_object), text.clone())
+                        {
+                            Some(element) => match element {
+                                ClassProperty::Function(function) => VmObject::from(Rc::new(KaramelPrimative::Function(function.clone(), Some(raw_object)))),
+                                ClassProperty::Field(field) => VmObject::from(field.clone()),
+                            },
                             _ => EMPTY_OBJECT,
-                        },
+                        }
+                    }
+                    KaramelPrimative::Number(_) | KaramelPrimative::Integer(_) | KaramelPrimative::BigInteger(_) => match (context.get_class(object).get_getter(), indexer.as_f64()) {
+                        (Some(function), Some(index)) => function(raw_object, index)?,
                         _ => EMPTY_OBJECT,
-                    };
-
-                    inc_memory_index!(context, 1);
-                }
+                    },
+                    KaramelPrimative::Tuple(_) => match (object, KaramelDictKey::new(&indexer)) {
+                        (KaramelPrimative::Dict(dict), Some(key)) => dict.borrow().get(&key).copied().unwrap_or(EMPTY_OBJECT),
+                        _ => EMPTY_OBJECT,
+                    },
+                    _ => EMPTY_OBJECT,
+                };
 
-                VmOpCode::Halt => {
-                    karamel_print_level2!("Halt");
-                    break;
-                }
+                inc_memory_index!(context, 1);
             }
 
-            context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            VmOpCode::Halt => {
+                karamel_print_level2!("Halt");
+                break;
+            }
         }
 
+        context.opcodes_ptr = context.opcodes_ptr.offset(1);
+    }
+
+    Ok(())
+}
+
+/// Native stack that could be used by the nested calls from native functions. Every call runs a new opcode loop
+/// and the frames of the loop are large in debug builds, so the limit is measured in bytes instead of calls.
+pub const MAX_CALL_STACK_SIZE: usize = 512 * 1024;
+
+/// Calls the function value and returns its result. Arguments are placed after the current stack position, so the
+/// native function that calls it keeps its own arguments. Opcode, stack and scope positions are restored after the call.
+///
+/// # Safety
+/// Context should be executing or executed by the 'run_vm'.
+pub unsafe fn call_function(context: &mut KaramelCompilerContext, function: VmObject, arguments: &[VmObject]) -> NativeCallResult {
+    let reference = match &*function.deref() {
+        KaramelPrimative::Function(reference, _) => reference.clone(),
+        value => return Err(KaramelErrorType::NotCallable(Rc::new(value.clone()))),
+    };
+
+    let argument_size = u8::try_from(arguments.len()).map_err(|_| KaramelErrorType::FunctionArgumentNotMatching {
+        function: reference.name.to_string(),
+        expected: reference.arguments.len() as u8,
+        found: u8::MAX,
+    })?;
+
+    /* Position of the first call is kept, nested calls are compared with it */
+    let marker = 0_u8;
+    let position = &marker as *const u8 as usize;
+    let first_call = context.call_stack_base == 0;
+    match first_call {
+        true => context.call_stack_base = position,
+        false if context.call_stack_base.saturating_sub(position) > MAX_CALL_STACK_SIZE => return Err(KaramelErrorType::CallDepthExceeded),
+        false => (),
+    }
+
+    /* Small program that calls the function at the top of the stack and stops */
+    let mut trampoline = [VmOpCode::CallStack.into(), argument_size, CallFlag::ASSIGN_TO_TEMP.bits(), VmOpCode::Halt.into()];
+    let (opcodes_ptr, stack_ptr, scope_index, current_scope) = (context.opcodes_ptr, context.stack_ptr, context.scope_index, context.current_scope);
+
+    for argument in arguments {
+        *context.stack_ptr = *argument;
+        inc_memory_index!(context, 1);
+    }
+
+    *context.stack_ptr = function;
+    inc_memory_index!(context, 1);
+
+    context.opcodes_ptr = trampoline.as_mut_ptr();
+    let result = run_opcodes(context).map(|_| *context.stack_ptr.sub(1));
+    if first_call {
+        context.call_stack_base = 0;
+    }
+
+    context.opcodes_ptr = opcodes_ptr;
+    context.stack_ptr = stack_ptr;
+    context.scope_index = scope_index;
+    context.current_scope = current_scope;
+    result
+}
+
+/// # Safety
+pub unsafe fn run_vm(context: &mut KaramelCompilerContext, dump_code: bool, dump_memory: bool) -> Result<Vec<VmObject>, KaramelErrorType> {
+    #[cfg(any(feature = "liveOpcodeView", feature = "dumpOpcodes"))]
+    let mut log_update = LogUpdate::new(stdout()).unwrap();
+
+    #[cfg(feature = "dumpMemory")]
+    {
+        context.storages[0].dump();
+    }
+
+    if dump_code {
+        let generated = context.opcode_generator.dump(&context.opcodes);
+        context.opcode_dump = Some(generated);
+        //log_update.render(&generated[..]);
+    }
+
+    // Save top stack for main storage
+    let top_stack = context.stack.as_mut_ptr();
+
+    // Move stack pointer to forward. First slots are reserved for variable memories.
+    context.stack_ptr = top_stack.add(context.storages[0].variables.len());
+    context.storages_ptr = context.storages.as_mut_ptr();
+    {
+        context.scopes[context.scope_index] = Scope {
+            location: ptr::null_mut(),
+            call_return_assign_to_temp: false,
+            top_stack,
+            constant_ptr: context.storages[0].constants.as_ptr(),
+        };
+
+        run_opcodes(context)?;
+
         if dump_memory {
             let dump = context.storages[0].dump();
             context.memory_dump = Some(dump);
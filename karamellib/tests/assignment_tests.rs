@@ -4,6 +4,7 @@ extern crate karamellib;
 mod tests {
     use crate::karamellib::compiler::ast::KaramelAstType;
     use crate::karamellib::compiler::value::KaramelPrimative;
+    use crate::karamellib::error::{KaramelError, KaramelErrorType};
     use crate::karamellib::parser::*;
     use crate::karamellib::syntax::SyntaxParser;
     use crate::karamellib::types::*;
@@ -49,4 +50,34 @@ mod tests {
             })
         }))
     );
+
+    test_compare!(
+        assignment_3,
+        "a, b = b, a",
+        Ok(Rc::new(KaramelAstType::Assignment {
+            variable: Rc::new(KaramelAstType::Tuple([Rc::new(KaramelAstType::Symbol("a".to_string())), Rc::new(KaramelAstType::Symbol("b".to_string()))].to_vec())),
+            operator: KaramelOperatorType::Assign,
+            expression: Rc::new(KaramelAstType::Tuple([Rc::new(KaramelAstType::Symbol("b".to_string())), Rc::new(KaramelAstType::Symbol("a".to_string()))].to_vec()))
+        }))
+    );
+
+    test_compare!(
+        assignment_4,
+        "[a, b] = (1,)",
+        Ok(Rc::new(KaramelAstType::Assignment {
+            variable: Rc::new(KaramelAstType::List([Rc::new(KaramelAstType::Symbol("a".to_string())), Rc::new(KaramelAstType::Symbol("b".to_string()))].to_vec())),
+            operator: KaramelOperatorType::Assign,
+            expression: Rc::new(KaramelAstType::Tuple([Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec()))
+        }))
+    );
+
+    test_compare!(
+        assignment_5,
+        "a, 5 = 1, 2",
+        Err(KaramelError {
+            error_type: KaramelErrorType::InvalidAssignmentTarget,
+            column: 8,
+            line: 0
+        })
+    );
 }
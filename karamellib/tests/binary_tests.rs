@@ -234,4 +234,64 @@ mod tests {
             right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
         }))
     );
+
+    test_compare!(
+        power_1,
+        "2 ** 3 ** 2",
+        Ok(Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            operator: KaramelOperatorType::Power,
+            right: Rc::new(KaramelAstType::Binary {
+                left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0)))),
+                operator: KaramelOperatorType::Power,
+                right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+            })
+        }))
+    );
+
+    test_compare!(
+        power_2,
+        "2 * 3 ** 2",
+        Ok(Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            operator: KaramelOperatorType::Multiplication,
+            right: Rc::new(KaramelAstType::Binary {
+                left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0)))),
+                operator: KaramelOperatorType::Power,
+                right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+            })
+        }))
+    );
+
+    test_compare!(
+        bitwise_1,
+        "1 | 2 & 3",
+        Ok(Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+            operator: KaramelOperatorType::BitwiseOr,
+            right: Rc::new(KaramelAstType::Binary {
+                left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+                operator: KaramelOperatorType::BitwiseAnd,
+                right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0))))
+            })
+        }))
+    );
+
+    test_compare!(
+        bitwise_2,
+        "1 + 2 << 3 ^ 4",
+        Ok(Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Binary {
+                left: Rc::new(KaramelAstType::Binary {
+                    left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+                    operator: KaramelOperatorType::Addition,
+                    right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+                }),
+                operator: KaramelOperatorType::LeftShift,
+                right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0))))
+            }),
+            operator: KaramelOperatorType::BitwiseXor,
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(4.0))))
+        }))
+    );
 }
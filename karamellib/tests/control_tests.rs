@@ -93,4 +93,24 @@ mod tests {
             right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
         }))
     );
+
+    test_compare!(
+        in_1,
+        "10 içinde liste",
+        Ok(Rc::new(KaramelAstType::Control {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+            operator: KaramelOperatorType::In,
+            right: Rc::new(KaramelAstType::Symbol("liste".to_string()))
+        }))
+    );
+
+    test_compare!(
+        in_2,
+        "10 içinde değil liste",
+        Ok(Rc::new(KaramelAstType::Control {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+            operator: KaramelOperatorType::NotIn,
+            right: Rc::new(KaramelAstType::Symbol("liste".to_string()))
+        }))
+    );
 }
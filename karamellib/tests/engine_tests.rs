@@ -0,0 +1,383 @@
+extern crate karamellib;
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::thread;
+
+    use log::LevelFilter;
+
+    use crate::karamellib::buildin::builder::ModuleBuilder;
+    use crate::karamellib::compiler::capabilities::Capabilities;
+    use crate::karamellib::compiler::function::{FunctionParameter, NativeCallResult};
+    use crate::karamellib::compiler::KaramelPrimative;
+    use crate::karamellib::error::KaramelErrorType;
+    use crate::karamellib::io::BufferIo;
+    use crate::karamellib::types::VmObject;
+    use crate::karamellib::vm::engine::Engine;
+    use crate::karamellib::vm::executer::ExecutionSource;
+
+    fn engine(code: &str) -> (Engine, Rc<BufferIo>) {
+        let io = Rc::new(BufferIo::new());
+        let mut engine = Engine::new();
+        engine.set_io(io.clone());
+        engine
+            .execute(ExecutionSource::Code(code.to_string()))
+            .unwrap();
+        (engine, io)
+    }
+
+    #[test]
+    fn test_call_many_times() {
+        let (mut engine, io) = engine(
+            r#"
+fonk olay_başladı(isim, puan):
+    döndür (isim + ':', puan * 2)
+
+fonk boş_dönüş():
+    gç::satıryaz('çalıştı')
+"#,
+        );
+
+        for index in 0..100_i64 {
+            let result: (String, i64) = engine.call("olay_başladı", ("oyuncu", index)).unwrap();
+            assert_eq!(result, ("oyuncu:".to_string(), index * 2));
+        }
+
+        let result: Option<i64> = engine.call("boş_dönüş", ()).unwrap();
+        assert_eq!(result, None);
+        assert!(io.stdout().contains("çalıştı"));
+    }
+
+    #[test]
+    fn test_call_with_defaults() {
+        let (mut engine, io) = engine(
+            r#"
+fonk selam(isim, ek='!'):
+    döndür 'Merhaba ' + isim + ek
+
+fonk faktoriyel(değer):
+    değer <= 1 ise:
+        döndür 1
+    döndür değer * faktoriyel(değer - 1)
+"#,
+        );
+
+        let result: String = engine.call("selam", ("Karamel",)).unwrap();
+        assert_eq!(result, "Merhaba Karamel!");
+
+        let result: String = engine
+            .call("selam", vec![VmObject::from("dünya".to_string()), VmObject::from("?".to_string())])
+            .unwrap();
+        assert_eq!(result, "Merhaba dünya?");
+
+        let result: i64 = engine.call("faktoriyel", (10,)).unwrap();
+        assert_eq!(result, 3628800);
+
+        let result: Option<i64> = engine.call("gç::satıryaz", ("modül fonksiyonu",)).unwrap();
+        assert_eq!(result, None);
+        assert!(io.stdout().contains("modül fonksiyonu"));
+    }
+
+    #[test]
+    fn test_call_errors() {
+        let (mut engine, io) = engine("fonk topla(a, b): döndür a + b");
+
+        let error = engine.call::<_, i64>("çıkar", (1, 2)).unwrap_err();
+        assert_eq!(error.error_type, KaramelErrorType::FunctionNotFound("çıkar".to_string()));
+
+        let error = engine.call::<_, i64>("topla", (1,)).unwrap_err();
+        assert!(matches!(error.error_type, KaramelErrorType::FunctionArgumentMissing { .. }));
+        assert!(io.stderr().contains("Program hata ile sonlandırıldı"));
+
+        let error = engine.call::<_, bool>("topla", (1, 2)).unwrap_err();
+        assert!(matches!(error.error_type, KaramelErrorType::FunctionReturnTypeNotMatching { .. }));
+
+        /* Engine should be usable after an error */
+        let result: i64 = engine.call("topla", (1, 2)).unwrap();
+        assert_eq!(result, 3);
+
+        let error = engine
+            .execute(ExecutionSource::Code("1".to_string()))
+            .unwrap_err();
+        assert_eq!(error.error_type, KaramelErrorType::ScriptAlreadyExecuted);
+
+        let mut engine = Engine::new();
+        let error = engine.call::<_, i64>("topla", (1, 2)).unwrap_err();
+        assert_eq!(error.error_type, KaramelErrorType::FunctionNotFound("topla".to_string()));
+    }
+
+    struct Events(RefCell<Vec<String>>);
+
+    fn apply(mut parameter: FunctionParameter) -> NativeCallResult {
+        let mut iter = parameter.iter();
+        let (function, value) = (*iter.next().unwrap(), *iter.next().unwrap());
+        let result = parameter.call(function, &[value])?;
+
+        if let Some(events) = parameter.user_data::<Events>() {
+            events.0.borrow_mut().push(format!("{:?}", result.deref()));
+        }
+        Ok(result)
+    }
+
+    #[test]
+    fn test_native_callback_and_user_data() {
+        let mut engine = Engine::new();
+        engine.set_user_data(Events(RefCell::new(Vec::new())));
+        engine.set_user_data(10_i64);
+        engine.add_module(
+            ModuleBuilder::new("olay")
+                .function("uygula", apply)
+                .function("çarpan", |parameter: FunctionParameter| Ok(VmObject::from(*parameter.user_data::<i64>().unwrap())))
+                .build(),
+        );
+
+        engine
+            .execute(ExecutionSource::Code(
+                r#"
+fonk çarp(değer): döndür değer * olay::çarpan()
+fonk iç_içe(değer): döndür olay::uygula(çarp, değer) + 1
+
+hataayıklama::doğrula(olay::uygula(çarp, 2), 20)
+hataayıklama::doğrula(olay::uygula(iç_içe, 3), 31)
+"#
+                .to_string(),
+            ))
+            .unwrap();
+
+        let result: i64 = engine.call("iç_içe", (5,)).unwrap();
+        assert_eq!(result, 51);
+        assert_eq!(*engine.user_data::<Events>().unwrap().0.borrow(), vec!["20", "30", "31", "50"]);
+
+        let error = engine.call::<_, i64>("olay::uygula", (1, 2)).unwrap_err();
+        assert!(matches!(error.error_type, KaramelErrorType::NotCallable(_)));
+    }
+
+    #[test]
+    fn test_call_without_context() {
+        let stack = [VmObject::from(1_i64)];
+        let mut parameter = FunctionParameter::new(&stack, None, 1, 1);
+
+        assert!(parameter.context().is_none());
+        assert!(parameter.user_data::<i64>().is_none());
+        assert_eq!(parameter.call(stack[0], &[]).unwrap_err(), KaramelErrorType::ContextNotAvailable);
+    }
+
+    fn run_in_thread(index: i64, level: LevelFilter) -> (i64, String, String) {
+        let io = Rc::new(BufferIo::new());
+        let mut engine = Engine::new();
+        engine.set_io(io.clone());
+        engine.set_log_level(level);
+        engine.add_module(
+            ModuleBuilder::new("kayıt")
+                .function("bilgi", |parameter: FunctionParameter| {
+                    log::info!("{}", parameter.iter().next().unwrap().deref());
+                    Ok(VmObject::from(true))
+                })
+                .build(),
+        );
+        engine
+            .execute(ExecutionSource::Code(format!(
+                r#"
+fonk hesapla(değer):
+    toplam = 0
+    döngü i = 0, i < 1000, i++:
+        toplam += değer
+    döndür toplam
+
+gç::satıryaz('iş {0}')
+kayıt::bilgi('iş {0}')
+"#,
+                index
+            )))
+            .unwrap();
+
+        let result: i64 = engine.call("hesapla", (index,)).unwrap();
+        (result, io.stdout(), io.log())
+    }
+
+    #[test]
+    fn test_engines_in_threads() {
+        crate::karamellib::logger::init_logger();
+
+        let handles = (0..8_i64)
+            .map(|index| {
+                let level = match index % 2 {
+                    0 => LevelFilter::Info,
+                    _ => LevelFilter::Off,
+                };
+                thread::spawn(move || run_in_thread(index, level))
+            })
+            .collect::<Vec<_>>();
+
+        for (index, handle) in handles.into_iter().enumerate() {
+            let (result, stdout, log) = handle.join().unwrap();
+            let index = index as i64;
+            assert_eq!(result, index * 1000);
+            assert!(stdout.contains(&format!("iş {}", index)));
+            assert!(!stdout.contains(&format!("iş {}", (index + 1) % 8)));
+
+            match index % 2 {
+                0 => assert!(log.contains(&format!("[INFO] \"iş {}\"", index))),
+                _ => assert!(log.is_empty()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_reserved_class_names() {
+        let mut engine = Engine::new();
+        engine.set_io(Rc::new(BufferIo::new()));
+        let error = engine
+            .execute(ExecutionSource::Code("tarih = 1".to_string()))
+            .unwrap_err();
+        assert_eq!(error.error_type, KaramelErrorType::ReservedName("tarih".to_string()));
+    }
+
+    fn eval(engine: &mut Engine, code: &str) -> String {
+        format!("{:?}", engine.eval(code).unwrap().deref())
+    }
+
+    #[test]
+    fn test_eval() {
+        let io = Rc::new(BufferIo::new());
+        let mut engine = Engine::new();
+        engine.set_io(io.clone());
+
+        assert_eq!(eval(&mut engine, "1 + 2"), "3");
+        assert_eq!(eval(&mut engine, "a = 10"), "boş");
+        assert_eq!(eval(&mut engine, "a * 2"), "20");
+        assert_eq!(eval(&mut engine, "fonk iki_katı(değer): döndür değer * 2"), "boş");
+        assert_eq!(eval(&mut engine, "iki_katı(a)"), "20");
+        assert_eq!(eval(&mut engine, "b = [a, iki_katı(a)]\nb.uzunluk()"), "2");
+        assert_eq!(eval(&mut engine, "b"), "[10, 20]");
+        assert_eq!(eval(&mut engine, "gç::satıryaz('selam')\na"), "10");
+        assert_eq!(eval(&mut engine, "gç::satıryaz(a)"), "boş");
+        assert_eq!(eval(&mut engine, "matematik::kök(16)"), "4");
+        assert_eq!(eval(&mut engine, "döngü i = 0, i < 3, i++:\n    a += i\na"), "13");
+        assert!(io.stdout().contains("selam"));
+
+        let result: i64 = engine.call("iki_katı", (4,)).unwrap();
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn test_eval_errors() {
+        let io = Rc::new(BufferIo::new());
+        let mut engine = Engine::new();
+        engine.set_io(io.clone());
+        engine
+            .execute(ExecutionSource::Code("fonk topla(a, b): döndür a + b\nsonuç = topla(1, 2)".to_string()))
+            .unwrap();
+
+        assert_eq!(eval(&mut engine, "sonuç"), "3");
+        assert!(engine.eval("fonk topla(a): döndür a").is_err());
+        assert!(engine.eval("1 +").is_err());
+        assert!(engine.eval("[1].eşle(10)").is_err());
+
+        /* Program should not be changed by the failed codes */
+        assert_eq!(eval(&mut engine, "topla(sonuç, 4)"), "7");
+        assert_eq!(eval(&mut engine, "yeni"), "boş");
+
+        /* Variables and constants of the failed code are removed */
+        let storage = &engine.context().storages[0];
+        let sizes = (storage.variables.len(), storage.constants.len());
+        assert!(engine
+            .eval("geçici = 'karamel'\ntanımsız::fonksiyon()")
+            .is_err());
+        let storage = &engine.context().storages[0];
+        assert_eq!((storage.variables.len(), storage.constants.len()), sizes);
+        assert_eq!(eval(&mut engine, "diğer = 5\nsonuç + diğer"), "8");
+        assert_eq!(eval(&mut engine, "geçici"), "boş");
+        assert_eq!(eval(&mut engine, "[sonuç, diğer]"), "[3, 5]");
+    }
+
+    #[test]
+    fn test_capabilities_at_call() {
+        let mut engine = Engine::new();
+        engine.set_io(Rc::new(BufferIo::with_input("satır")));
+        engine.set_capabilities(Capabilities::sandbox());
+        engine
+            .execute(ExecutionSource::Code("fonk çağır(fonksiyon): döndür fonksiyon()".to_string()))
+            .unwrap();
+
+        let error = engine.call::<_, String>("gç::satıroku", ()).unwrap_err();
+        assert_eq!(error.error_type, KaramelErrorType::StdinNotAllowed);
+
+        /* Function value is checked when it is called by the script */
+        let read_line = engine.get_function("gç::satıroku").unwrap();
+        let argument = VmObject::native_convert(KaramelPrimative::Function(read_line, None));
+        let error = engine
+            .call::<_, String>("çağır", vec![argument])
+            .unwrap_err();
+        assert_eq!(error.error_type, KaramelErrorType::StdinNotAllowed);
+
+        let error = engine.eval("gç::satıroku()").unwrap_err();
+        assert_eq!(error.error_type, KaramelErrorType::StdinNotAllowed);
+    }
+
+    #[test]
+    fn test_native_callback_depth() {
+        let (mut engine, _) = engine("fonk f(x): döndür [x].eşle(f)\nfonk iki_katı(x): döndür x * 2");
+        let error = engine.eval("f(1)").unwrap_err();
+        assert_eq!(error.error_type, KaramelErrorType::CallDepthExceeded);
+
+        /* Depth is reset after the error */
+        assert_eq!(eval(&mut engine, "[1, 2].eşle(iki_katı)"), "[2, 4]");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_values() {
+        use karamellib::compiler::serialize::{from_value, to_value};
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Settings {
+            name: String,
+            retry: u32,
+            tags: Vec<String>,
+            limit: Option<f64>,
+        }
+
+        let (mut engine, _) = engine(
+            r#"
+fonk güncelle(ayar):
+    ayar['name'] = ayar['name'] + ' betik'
+    ayar['retry'] = ayar['retry'] * 2
+    ayar['limit'] = 1.5
+    döndür ayar
+
+fonk ilk(değerler): döndür değerler['liste'][1]"#,
+        );
+
+        let settings = Settings {
+            name: "ayar".to_string(),
+            retry: 2,
+            tags: vec!["a".to_string()],
+            limit: None,
+        };
+
+        let result: VmObject = engine
+            .call("güncelle", vec![to_value(&settings).unwrap()])
+            .unwrap();
+        assert_eq!(
+            from_value::<Settings>(&result).unwrap(),
+            Settings {
+                name: "ayar betik".to_string(),
+                retry: 4,
+                tags: vec!["a".to_string()],
+                limit: Some(1.5),
+            }
+        );
+
+        let json: VmObject = serde_json::from_str(r#"{"liste": [1, 2.5, "üç", null]}"#).unwrap();
+        let result: f64 = engine.call("ilk", vec![json]).unwrap();
+        assert_eq!(result, 2.5);
+
+        let function = engine.eval("güncelle").unwrap();
+        assert_eq!(from_value::<String>(&function), Err(KaramelErrorType::ValueNotSerializable("fonksiyon".to_string())));
+        assert!(serde_json::to_string(&function).is_err());
+    }
+}
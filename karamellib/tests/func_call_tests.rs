@@ -159,4 +159,38 @@ mod tests {
         }))
     );
     test_compare!(func_call_12, "gç::satıryaz", Ok(Rc::new(KaramelAstType::ModulePath(["gç".to_string(), "satıryaz".to_string()].to_vec()))));
+    test_compare!(
+        func_call_13,
+        "print(1, isim='erhan')",
+        Ok(Rc::new(KaramelAstType::FuncCall {
+            func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
+            arguments: [
+                Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+                Rc::new(KaramelAstType::NamedArgument {
+                    name: "isim".to_string(),
+                    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("erhan".to_string())))))
+                })
+            ]
+            .to_vec(),
+            assign_to_temp: Cell::new(false)
+        }))
+    );
+    test_compare!(
+        func_call_14,
+        "print(isim='erhan', 1)",
+        Err(KaramelError {
+            error_type: KaramelErrorType::PositionalArgumentAfterNamedArgument,
+            column: 22,
+            line: 0
+        })
+    );
+    test_compare!(
+        func_call_15,
+        "print(isim='erhan', isim=1)",
+        Err(KaramelError {
+            error_type: KaramelErrorType::NamedArgumentAlreadyUsed("isim".to_string()),
+            column: 27,
+            line: 0
+        })
+    );
 }
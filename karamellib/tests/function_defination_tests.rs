@@ -5,6 +5,7 @@ mod tests {
     use karamellib::error::{KaramelError, KaramelErrorType};
 
     use crate::karamellib::compiler::ast::KaramelAstType;
+    use crate::karamellib::compiler::function::FunctionArgument;
     use crate::karamellib::compiler::value::KaramelPrimative;
     use crate::karamellib::parser::*;
     use crate::karamellib::syntax::*;
@@ -56,7 +57,7 @@ fonk test(a):
     erhan=123"#,
         Ok(Rc::new(KaramelAstType::FunctionDefination {
             name: "test".to_string(),
-            arguments: ["a".to_string()].to_vec(),
+            arguments: [FunctionArgument::new("a")].to_vec(),
             body: Rc::new(KaramelAstType::Block(
                 [
                     Rc::new(KaramelAstType::Assignment {
@@ -77,7 +78,7 @@ fonk test(a, b    ,   c):
     erhan=123"#,
         Ok(Rc::new(KaramelAstType::FunctionDefination {
             name: "test".to_string(),
-            arguments: ["a".to_string(), "b".to_string(), "c".to_string()].to_vec(),
+            arguments: [FunctionArgument::new("a"), FunctionArgument::new("b"), FunctionArgument::new("c")].to_vec(),
             body: Rc::new(KaramelAstType::Block(
                 [
                     Rc::new(KaramelAstType::Assignment {
@@ -272,4 +273,69 @@ fonk test():
             ))
         }))
     );
+    test_compare!(
+        func_def_17,
+        r#"
+fonk test(a, b='merhaba', c=-1, *d):
+    döndür a"#,
+        Ok(Rc::new(KaramelAstType::FunctionDefination {
+            name: "test".to_string(),
+            arguments: [
+                FunctionArgument::new("a"),
+                FunctionArgument::with_default("b", Rc::new(KaramelPrimative::Text(Rc::new("merhaba".to_string())))),
+                FunctionArgument::with_default("c", Rc::new(KaramelPrimative::Number(-1.0))),
+                FunctionArgument::variadic("d")
+            ]
+            .to_vec(),
+            body: Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Symbol("a".to_string()))))
+        }))
+    );
+    test_compare!(
+        func_def_18,
+        r#"
+fonk test(a=1, b):
+  döndür a
+"#,
+        Err(KaramelError {
+            error_type: KaramelErrorType::ArgumentDefaultValueRequired("b".to_string()),
+            column: 17,
+            line: 1
+        })
+    );
+    test_compare!(
+        func_def_19,
+        r#"
+fonk test(*a, b):
+  döndür a
+"#,
+        Err(KaramelError {
+            error_type: KaramelErrorType::VariadicArgumentMustBeLast,
+            column: 15,
+            line: 1
+        })
+    );
+    test_compare!(
+        func_def_20,
+        r#"
+fonk test(a, a):
+  döndür a
+"#,
+        Err(KaramelError {
+            error_type: KaramelErrorType::ArgumentAlreadyDefined("a".to_string()),
+            column: 15,
+            line: 1
+        })
+    );
+    test_compare!(
+        func_def_21,
+        r#"
+fonk test(a=b):
+  döndür a
+"#,
+        Err(KaramelError {
+            error_type: KaramelErrorType::ArgumentDefaultValueNotValid,
+            column: 14,
+            line: 1
+        })
+    );
 }
@@ -1,9 +1,12 @@
 extern crate karamellib;
 
 #[cfg(test)]
+#[allow(clippy::redundant_pattern_matching)]
 mod tests {
     use crate::karamellib::parser::*;
     use crate::karamellib::types::*;
+    use num_bigint::BigInt;
+    use std::rc::Rc;
 
     #[warn(unused_macros)]
     macro_rules! test_number {
@@ -280,6 +283,20 @@ mod tests {
     test_number!(double_5, Double, "1_23.4e+4", 1234000.0);
     test_number!(double_6, Double, "1_23.4_e+4_", 1234000.0);
     test_number!(double_7, Double, "09__9_999.9_", 99999.9);
+    test_number!(integer_max, Integer, "9223372036854775807", i64::MAX);
+    test_number!(big_integer_1, BigInteger, "9223372036854775808", Rc::new(BigInt::from(i64::MAX) + 1));
+    test_number!(big_integer_2, BigInteger, "123_456_789_012_345_678_901_234_567_890", Rc::new("123456789012345678901234567890".parse::<BigInt>().unwrap()));
+    test_number!(big_integer_3, BigInteger, "0xFFFFFFFFFFFFFFFFFF", Rc::new(BigInt::parse_bytes(b"FFFFFFFFFFFFFFFFFF", 16).unwrap()));
+
+    #[test]
+    fn comment_after_value() {
+        let mut parser = Parser::new("a = 5 // beş");
+        assert!(parser.parse().is_ok());
+        assert!(!parser
+            .tokens()
+            .iter()
+            .any(|token| matches!(token.token_type, KaramelTokenType::Symbol(ref name) if name.as_str() == "beş")));
+    }
 
     test_keyword!(keyword_2, "doğru", KaramelKeywordType::True);
     test_keyword!(keyword_4, "yanlış", KaramelKeywordType::False);
@@ -0,0 +1,177 @@
+extern crate karamellib;
+
+#[cfg(test)]
+mod tests {
+    use karamellib::error::{KaramelError, KaramelErrorType, KaramelWarningType};
+
+    use crate::karamellib::compiler::ast::{KaramelAstType, KaramelMatchCase, KaramelMatchPattern};
+    use crate::karamellib::compiler::value::KaramelPrimative;
+    use crate::karamellib::parser::*;
+    use crate::karamellib::syntax::*;
+    use crate::karamellib::types::*;
+    use crate::karamellib::vm::executer::*;
+    use std::rc::Rc;
+
+    #[warn(unused_macros)]
+    macro_rules! test_compare {
+        ($name:ident, $text:expr, $result:expr) => {
+            #[test]
+            fn $name() {
+                let mut parser = Parser::new($text);
+                match parser.parse() {
+                    Err(_) => assert_eq!(true, false),
+                    _ => (),
+                };
+
+                let syntax = SyntaxParser::new(parser.tokens().to_vec());
+                assert_eq!(syntax.parse(), $result);
+            }
+        };
+    }
+
+    #[warn(unused_macros)]
+    macro_rules! test_warnings {
+        ($name:ident, $text:expr, $result:expr) => {
+            #[test]
+            fn $name() {
+                let parameters = ExecutionParameters {
+                    source: ExecutionSource::Code($text.to_string()),
+                    ..Default::default()
+                };
+
+                let status = code_executer(parameters);
+                assert!(status.executed);
+                assert_eq!(status.warnings, $result);
+            }
+        };
+    }
+
+    test_compare!(
+        match_1,
+        r#"eşleştir veri:
+    1, 2: sonuç = 'küçük'
+    _: sonuç = 'büyük'
+"#,
+        Ok(Rc::new(KaramelAstType::Match {
+            value: Rc::new(KaramelAstType::Symbol("veri".to_string())),
+            variable: "__eşleştir_1".to_string(),
+            cases: vec![
+                Rc::new(KaramelMatchCase::new(
+                    vec![Rc::new(KaramelMatchPattern::Literal(Rc::new(KaramelPrimative::Number(1.0)))), Rc::new(KaramelMatchPattern::Literal(Rc::new(KaramelPrimative::Number(2.0))))],
+                    None,
+                    Rc::new(KaramelAstType::Assignment {
+                        variable: Rc::new(KaramelAstType::Symbol("sonuç".to_string())),
+                        operator: KaramelOperatorType::Assign,
+                        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("küçük".to_string())))))
+                    })
+                )),
+                Rc::new(KaramelMatchCase::new(
+                    vec![Rc::new(KaramelMatchPattern::Wildcard)],
+                    None,
+                    Rc::new(KaramelAstType::Assignment {
+                        variable: Rc::new(KaramelAstType::Symbol("sonuç".to_string())),
+                        operator: KaramelOperatorType::Assign,
+                        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("büyük".to_string())))))
+                    })
+                ))
+            ]
+        }))
+    );
+
+    test_compare!(
+        match_2,
+        r#"eşleştir veri:
+    [a, ...geri] eğer a:
+        sonuç = geri
+"#,
+        Ok(Rc::new(KaramelAstType::Match {
+            value: Rc::new(KaramelAstType::Symbol("veri".to_string())),
+            variable: "__eşleştir_1".to_string(),
+            cases: vec![Rc::new(KaramelMatchCase::new(
+                vec![Rc::new(KaramelMatchPattern::List {
+                    items: vec![Rc::new(KaramelMatchPattern::Binding("a".to_string()))],
+                    rest: Some("geri".to_string())
+                })],
+                Some(Rc::new(KaramelAstType::Symbol("a".to_string()))),
+                Rc::new(KaramelAstType::Assignment {
+                    variable: Rc::new(KaramelAstType::Symbol("sonuç".to_string())),
+                    operator: KaramelOperatorType::Assign,
+                    expression: Rc::new(KaramelAstType::Symbol("geri".to_string()))
+                })
+            ))]
+        }))
+    );
+
+    test_compare!(
+        match_3,
+        r#"eşleştir veri:
+    {'ad': isim, 'yaş': -1}: sonuç = isim
+"#,
+        Ok(Rc::new(KaramelAstType::Match {
+            value: Rc::new(KaramelAstType::Symbol("veri".to_string())),
+            variable: "__eşleştir_1".to_string(),
+            cases: vec![Rc::new(KaramelMatchCase::new(
+                vec![Rc::new(KaramelMatchPattern::Dict(vec![
+                    (Rc::new(KaramelPrimative::Text(Rc::new("ad".to_string()))), Rc::new(KaramelMatchPattern::Binding("isim".to_string()))),
+                    (Rc::new(KaramelPrimative::Text(Rc::new("yaş".to_string()))), Rc::new(KaramelMatchPattern::Literal(Rc::new(KaramelPrimative::Number(-1.0)))))
+                ]))],
+                None,
+                Rc::new(KaramelAstType::Assignment {
+                    variable: Rc::new(KaramelAstType::Symbol("sonuç".to_string())),
+                    operator: KaramelOperatorType::Assign,
+                    expression: Rc::new(KaramelAstType::Symbol("isim".to_string()))
+                })
+            ))]
+        }))
+    );
+
+    test_compare!(
+        match_4,
+        r#"eşleştir veri:
+    [1, 2: sonuç = 1
+"#,
+        Err(KaramelError {
+            error_type: KaramelErrorType::ArrayNotClosed,
+            line: 1,
+            column: 10
+        })
+    );
+
+    test_compare!(
+        match_5,
+        r#"eşleştir veri: 1
+"#,
+        Err(KaramelError {
+            error_type: KaramelErrorType::MatchCaseNotFound,
+            line: 0,
+            column: 16
+        })
+    );
+
+    test_warnings!(
+        match_warning_1,
+        r#"eşleştir 1:
+    1: a = 1
+"#,
+        vec![KaramelWarningType::NonExhaustiveMatch]
+    );
+
+    test_warnings!(
+        match_warning_2,
+        r#"eşleştir 1:
+    x: a = 1
+    2: a = 2
+    _: a = 3
+"#,
+        vec![KaramelWarningType::UnreachableMatchCase(2), KaramelWarningType::UnreachableMatchCase(3)]
+    );
+
+    test_warnings!(
+        match_warning_3,
+        r#"eşleştir doğru:
+    doğru: a = 1
+    yanlış: a = 2
+"#,
+        Vec::<KaramelWarningType>::new()
+    );
+}
@@ -143,6 +143,18 @@ mod tests {
         )))
     );
 
+    test_success!(
+        dict_5,
+        "{('1', '2'):1}",
+        Ok(Rc::new(KaramelAstType::Dict(
+            [Rc::new(KaramelDictItem {
+                key: Rc::new(KaramelPrimative::Tuple(vec![VmObject::from("1".to_string()), VmObject::from("2".to_string())])),
+                value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+            })]
+            .to_vec()
+        )))
+    );
+
     test_success!(list_1, "[]", Ok(Rc::new(KaramelAstType::List(Vec::new()))));
     test_success!(list_2, "[1]", Ok(Rc::new(KaramelAstType::List([Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec()))));
     test_success!(list_3, "[doğru]", Ok(Rc::new(KaramelAstType::List([Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true))))].to_vec()))));
@@ -1,9 +1,11 @@
 extern crate karamellib;
 
 #[cfg(test)]
+#[allow(clippy::manual_flatten)]
 mod tests {
     use std::fs::Metadata;
 
+    use crate::karamellib::compiler::capabilities::Capabilities;
     use crate::karamellib::vm::executer::{ExecutionParameters, ExecutionSource};
     use crate::karamellib::vm::*;
 
@@ -59,10 +61,8 @@ mod tests {
                 if let Some(path_str) = path.path().to_str() {
                     let parameters = ExecutionParameters {
                         source: ExecutionSource::File(path_str.to_string()),
-                        return_opcode: false,
-                        return_output: false,
-                        dump_opcode: false,
-                        dump_memory: false,
+                        capabilities: Capabilities::all(),
+                        ..Default::default()
                     };
 
                     let result = executer::code_executer(parameters);
@@ -98,4 +98,235 @@ mod tests {
     fn test_module_executer() -> Result<(), String> {
         executer(ExecuterType::Module)
     }
+    #[test]
+    fn test_fixed_clock() {
+        use crate::karamellib::buildin::date::FixedClock;
+        use std::rc::Rc;
+
+        let parameters = ExecutionParameters {
+            source: ExecutionSource::Code("hataayıklama::doğrula(tarih::şimdi(), tarih::oluştur(2024, 1, 1, 12))".to_string()),
+            clock: Some(Rc::new(FixedClock(1_704_110_400_000))),
+            ..Default::default()
+        };
+
+        let result = executer::code_executer(parameters);
+        assert!(result.compiled && result.executed);
+    }
+    #[test]
+    fn test_file_access_not_allowed() {
+        let parameters = ExecutionParameters {
+            source: ExecutionSource::Code("dosya::var_mı('.')".to_string()),
+            return_output: true,
+            ..Default::default()
+        };
+
+        let result = executer::code_executer(parameters);
+        assert!(!result.executed);
+        assert!(result
+            .stderr
+            .unwrap()
+            .borrow()
+            .contains("Dosya sistemine erişim izni verilmedi"));
+    }
+    fn execute_with(source: ExecutionSource, capabilities: Capabilities) -> (bool, String) {
+        let parameters = ExecutionParameters {
+            source,
+            return_output: true,
+            capabilities,
+            ..Default::default()
+        };
+
+        let result = executer::code_executer(parameters);
+        (result.compiled && result.executed, result.stderr.unwrap().borrow().to_string())
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let code = |code: &str| ExecutionSource::Code(code.to_string());
+
+        let (executed, stderr) = execute_with(code("gç::satıroku()"), Capabilities::sandbox());
+        assert!(!executed);
+        assert!(stderr.contains("Girdi okuma izni verilmedi"));
+
+        let (executed, stderr) = execute_with(code("matematik::kök(4)"), Capabilities::new().deny_function("matematik", "kök"));
+        assert!(!executed);
+        assert!(stderr.contains("'matematik::kök' fonksiyonunu kullanma izni verilmedi"));
+
+        let (executed, stderr) = execute_with(code("gç::yaz(matematik::pi)"), Capabilities::new().only_modules(["gç"]));
+        assert!(!executed);
+        assert!(stderr.contains("'matematik' modülünü kullanma izni verilmedi"));
+
+        let (executed, _) = execute_with(code("gç::yaz(matematik::kök(4))"), Capabilities::sandbox().only_modules(["gç", "matematik"]));
+        assert!(executed);
+    }
+
+    #[test]
+    fn test_load_capabilities() {
+        let folder = std::env::current_dir().unwrap().join("test_modules");
+        let module = |name: &str| ExecutionSource::File(folder.join(name).to_str().unwrap().to_string());
+
+        let (executed, stderr) = execute_with(module("pass_module_2"), Capabilities::new().load_inside(folder.join("pass_module_2")));
+        assert!(executed, "{}", stderr);
+
+        let (executed, stderr) = execute_with(module("pass_module_2"), Capabilities::new().load_inside(folder.join("pass_module_1")));
+        assert!(!executed);
+        assert!(stderr.contains("yükleme izni verilmedi"));
+
+        let (executed, stderr) = execute_with(module("pass_module_2"), Capabilities::sandbox());
+        assert!(!executed);
+        assert!(stderr.contains("yükleme izni verilmedi"));
+    }
+
+    #[test]
+    fn test_module_builder() {
+        use crate::karamellib::buildin::builder::ModuleBuilder;
+        use crate::karamellib::compiler::function::{FunctionArgument, FunctionParameter, NativeCallResult};
+        use crate::karamellib::compiler::value::KaramelPrimative;
+        use crate::karamellib::types::VmObject;
+
+        fn score(parameter: FunctionParameter) -> NativeCallResult {
+            match &*parameter.iter().next().unwrap().deref() {
+                KaramelPrimative::Integer(multiplier) => Ok(VmObject::from(multiplier * 100)),
+                _ => Ok(VmObject::from(0_i64)),
+            }
+        }
+
+        fn count(parameter: FunctionParameter) -> NativeCallResult {
+            Ok(VmObject::from(parameter.length() as i64))
+        }
+
+        let module = ModuleBuilder::new("oyun")
+            .function_with_arguments("skor", score, vec![FunctionArgument::new("çarpan")])
+            .function("say", count)
+            .constant("SÜRÜM", KaramelPrimative::Integer(2))
+            .build();
+
+        let parameters = ExecutionParameters {
+            source: ExecutionSource::Code(
+                r#"
+hataayıklama::doğrula(oyun::skor(3), 300)
+hataayıklama::doğrula(oyun::skor(çarpan=2), 200)
+hataayıklama::doğrula(oyun::say(1, 2, 3), 3)
+hataayıklama::doğrula(oyun::SÜRÜM, 2)
+sürüm = oyun::SÜRÜM + 1
+hataayıklama::doğrula(sürüm, 3)
+"#
+                .to_string(),
+            ),
+            return_output: true,
+            modules: vec![module],
+            ..Default::default()
+        };
+
+        let result = executer::code_executer(parameters);
+        assert!(result.executed, "{}", result.stderr.unwrap().borrow());
+    }
+
+    #[test]
+    fn test_typed_function() {
+        use crate::karamellib::buildin::builder::ModuleBuilder;
+        use std::cell::Cell;
+        use std::collections::HashMap;
+        use std::rc::Rc;
+
+        let calls = Rc::new(Cell::new(0_i64));
+        let counter = calls.clone();
+
+        let module = ModuleBuilder::new("hesap")
+            .typed_function("topla", |items: Vec<i64>| items.iter().sum::<i64>())
+            .typed_function("böl", |left: f64, right: f64| match right == 0.0 {
+                true => None,
+                false => Some(left / right),
+            })
+            .typed_function("say", move || {
+                counter.set(counter.get() + 1);
+                counter.get()
+            })
+            .typed_function("anahtarlar", |items: HashMap<String, bool>| {
+                let mut keys = items
+                    .into_iter()
+                    .filter(|(_, value)| *value)
+                    .map(|(key, _)| key)
+                    .collect::<Vec<_>>();
+                keys.sort();
+                keys
+            })
+            .typed_function("çift", |text: String, count: usize| (text.repeat(count), count))
+            .build();
+
+        let parameters = ExecutionParameters {
+            source: ExecutionSource::Code(
+                r#"
+hataayıklama::doğrula(hesap::topla([1, 2, 3]), 6)
+hataayıklama::doğrula(hesap::böl(9, 2), 4.5)
+hataayıklama::doğrula(hesap::böl(9, 0), boş)
+hesap::say()
+hataayıklama::doğrula(hesap::say(), 2)
+hataayıklama::doğrula(hesap::anahtarlar({'b': doğru, 'a': doğru, 'c': yanlış}), ['a', 'b'])
+hataayıklama::doğrula(hesap::çift('ab', 2), ('abab', 2))
+"#
+                .to_string(),
+            ),
+            return_output: true,
+            modules: vec![module.clone()],
+            ..Default::default()
+        };
+
+        let result = executer::code_executer(parameters);
+        assert!(result.executed, "{}", result.stderr.unwrap().borrow());
+        assert_eq!(calls.get(), 2);
+
+        for (code, message) in [("hesap::topla(1)", "Tam sayı listesi"), ("hesap::topla()", "topla")] {
+            let parameters = ExecutionParameters {
+                source: ExecutionSource::Code(code.to_string()),
+                return_output: true,
+                modules: vec![module.clone()],
+                ..Default::default()
+            };
+
+            let result = executer::code_executer(parameters);
+            assert!(!result.executed);
+            assert!(result.stderr.unwrap().borrow().contains(message));
+        }
+    }
+
+    #[test]
+    fn test_custom_io() {
+        use crate::karamellib::io::BufferIo;
+        use std::rc::Rc;
+
+        crate::karamellib::logger::init_logger();
+        let io = Rc::new(BufferIo::with_input("Karamel\n42"));
+        let parameters = ExecutionParameters {
+            source: ExecutionSource::Code(
+                r#"
+isim = gç::satıroku()
+sayı_yazısı = gç::satıroku()
+gç::yaz(isim)
+hataayıklama::doğrula(sayı_yazısı, '42')
+hataayıklama::doğrula(gç::satıroku(), boş)
+[1].eşle(10)
+"#
+                .to_string(),
+            ),
+            return_output: true,
+            io: Some(io.clone()),
+            ..Default::default()
+        };
+
+        let result = executer::code_executer(parameters);
+        assert!(!result.executed);
+        assert!(result.stdout.is_none());
+        assert_eq!(io.stdout(), "\"Karamel\"");
+        assert!(io
+            .stderr()
+            .contains("'10' fonksiyon olarak çağrılabilir değil"));
+        assert_eq!(
+            io.stderr()
+                .matches("Program hata ile sonlandırıldı")
+                .count(),
+            1
+        );
+        assert!(!io.log().contains("Program hata ile sonlandırıldı"));
+    }
 }
@@ -1,6 +1,7 @@
 extern crate karamellib;
 
 #[cfg(test)]
+#[allow(clippy::almost_complete_range)]
 mod tests {
     use crate::karamellib::types::CharTraits;
 
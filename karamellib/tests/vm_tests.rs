@@ -560,4 +560,11 @@ hataayıklama::doğrula(Fibonacci(10), 55)
 hataayıklama::doğrula(Fibonacci(20), 6765)
 "#
     );
+    test_last_memory!(vm_108, "2 içinde [1, 2, 3]", KaramelPrimative::Bool(true));
+    test_last_memory!(vm_109, "4 içinde [1, 2, 3]", KaramelPrimative::Bool(false));
+    test_last_memory!(vm_110, "4 içinde değil [1, 2, 3]", KaramelPrimative::Bool(true));
+    test_last_memory!(vm_111, "'ad' içinde {'ad': 'erhan'}", KaramelPrimative::Bool(true));
+    test_last_memory!(vm_112, "'soyad' içinde {'ad': 'erhan'}", KaramelPrimative::Bool(false));
+    test_last_memory!(vm_113, "'han' içinde 'erhan'", KaramelPrimative::Bool(true));
+    test_last_memory!(vm_114, "'barış' icinde degil 'erhan'", KaramelPrimative::Bool(true));
 }
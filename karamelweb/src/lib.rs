@@ -2,13 +2,23 @@ extern crate karamellib;
 
 use js_sys::*;
 use karamellib::{
+    compiler::capabilities::Capabilities,
     compiler::KaramelPrimative,
+    io::BufferIo,
     vm::executer::{ExecutionParameters, ExecutionSource},
 };
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
 pub fn execute_code(name: &str) -> Object {
+    execute_code_with_input(name, "")
+}
+
+/// Every line of the input is read by one 'gç::satıroku' call.
+#[wasm_bindgen]
+pub fn execute_code_with_input(name: &str, input: &str) -> Object {
+    karamellib::logger::init_logger();
     let response = js_sys::Object::new();
 
     /* JS referance object */
@@ -19,12 +29,16 @@ pub fn execute_code(name: &str) -> Object {
     let opcode_dump_ref = JsValue::from("code_dump");
     let memory_dump_ref = JsValue::from("memory_dump");
 
+    let io = Rc::new(BufferIo::with_input(input));
     let parameters = ExecutionParameters {
         source: ExecutionSource::Code(name.to_string()),
         return_opcode: true,
         return_output: true,
         dump_opcode: true,
         dump_memory: true,
+        capabilities: Capabilities::sandbox().stdin(true),
+        io: Some(io.clone()),
+        ..Default::default()
     };
 
     let result = karamellib::vm::executer::code_executer(parameters);
@@ -38,6 +52,8 @@ pub fn execute_code(name: &str) -> Object {
                     match &*object.deref() {
                         KaramelPrimative::Text(text) => results.push(&JsValue::from(&**text)),
                         KaramelPrimative::Number(number) => results.push(&JsValue::from_f64(*number)),
+                        KaramelPrimative::Integer(number) => results.push(&JsValue::from_f64(*number as f64)),
+                        KaramelPrimative::BigInteger(number) => results.push(&JsValue::from(number.to_string())),
                         KaramelPrimative::Bool(bool) => results.push(&JsValue::from_bool(*bool)),
                         KaramelPrimative::Empty => results.push(&JsValue::undefined()),
                         _ => 0,
@@ -45,9 +61,7 @@ pub fn execute_code(name: &str) -> Object {
                 }
             };
 
-            if let Some(stdout) = result.stdout {
-                stdouts.push(&JsValue::from(stdout.borrow().clone()));
-            };
+            stdouts.push(&JsValue::from(io.stdout()));
 
             if let Some(memory_dump) = result.memory_dump {
                 Reflect::set(response.as_ref(), memory_dump_ref.as_ref(), JsValue::from_str(&memory_dump).as_ref()).unwrap();
@@ -65,13 +79,8 @@ pub fn execute_code(name: &str) -> Object {
             let stderrs = Array::new();
             let stdouts = Array::new();
 
-            if let Some(stdout) = result.stdout {
-                stdouts.push(&JsValue::from(stdout.borrow().clone()));
-            };
-
-            if let Some(stderr) = result.stderr {
-                stderrs.push(&JsValue::from(stderr.borrow().clone()));
-            };
+            stdouts.push(&JsValue::from(io.stdout()));
+            stderrs.push(&JsValue::from(io.stderr()));
 
             Reflect::set(response.as_ref(), status_ref.as_ref(), JsValue::from_bool(false).as_ref()).unwrap();
             Reflect::set(response.as_ref(), stdout_ref.as_ref(), stdouts.as_ref()).unwrap();